@@ -448,6 +448,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .map_err(|_| "Unable to generate raw bindings")?
         .write_to_file(out_dir.join("bindings_raw.rs"))?;
 
+    // Generate the names of the node tags, for errors about nodes the raw parser doesn't convert
+    let node_tags = std::fs::read_to_string(out_dir.join("src/postgres/include/nodes/nodetags.h"))?;
+    std::fs::write(out_dir.join("node_tags.rs"), generate_node_tags(&node_tags))?;
+
     // Only generate protobuf bindings if protoc is available
     let protoc_exists = Command::new("protoc").arg("--version").status().is_ok();
     // If the package is being built by docs.rs, we don't want to regenerate the protobuf bindings
@@ -460,7 +464,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         env::set_var("OUT_DIR", &src_dir);
 
         let mut prost_build = prost_build::Config::new();
-        prost_build.type_attribute(".", "#[derive(serde::Serialize)]");
+        prost_build.type_attribute(".", "#[derive(serde::Serialize, serde::Deserialize)]");
+        prost_build.message_attribute(".", "#[serde(default)]");
         prost_build.compile_protos(&[&out_protobuf_path.join("pg_query").with_extension("proto")], &[&out_protobuf_path])?;
 
         std::fs::rename(src_dir.join("pg_query.rs"), src_dir.join("protobuf.rs"))?;
@@ -471,5 +476,537 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         println!("skipping protobuf generation");
     }
 
+    // Generate the visitors, node paths, selector fields, JSON schema and limit checks from the protobuf types, so that they cover every node
+    let protobuf = std::fs::read_to_string(PathBuf::from(env::var("CARGO_MANIFEST_DIR")?).join("src").join("protobuf.rs"))?;
+    let messages = Messages::parse(&protobuf);
+    std::fs::write(out_dir.join("visitor.rs"), generate_visitor(&messages, false) + "\n" + &generate_visitor(&messages, true))?;
+    std::fs::write(out_dir.join("node_path.rs"), generate_node_path(&messages))?;
+    std::fs::write(out_dir.join("selector.rs"), generate_selector(&messages))?;
+    std::fs::write(out_dir.join("libpg_query_json.rs"), generate_libpg_query_json(&messages))?;
+    std::fs::write(out_dir.join("limits.rs"), generate_limits(&messages))?;
+
     Ok(())
 }
+
+/// A field of a protobuf message.
+struct MessageField {
+    name: String,
+    /// The field number, or 0 for oneofs, whose variants have their own
+    tag: u32,
+    kind: FieldKind,
+    /// Whether an optional message is boxed
+    boxed: bool,
+}
+
+enum FieldKind {
+    /// An optional message, possibly boxed, e.g. `Option<Box<Node>>`
+    Optional(String),
+    /// A repeated message, e.g. `Vec<Node>`
+    Repeated(String),
+    /// A oneof, as the path of its enum (e.g. `a_const::Val`) and its `(variant, message, boxed, tag)` tuples
+    Oneof(String, Vec<(String, String, bool, u32)>),
+    /// A scalar, as its protobuf type, e.g. `string` or `uint32`
+    Scalar(String),
+    /// An enum, as its type name, e.g. `SetOperation`
+    Enumeration(String),
+}
+
+/// The messages of the parse tree, keyed by type name, with their fields.
+struct Messages(std::collections::BTreeMap<String, Vec<MessageField>>);
+
+impl Messages {
+    /// Reads the messages reachable from `ParseResult` out of the prost output.
+    fn parse(protobuf: &str) -> Self {
+        // Drop comments and put every item on one line, as attributes and types may be wrapped
+        let source = protobuf.lines().filter(|line| !line.trim_start().starts_with("//")).collect::<Vec<_>>().join(" ");
+        let source = source.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        // Nested modules hold the oneofs and the summary types, which aren't part of the tree
+        let modules: Vec<_> = source.match_indices("pub mod ").map(|(start, _)| start..start + block_len(&source[start..])).collect();
+        let mut all = std::collections::BTreeMap::new();
+        for (start, _) in source.match_indices("pub struct ") {
+            if !modules.iter().any(|module| module.contains(&start)) {
+                let (name, body) = item_body(&source[start + "pub struct ".len()..]);
+                all.insert(name.to_string(), message_fields(&source, body));
+            }
+        }
+
+        let mut messages = std::collections::BTreeMap::new();
+        let mut queue = vec!["ParseResult".to_string()];
+        while let Some(name) = queue.pop() {
+            if messages.contains_key(&name) {
+                continue;
+            }
+            let fields: Vec<MessageField> = all.remove(&name).unwrap_or_else(|| panic!("unknown message {name}"));
+            for field in &fields {
+                match &field.kind {
+                    FieldKind::Optional(message) | FieldKind::Repeated(message) => queue.push(message.clone()),
+                    FieldKind::Oneof(_, variants) => queue.extend(variants.iter().map(|(_, message, _, _)| message.clone())),
+                    FieldKind::Scalar(_) | FieldKind::Enumeration(_) => {}
+                }
+            }
+            messages.insert(name, fields);
+        }
+        Messages(messages)
+    }
+
+    /// The `Node` variant holding a message, and whether it's boxed there.
+    fn variant(&self, message: &str) -> Option<(&str, bool)> {
+        self.0["Node"].iter().find_map(|field| match &field.kind {
+            FieldKind::Oneof(_, variants) => variants.iter().find(|(_, m, _, _)| m == message).map(|(variant, _, boxed, _)| (variant.as_str(), *boxed)),
+            _ => None,
+        })
+    }
+
+    /// The `snake_case` name used in method names, which follows the `Node` variant where there is one
+    /// (e.g. `create_plang_stmt` for `CreatePLangStmt`).
+    fn method_name(&self, message: &str) -> String {
+        let variant = self.variant(message).map_or(message, |(variant, _)| variant);
+        let mut name = String::new();
+        for (i, c) in variant.chars().enumerate() {
+            if c.is_ascii_uppercase() && i > 0 {
+                name.push('_');
+            }
+            name.push(c.to_ascii_lowercase());
+        }
+        name
+    }
+}
+
+/// Splits `Name { ... } ...` into the name and the text between the braces.
+fn item_body(item: &str) -> (&str, &str) {
+    let open = item.find('{').unwrap();
+    let close = open + item[open..].find('}').unwrap();
+    (item[..open].trim(), &item[open + 1..close])
+}
+
+/// Returns the length of the item starting at `item`, up to and including its closing brace.
+fn block_len(item: &str) -> usize {
+    let mut depth = 0;
+    for (i, c) in item.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 1 => return i + 1,
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    item.len()
+}
+
+/// Reads the fields of a message body.
+fn message_fields(source: &str, body: &str) -> Vec<MessageField> {
+    let mut fields = Vec::new();
+    for field in body.split("#[prost(").skip(1) {
+        let (attribute, declaration) = field.split_once(")]").unwrap();
+        let attribute = attribute.trim();
+        let (name, ty) = declaration.trim().trim_start_matches("pub ").split_once(':').unwrap();
+        let name = name.trim().to_string();
+        let kind = if attribute.starts_with("message") {
+            let message = message_type(ty);
+            if attribute.contains("repeated") {
+                FieldKind::Repeated(message)
+            } else {
+                FieldKind::Optional(message)
+            }
+        } else if let Some(oneof) = attribute.strip_prefix("oneof = \"") {
+            let path = &oneof[..oneof.find('"').unwrap()];
+            let (module, enumeration) = path.split_once("::").unwrap();
+            let module = &source[source.find(&format!("pub mod {module} {{")).unwrap()..];
+            let (_, variants) = item_body(&module[module.find(&format!("pub enum {enumeration} ")).unwrap() + "pub enum ".len()..]);
+            let variants = variants
+                .split("#[prost(")
+                .skip(1)
+                .map(|variant| {
+                    let (attribute, declaration) = variant.split_once(")]").unwrap();
+                    let (name, ty) = declaration.trim().split_once('(').unwrap();
+                    (name.to_string(), message_type(ty), ty.contains("Box<"), tag(attribute))
+                })
+                .collect();
+            FieldKind::Oneof(path.to_string(), variants)
+        } else if let Some(enumeration) = attribute.strip_prefix("enumeration = \"") {
+            FieldKind::Enumeration(enumeration[..enumeration.find('"').unwrap()].to_string())
+        } else if !attribute.contains("repeated") && !attribute.starts_with("map") {
+            FieldKind::Scalar(attribute[..attribute.find(',').unwrap()].to_string())
+        } else {
+            continue;
+        };
+        let boxed = ty.contains("Box<");
+        fields.push(MessageField { name, tag: tag(attribute), kind, boxed });
+    }
+    fields
+}
+
+/// Reads the field number of a prost attribute, e.g. `message, optional, tag = "3"`, or 0 if it has none.
+fn tag(attribute: &str) -> u32 {
+    attribute.split_once("tag = \"").map_or(0, |(_, tag)| tag[..tag.find('"').unwrap()].parse().unwrap())
+}
+
+/// Strips the wrappers of a field type, e.g. `::core::option::Option<::prost::alloc::boxed::Box<Node>>` to `Node`.
+fn message_type(ty: &str) -> String {
+    let ty = ty.trim_end_matches([',', ')', '>', ' ']);
+    ty.rsplit(['<', ':']).next().unwrap().trim().to_string()
+}
+
+/// Generates the `Visitor` trait, or `VisitorMut` if `mutable`, with a `visit_*` method and a `walk_*`
+/// function per message.
+fn generate_visitor(messages: &Messages, mutable: bool) -> String {
+    let (visitor_trait, suffix, reference, lifetime, doc) =
+        if mutable { ("VisitorMut", "_mut", "&mut ", "", VISITOR_MUT_DOC) } else { ("Visitor<'ast>", "", "&'ast ", "'ast, ", VISITOR_DOC) };
+    let borrow = if mutable { "&mut " } else { "&" };
+    let mut methods = String::new();
+    let mut walks = String::new();
+    for (message, fields) in &messages.0 {
+        let name = format!("{}{suffix}", messages.method_name(message));
+        methods.push_str(&format!(
+            "    fn visit_{name}(&mut self, node: {reference}protobuf::{message}) {{\n        walk_{name}(self, node)\n    }}\n\n"
+        ));
+        let mut body = String::new();
+        for field in fields {
+            let field_name = &field.name;
+            match &field.kind {
+                FieldKind::Optional(child) => body.push_str(&format!(
+                    "    if let Some(child) = {borrow}node.{field_name} {{\n        visitor.visit_{}{suffix}(child);\n    }}\n",
+                    messages.method_name(child)
+                )),
+                FieldKind::Repeated(child) => body.push_str(&format!(
+                    "    for child in {borrow}node.{field_name} {{\n        visitor.visit_{}{suffix}(child);\n    }}\n",
+                    messages.method_name(child)
+                )),
+                FieldKind::Oneof(path, variants) => {
+                    body.push_str(&format!("    match {borrow}node.{field_name} {{\n"));
+                    for (variant, child, _, _) in variants {
+                        body.push_str(&format!(
+                            "        Some(protobuf::{path}::{variant}(child)) => visitor.visit_{}{suffix}(child),\n",
+                            messages.method_name(child)
+                        ));
+                    }
+                    body.push_str("        None => {}\n    }\n");
+                }
+                FieldKind::Scalar(_) | FieldKind::Enumeration(_) => {}
+            }
+        }
+        let (visitor, node) = if body.is_empty() { ("_visitor", "_node") } else { ("visitor", "node") };
+        walks.push_str(&format!(
+            "pub fn walk_{name}<{lifetime}V: {visitor_trait} + ?Sized>({visitor}: &mut V, {node}: {reference}protobuf::{message}) {{\n{body}}}\n\n"
+        ));
+    }
+    format!("{doc}pub trait {visitor_trait} {{\n{}}}\n\n{}\n", methods.trim_end(), walks.trim_end())
+}
+
+/// Generates `children`, which lists the child nodes of a node together with their field names, and
+/// the `Fields` implementations that find and replace children mutably.
+fn generate_node_path(messages: &Messages) -> String {
+    let mut children = String::new();
+    let mut fields_mut = String::new();
+    let mut impls = String::new();
+    for (message, fields) in messages.0.iter().filter(|(message, _)| !matches!(message.as_str(), "Node" | "ParseResult")) {
+        let (variant, boxed) = messages.variant(message).unwrap_or_else(|| panic!("{message} isn't a node"));
+        let node = if boxed { "&mut **node" } else { "node" };
+        fields_mut.push_str(&format!("        NodeEnum::{variant}(node) => {node},\n"));
+
+        let mut visit = String::new();
+        let mut find = String::new();
+        for field in fields {
+            let (name, path) = (&field.name, field.name.trim_start_matches("r#"));
+            let as_mut = if field.boxed { "as_deref_mut" } else { "as_mut" };
+            match &field.kind {
+                FieldKind::Optional(child) if child == "Node" => {
+                    visit.push_str(&format!(
+                        "            if let Some(child) = node.{name}.as_ref().and_then(|child| child.node.as_ref()) {{\n                f(\"{path}\", None, child.to_ref());\n            }}\n"
+                    ));
+                    find.push_str(&format!("            (\"{path}\", None) => self.{name}.{as_mut}().map(ChildMut::Node),\n"));
+                }
+                FieldKind::Repeated(child) if child == "Node" => {
+                    visit.push_str(&format!(
+                        "            for (i, child) in node.{name}.iter().enumerate() {{\n                if let Some(child) = &child.node {{\n                    f(\"{path}\", Some(i), child.to_ref());\n                }}\n            }}\n"
+                    ));
+                    find.push_str(&format!("            (\"{path}\", Some(i)) => self.{name}.get_mut(i).map(ChildMut::Node),\n"));
+                }
+                FieldKind::Optional(child) => {
+                    let (child, _) = messages.variant(child).unwrap();
+                    visit.push_str(&format!("            if let Some(child) = &node.{name} {{\n                f(\"{path}\", None, NodeRef::{child}(child));\n            }}\n"));
+                    find.push_str(&format!("            (\"{path}\", None) => self.{name}.{as_mut}().map(|child| ChildMut::Message(child)),\n"));
+                }
+                FieldKind::Repeated(child) => {
+                    let (child, _) = messages.variant(child).unwrap();
+                    visit.push_str(&format!(
+                        "            for (i, child) in node.{name}.iter().enumerate() {{\n                f(\"{path}\", Some(i), NodeRef::{child}(child));\n            }}\n"
+                    ));
+                    find.push_str(&format!("            (\"{path}\", Some(i)) => self.{name}.get_mut(i).map(|child| ChildMut::Message(child)),\n"));
+                }
+                FieldKind::Oneof(oneof, variants) => {
+                    visit.push_str(&format!("            match &node.{name} {{\n"));
+                    find.push_str(&format!("            (\"{path}\", None) => match &mut self.{name} {{\n"));
+                    for (oneof_variant, child, _, _) in variants {
+                        let (child, _) = messages.variant(child).unwrap();
+                        visit.push_str(&format!(
+                            "                Some(protobuf::{oneof}::{oneof_variant}(child)) => f(\"{path}\", None, NodeRef::{child}(child)),\n"
+                        ));
+                        find.push_str(&format!(
+                            "                Some(protobuf::{oneof}::{oneof_variant}(child)) => Some(ChildMut::Message(child)),\n"
+                        ));
+                    }
+                    visit.push_str("                None => {}\n            }\n");
+                    find.push_str("                None => None,\n            },\n");
+                }
+                FieldKind::Scalar(_) | FieldKind::Enumeration(_) => {}
+            }
+        }
+        if !visit.is_empty() {
+            children.push_str(&format!("        NodeRef::{variant}(node) => {{\n{visit}        }}\n"));
+        }
+
+        let find = if find.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "    fn child_mut(&mut self, field: &str, index: Option<usize>) -> Option<ChildMut<'_>> {{\n        match (field, index) {{\n{find}            _ => None,\n        }}\n    }}\n\n"
+            )
+        };
+        let rebox = if boxed { "Box::new(std::mem::replace(self, *node))" } else { "std::mem::replace(self, node)" };
+        impls.push_str(&format!(
+            "impl Fields for protobuf::{message} {{\n{find}    fn replace(&mut self, node: NodeEnum) -> Option<NodeEnum> {{\n        match node {{\n            NodeEnum::{variant}(node) => Some(NodeEnum::{variant}({rebox})),\n            _ => None,\n        }}\n    }}\n}}\n\n"
+        ));
+    }
+    format!(
+        "/// Calls `f` with the field name, list index and node of each child of `node`, in field order.\n\
+         pub(crate) fn children<'a>(node: NodeRef<'a>, f: &mut dyn FnMut(&'static str, Option<usize>, NodeRef<'a>)) {{\n    match node {{\n{children}        _ => {{}}\n    }}\n}}\n\n\
+         pub(crate) fn fields_mut(node: &mut NodeEnum) -> &mut dyn Fields {{\n    match node {{\n{fields_mut}    }}\n}}\n\n{}\n",
+        impls.trim_end()
+    )
+}
+
+/// Generates the node type and field names and scalar field accessors used by selectors and templates.
+fn generate_selector(messages: &Messages) -> String {
+    let mut types = String::new();
+    let mut names = String::new();
+    let mut scalars = String::new();
+    let mut scalar_names = String::new();
+    let mut node_fields = String::new();
+    let all_fields: std::collections::BTreeSet<_> = messages
+        .0
+        .iter()
+        .filter(|(message, _)| *message != "Node")
+        .flat_map(|(_, fields)| fields)
+        .map(|field| field.name.trim_start_matches("r#"))
+        .collect();
+    for (message, fields) in messages.0.iter().filter(|(message, _)| !matches!(message.as_str(), "Node" | "ParseResult")) {
+        let (variant, _) = messages.variant(message).unwrap();
+        types.push_str(&format!("    \"{variant}\",\n"));
+        names.push_str(&format!("        NodeRef::{variant}(_) => \"{variant}\",\n"));
+        let all_names: Vec<_> = fields.iter().map(|field| format!("\"{}\"", field.name.trim_start_matches("r#"))).collect();
+        node_fields.push_str(&format!("        \"{variant}\" => &[{}],\n", all_names.join(", ")));
+        let mut field_names = Vec::new();
+        for field in fields {
+            let (name, path) = (&field.name, field.name.trim_start_matches("r#"));
+            let value = match &field.kind {
+                FieldKind::Scalar(ty) if ty == "string" => format!("node.{name}.clone(), !node.{name}.is_empty()"),
+                FieldKind::Scalar(ty) if ty == "bool" => format!("node.{name}.to_string(), node.{name}"),
+                FieldKind::Scalar(ty) if ty == "double" => format!("node.{name}.to_string(), node.{name} != 0.0"),
+                FieldKind::Scalar(_) => format!("node.{name}.to_string(), node.{name} != 0"),
+                FieldKind::Enumeration(ty) => format!(
+                    "protobuf::{ty}::try_from(node.{name}).map_or_else(|_| node.{name}.to_string(), |value| value.as_str_name().to_string()), node.{name} != 0"
+                ),
+                _ => continue,
+            };
+            scalars.push_str(&format!("        (NodeRef::{variant}(node), \"{path}\") => Some(({value})),\n"));
+            field_names.push(format!("\"{path}\""));
+        }
+        scalar_names.push_str(&format!("        NodeRef::{variant}(_) => &[{}],\n", field_names.join(", ")));
+    }
+    let all_fields: String = all_fields.iter().map(|field| format!("    \"{field}\",\n")).collect();
+    format!(
+        "/// The names of the node types, as in [NodeRef].\n\
+         pub(crate) const NODE_TYPES: &[&str] = &[\n{types}];\n\n\
+         /// The names of the fields of all node types, sorted.\n\
+         pub(crate) const FIELD_NAMES: &[&str] = &[\n{all_fields}];\n\n\
+         /// Returns the names of the fields of a node type, as in [NodeRef].\n\
+         pub(crate) fn node_fields(node_type: &str) -> &'static [&'static str] {{\n    match node_type {{\n{node_fields}        _ => &[],\n    }}\n}}\n\n\
+         /// Returns the name of the type of `node`, as in [NodeRef].\n\
+         pub(crate) fn node_type(node: NodeRef<'_>) -> &'static str {{\n    match node {{\n{names}    }}\n}}\n\n\
+         /// Returns the value of the scalar field `field` of `node` as text, and whether it differs from the default.\n\
+         /// Enums are named as in the protobuf definition, e.g. `SETOP_UNION`.\n\
+         pub(crate) fn scalar_field(node: NodeRef<'_>, field: &str) -> Option<(String, bool)> {{\n    match (node, field) {{\n{scalars}        _ => None,\n    }}\n}}\n\n\
+         /// Returns the names of the scalar fields of `node`.\n\
+         pub(crate) fn scalar_fields(node: NodeRef<'_>) -> &'static [&'static str] {{\n    match node {{\n{scalar_names}    }}\n}}\n"
+    )
+}
+
+/// Generates the schema used to read libpg_query JSON: the message, enum and node fields of each message,
+/// the messages of the `Node` variants named differently, and a lookup of enum values by name.
+fn generate_libpg_query_json(messages: &Messages) -> String {
+    let mut fields = String::new();
+    let mut enumerations = std::collections::BTreeSet::new();
+    for (message, message_fields) in &messages.0 {
+        let mut schema = Vec::new();
+        for field in message_fields {
+            let path = field.name.trim_start_matches("r#");
+            match &field.kind {
+                FieldKind::Optional(child) | FieldKind::Repeated(child) if child == "Node" => schema.push(format!("(\"{path}\", Node)")),
+                FieldKind::Optional(child) | FieldKind::Repeated(child) => schema.push(format!("(\"{path}\", Message(\"{child}\"))")),
+                FieldKind::Enumeration(enumeration) => {
+                    schema.push(format!("(\"{path}\", Enum(\"{enumeration}\"))"));
+                    enumerations.insert(enumeration.as_str());
+                }
+                // The oneofs of `Node` and `AConst` are converted separately
+                FieldKind::Oneof(..) | FieldKind::Scalar(_) => {}
+            }
+        }
+        fields.push_str(&format!("        \"{message}\" => &[{}],\n", schema.join(", ")));
+    }
+
+    let mut node_messages = String::new();
+    for field in &messages.0["Node"] {
+        if let FieldKind::Oneof(_, variants) = &field.kind {
+            for (variant, message, _, _) in variants.iter().filter(|(variant, message, _, _)| variant != message) {
+                node_messages.push_str(&format!("        \"{variant}\" => \"{message}\",\n"));
+            }
+        }
+    }
+
+    let enum_values: String = enumerations
+        .iter()
+        .map(|enumeration| format!("        \"{enumeration}\" => protobuf::{enumeration}::from_str_name(value).map(|v| v as i32),\n"))
+        .collect();
+    format!(
+        "/// The message, enum and node fields of each message, or `None` for unknown messages.\n\
+         fn fields(message: &str) -> Option<&'static [(&'static str, Field)]> {{\n    Some(match message {{\n{fields}        _ => return None,\n    }})\n}}\n\n\
+         /// The message held by each variant of `node::Node`, where its name differs from the variant.\n\
+         fn node_message(variant: &str) -> &str {{\n    match variant {{\n{node_messages}        variant => variant,\n    }}\n}}\n\n\
+         /// Looks up an enum value by its Postgres name, e.g. `SETOP_NONE`.\n\
+         fn enum_value(name: &str, value: &str) -> Option<i32> {{\n    match name {{\n{enum_values}        _ => None,\n    }}\n}}\n"
+    )
+}
+
+/// Generates the table of message fields used to check limits on encoded parse results, indexed by
+/// message in name order.
+fn generate_limits(messages: &Messages) -> String {
+    let index = |message: &str| messages.0.keys().position(|name| name == message).unwrap();
+    let children = |fields: &[MessageField]| {
+        let mut children = Vec::new();
+        for field in fields {
+            match &field.kind {
+                FieldKind::Optional(child) | FieldKind::Repeated(child) => children.push((field.tag, child.clone())),
+                FieldKind::Oneof(_, variants) => children.extend(variants.iter().map(|(_, child, _, tag)| (*tag, child.clone()))),
+                FieldKind::Scalar(_) | FieldKind::Enumeration(_) => {}
+            }
+        }
+        children
+    };
+    // Only messages that can hold nodes need to be walked
+    let mut walked = std::collections::BTreeSet::from(["Node".to_string()]);
+    loop {
+        let more: Vec<_> = messages
+            .0
+            .iter()
+            .filter(|(message, fields)| !walked.contains(*message) && children(fields).iter().any(|(_, child)| walked.contains(child)))
+            .map(|(message, _)| message.clone())
+            .collect();
+        if more.is_empty() {
+            break;
+        }
+        walked.extend(more);
+    }
+    let mut table = String::new();
+    for (message, fields) in &messages.0 {
+        let mut children = children(fields);
+        children.retain(|(_, child)| walked.contains(child));
+        children.sort();
+        let children: Vec<_> = children.iter().map(|(tag, child)| format!("({tag}, {})", index(child))).collect();
+        table.push_str(&format!("    // {message}\n    &[{}],\n", children.join(", ")));
+    }
+    format!(
+        "const PARSE_RESULT: u16 = {};\nconst NODE: u16 = {};\nconst SELECT_STMT: u16 = {};\n\n\
+         /// The fields of each message that can hold nodes, as `(tag, message)` pairs sorted by tag, where `message`\n\
+         /// indexes this table.\n\
+         static MESSAGE_FIELDS: &[&[(u32, u16)]] = &[\n{table}];\n",
+        index("ParseResult"),
+        index("Node"),
+        index("SelectStmt")
+    )
+}
+
+/// Generates `node_tag_name`, which names each tag of Postgres' `NodeTag` enum, from its `T_Name = value,` lines.
+fn generate_node_tags(header: &str) -> String {
+    let mut names = String::new();
+    for line in header.lines() {
+        let Some(tag) = line.trim_start().strip_prefix("T_") else { continue };
+        let name: String = tag.chars().take_while(|c| c.is_ascii_alphanumeric() || *c == '_').collect();
+        names.push_str(&format!("        bindings_raw::NodeTag_T_{name} => \"T_{name}\",\n"));
+    }
+    format!(
+        "/// Returns the name of a node tag, e.g. `T_Aggref`.\n\
+         fn node_tag_name(node_tag: bindings_raw::NodeTag) -> Option<&'static str> {{\n    Some(match node_tag {{\n{names}        _ => return None,\n    }})\n}}\n"
+    )
+}
+
+const VISITOR_DOC: &str = r#"/// Visits every node of a parse tree.
+///
+/// There is a `visit_*` method for each message in [crate::protobuf], which by default visits the
+/// node's children through the `walk_*` function of the same name. Override the methods for the nodes
+/// of interest, and call the `walk_*` function from them to keep descending.
+///
+/// Unlike [crate::protobuf::ParseResult::nodes], this reaches every node, wherever it is nested.
+/// It recurses once per level of nesting, so use [crate::ParseOptions::limits] when visiting trees
+/// parsed from untrusted input.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::protobuf::ColumnRef;
+/// use pg_query::visitor::{walk_column_ref, Visitor};
+///
+/// struct Columns(usize);
+///
+/// impl<'ast> Visitor<'ast> for Columns {
+///     fn visit_column_ref(&mut self, node: &'ast ColumnRef) {
+///         self.0 += 1;
+///         walk_column_ref(self, node);
+///     }
+/// }
+///
+/// let result = pg_query::parse("MERGE INTO t USING s ON t.id = s.id WHEN MATCHED THEN UPDATE SET v = s.v").unwrap();
+/// let mut columns = Columns(0);
+/// columns.visit_parse_result(&result.protobuf);
+/// assert_eq!(columns.0, 3);
+/// ```
+"#;
+
+const VISITOR_MUT_DOC: &str = r#"/// Visits every node of a parse tree mutably, e.g. to rewrite it.
+///
+/// Like [Visitor], with a `visit_*_mut` method and a `walk_*_mut` function per message. Methods get
+/// `&mut` access to their node, so a node can be replaced by assigning to it in `visit_node_mut`, and
+/// children can be removed from or inserted into the lists of their parent. Call the `walk_*_mut`
+/// function before changing a node to rewrite its original children, or after to visit the new ones.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::protobuf::{Node, SelectStmt};
+/// use pg_query::visitor::{walk_node_mut, walk_select_stmt_mut, VisitorMut};
+/// use pg_query::NodeEnum;
+///
+/// /// Replaces constants with `NULL`, and drops `ORDER BY` clauses.
+/// struct Rewrite;
+///
+/// impl VisitorMut for Rewrite {
+///     fn visit_node_mut(&mut self, node: &mut Node) {
+///         if let Some(NodeEnum::AConst(value)) = &mut node.node {
+///             value.val = None;
+///             value.isnull = true;
+///         }
+///         walk_node_mut(self, node);
+///     }
+///
+///     fn visit_select_stmt_mut(&mut self, node: &mut SelectStmt) {
+///         node.sort_clause.clear();
+///         walk_select_stmt_mut(self, node);
+///     }
+/// }
+///
+/// let mut result = pg_query::parse("SELECT * FROM users WHERE id = 1 ORDER BY name").unwrap();
+/// Rewrite.visit_parse_result_mut(&mut result.protobuf);
+/// assert_eq!(result.deparse().unwrap(), "SELECT * FROM users WHERE id = NULL");
+/// ```
+"#;
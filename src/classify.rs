@@ -0,0 +1,226 @@
+//! Classification of statements by what they do, e.g. to route them to a primary or a replica.
+
+use crate::protobuf;
+use crate::resolve::strings;
+use crate::*;
+
+/// What a statement does. Classes are ordered so that a statement gets the greatest class that its
+/// [ClassificationReason]s call for, e.g. a `SELECT` calling `nextval` is [StatementClass::Write].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum StatementClass {
+    /// Only reads data, and can run on a replica: `SELECT`, `SHOW`, `COPY ... TO` (unless its query writes),
+    /// `EXPLAIN` without `ANALYZE`
+    ReadOnly,
+    /// Changes the state of the session, which has to stay on the same connection: `SET`, `PREPARE`,
+    /// `LISTEN`, cursors, advisory locks
+    Session,
+    /// Controls transactions: `BEGIN`, `COMMIT`, `SAVEPOINT`, `SET CONSTRAINTS`, ...
+    Transaction,
+    /// Maintenance commands, such as `VACUUM`, `CLUSTER` or `CHECKPOINT`
+    Utility,
+    /// Modifies data: `INSERT`, `UPDATE`, `DELETE`, `MERGE`, `TRUNCATE`, `COPY ... FROM`, row locks, ...
+    Write,
+    /// Changes the schema or other objects: `CREATE`, `ALTER`, `DROP`, `GRANT`, `SELECT ... INTO`, ...
+    Ddl,
+}
+
+/// Why a statement got its [StatementClass].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClassificationReason {
+    /// The type of the statement, e.g. `InsertStmt`
+    Statement(&'static str),
+    /// `EXPLAIN ANALYZE`, which runs the statement it explains
+    ExplainAnalyze,
+    /// A data-modifying statement in `WITH`, e.g. `WITH x AS (DELETE ...) SELECT ...`, at the path of the CTE
+    DataModifyingCte(NodePath),
+    /// `FOR UPDATE`, `FOR SHARE`, ... at the path of the `SELECT`
+    RowLock(NodePath),
+    /// `SELECT ... INTO`, which creates a table, at the path of the `SELECT`
+    SelectInto(NodePath),
+    /// A call of a function that writes, like `nextval`, or changes the state of the session, like
+    /// `set_config` or `pg_advisory_lock`
+    Function(String, NodePath),
+}
+
+/// The class of a statement, with the reasons for it. See [ParseResult::classify].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Classification {
+    pub class: StatementClass,
+    /// The type of the statement, followed by what made its class greater than the type calls for
+    pub reasons: Vec<ClassificationReason>,
+}
+
+impl Classification {
+    /// Returns whether the statement can run on a replica.
+    pub fn is_read_only(&self) -> bool {
+        self.class == StatementClass::ReadOnly
+    }
+
+    fn add(&mut self, class: StatementClass, reason: ClassificationReason) {
+        self.class = self.class.max(class);
+        self.reasons.push(reason);
+    }
+
+    /// Adds the reasons found within `node` at `path`.
+    fn walk(&mut self, node: NodeRef, path: &NodePath) {
+        match node {
+            NodeRef::CommonTableExpr(cte) => {
+                if let Some(NodeEnum::InsertStmt(_) | NodeEnum::UpdateStmt(_) | NodeEnum::DeleteStmt(_) | NodeEnum::MergeStmt(_)) =
+                    cte.ctequery.as_ref().and_then(|query| query.node.as_ref())
+                {
+                    self.add(StatementClass::Write, ClassificationReason::DataModifyingCte(path.clone()));
+                }
+            }
+            NodeRef::SelectStmt(select) => {
+                if !select.locking_clause.is_empty() {
+                    self.add(StatementClass::Write, ClassificationReason::RowLock(path.clone()));
+                }
+                if select.into_clause.is_some() {
+                    self.add(StatementClass::Ddl, ClassificationReason::SelectInto(path.clone()));
+                }
+            }
+            NodeRef::FuncCall(call) => {
+                if let Some(name) = strings(&call.funcname).pop() {
+                    let class = if WRITE_FUNCTIONS.contains(&name.as_str()) {
+                        Some(StatementClass::Write)
+                    } else if SESSION_FUNCTIONS.contains(&name.as_str()) {
+                        Some(StatementClass::Session)
+                    } else {
+                        None
+                    };
+                    if let Some(class) = class {
+                        self.add(class, ClassificationReason::Function(name, path.clone()));
+                    }
+                }
+            }
+            _ => {}
+        }
+        let mut nodes = Vec::new();
+        children(node, &mut |field, index, child| nodes.push((path.join(field, index), child)));
+        for (path, child) in nodes {
+            self.walk(child, &path);
+        }
+    }
+}
+
+impl protobuf::ParseResult {
+    /// Classifies each statement by what it does. See [ParseResult::classify].
+    pub fn classify(&self) -> Vec<Classification> {
+        self.stmts
+            .iter()
+            .enumerate()
+            .filter_map(|(i, stmt)| stmt.stmt.as_ref().and_then(|stmt| stmt.node.as_ref()).map(|node| (i, node)))
+            .map(|(i, node)| classify(node.to_ref(), &NodePath::default().join("stmts", Some(i))))
+            .collect()
+    }
+}
+
+impl ParseResult {
+    /// Classifies each statement by what it does, e.g. to decide whether it can run on a replica. Besides
+    /// the type of the statement, this looks for data-modifying CTEs, `FOR UPDATE`, `SELECT ... INTO`,
+    /// `EXPLAIN ANALYZE` and calls of functions like `nextval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pg_query::{ClassificationReason, StatementClass};
+    ///
+    /// let result = pg_query::parse("SELECT * FROM users; WITH x AS (DELETE FROM jobs RETURNING *) SELECT * FROM x; SET search_path = app").unwrap();
+    /// let classes: Vec<_> = result.classify().into_iter().map(|classification| classification.class).collect();
+    /// assert_eq!(classes, [StatementClass::ReadOnly, StatementClass::Write, StatementClass::Session]);
+    ///
+    /// let result = pg_query::parse("SELECT nextval('ids')").unwrap();
+    /// let classification = &result.classify()[0];
+    /// assert!(!classification.is_read_only());
+    /// assert!(matches!(&classification.reasons[1], ClassificationReason::Function(name, _) if name == "nextval"));
+    /// ```
+    pub fn classify(&self) -> Vec<Classification> {
+        self.protobuf.classify()
+    }
+}
+
+/// Functions that modify data, or need a transaction ID, so they can't run on a replica.
+const WRITE_FUNCTIONS: &[&str] =
+    &["lo_creat", "lo_create", "lo_from_bytea", "lo_import", "lo_put", "lo_unlink", "nextval", "pg_current_xact_id", "pg_notify", "setval", "txid_current"];
+
+/// Functions that change the state of the session.
+const SESSION_FUNCTIONS: &[&str] = &[
+    "pg_advisory_lock",
+    "pg_advisory_lock_shared",
+    "pg_advisory_unlock",
+    "pg_advisory_unlock_all",
+    "pg_advisory_unlock_shared",
+    "pg_try_advisory_lock",
+    "pg_try_advisory_lock_shared",
+    "set_config",
+];
+
+fn classify(node: NodeRef, path: &NodePath) -> Classification {
+    let statement = ClassificationReason::Statement(node_type(node));
+    let class = match node {
+        NodeRef::ExplainStmt(explain) => {
+            let query = explain.query.as_ref().and_then(|query| query.node.as_ref());
+            return match query {
+                Some(query) if analyze(explain) => {
+                    let mut classification = classify(query.to_ref(), &path.join("query", None));
+                    classification.reasons.splice(0..0, [statement, ClassificationReason::ExplainAnalyze]);
+                    classification
+                }
+                _ => Classification { class: StatementClass::ReadOnly, reasons: vec![statement] },
+            };
+        }
+        NodeRef::SelectStmt(_) | NodeRef::VariableShowStmt(_) => StatementClass::ReadOnly,
+        NodeRef::CopyStmt(copy) if !copy.is_from => match copy.query.as_ref().and_then(|query| query.node.as_ref()) {
+            // The query of `COPY (...) TO` may be a DML statement with `RETURNING`
+            Some(query) => {
+                let mut classification = classify(query.to_ref(), &path.join("query", None));
+                classification.reasons.insert(0, statement);
+                return classification;
+            }
+            None => StatementClass::ReadOnly,
+        },
+        NodeRef::VariableSetStmt(_)
+        | NodeRef::DiscardStmt(_)
+        | NodeRef::PrepareStmt(_)
+        | NodeRef::ExecuteStmt(_)
+        | NodeRef::DeallocateStmt(_)
+        | NodeRef::ListenStmt(_)
+        | NodeRef::UnlistenStmt(_)
+        | NodeRef::DeclareCursorStmt(_)
+        | NodeRef::FetchStmt(_)
+        | NodeRef::ClosePortalStmt(_)
+        | NodeRef::LoadStmt(_) => StatementClass::Session,
+        NodeRef::TransactionStmt(_) | NodeRef::ConstraintsSetStmt(_) => StatementClass::Transaction,
+        NodeRef::VacuumStmt(_) | NodeRef::CheckPointStmt(_) | NodeRef::ClusterStmt(_) | NodeRef::ReindexStmt(_) | NodeRef::AlterSystemStmt(_) => {
+            StatementClass::Utility
+        }
+        NodeRef::InsertStmt(_)
+        | NodeRef::UpdateStmt(_)
+        | NodeRef::DeleteStmt(_)
+        | NodeRef::MergeStmt(_)
+        | NodeRef::TruncateStmt(_)
+        | NodeRef::CopyStmt(_)
+        | NodeRef::CallStmt(_)
+        | NodeRef::DoStmt(_)
+        | NodeRef::LockStmt(_)
+        | NodeRef::NotifyStmt(_)
+        | NodeRef::RefreshMatViewStmt(_) => StatementClass::Write,
+        _ => StatementClass::Ddl,
+    };
+    let mut classification = Classification { class, reasons: vec![statement] };
+    classification.walk(node, path);
+    classification
+}
+
+/// Returns whether the `EXPLAIN` runs its statement.
+fn analyze(explain: &protobuf::ExplainStmt) -> bool {
+    explain.options.iter().any(|option| match &option.node {
+        Some(NodeEnum::DefElem(option)) if option.defname == "analyze" => match option.arg.as_ref().and_then(|arg| arg.node.as_ref()) {
+            Some(NodeEnum::String(value)) => !matches!(value.sval.as_str(), "false" | "off"),
+            Some(NodeEnum::Integer(value)) => value.ival != 0,
+            Some(NodeEnum::Boolean(value)) => value.boolval,
+            _ => true,
+        },
+        _ => false,
+    })
+}
@@ -0,0 +1,143 @@
+//! The columns of each table that statements read and write.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::lineage::Tracer;
+use crate::protobuf::{self, RangeVar};
+use crate::resolve::strings;
+use crate::roles::relation_name;
+use crate::*;
+
+/// The columns of a table that statements read and write. See [ParseResult::column_access].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColumnAccess {
+    /// The columns read anywhere, e.g. in the select list, `WHERE`, `ORDER BY`, `RETURNING` or the values
+    /// of `UPDATE ... SET`
+    pub read: BTreeSet<String>,
+    /// Whether all columns are read, by `*` or `COPY ... TO`
+    pub read_all: bool,
+    /// The columns written by `INSERT`, `UPDATE ... SET`, `ON CONFLICT ... DO UPDATE SET`, `MERGE` actions or
+    /// `COPY ... FROM`
+    pub written: BTreeSet<String>,
+    /// Whether all columns are written, by `INSERT` or `COPY ... FROM` without a column list
+    pub written_all: bool,
+}
+
+impl protobuf::ParseResult {
+    /// Returns the columns each table is read and written through. See [ParseResult::column_access].
+    pub fn column_access(&self) -> BTreeMap<String, ColumnAccess> {
+        let resolution = self.resolve();
+        let mut tables: BTreeMap<String, ColumnAccess> = BTreeMap::new();
+        for table in resolution.tables().iter().filter(|table| table.cte.is_none()) {
+            tables.entry(relation_name(table.node)).or_default();
+        }
+        let mut tracer = Tracer::new(&resolution);
+        for column in resolution.columns() {
+            for source in tracer.column_sources(column.node) {
+                let access = tables.entry(source.table).or_default();
+                if source.column == "*" {
+                    access.read_all = true;
+                } else {
+                    access.read.insert(source.column);
+                }
+            }
+        }
+        for (node, ..) in self.nodes() {
+            match node {
+                NodeRef::InsertStmt(insert) => {
+                    let Some(relation) = &insert.relation else {
+                        continue;
+                    };
+                    let access = access_of(&mut tables, relation);
+                    // `DEFAULT VALUES` has no query, and writes no column explicitly
+                    access.write(target_names(&insert.cols), insert.cols.is_empty() && insert.select_stmt.is_some());
+                    if let Some(on_conflict) = &insert.on_conflict_clause {
+                        access.write(target_names(&on_conflict.target_list), false);
+                    }
+                }
+                NodeRef::UpdateStmt(update) => {
+                    if let Some(relation) = &update.relation {
+                        // `SET (a, b) = ...` has a target for each column, with a `MultiAssignRef` value
+                        access_of(&mut tables, relation).write(target_names(&update.target_list), false);
+                    }
+                }
+                NodeRef::MergeStmt(merge) => {
+                    let Some(relation) = &merge.relation else {
+                        continue;
+                    };
+                    let access = access_of(&mut tables, relation);
+                    for clause in &merge.merge_when_clauses {
+                        let Some(NodeEnum::MergeWhenClause(clause)) = &clause.node else {
+                            continue;
+                        };
+                        match protobuf::CmdType::try_from(clause.command_type) {
+                            Ok(protobuf::CmdType::CmdUpdate) => access.write(target_names(&clause.target_list), false),
+                            Ok(protobuf::CmdType::CmdInsert) => {
+                                access.write(target_names(&clause.target_list), clause.target_list.is_empty() && !clause.values.is_empty())
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                NodeRef::CopyStmt(copy) => {
+                    let Some(relation) = &copy.relation else {
+                        continue;
+                    };
+                    let (access, columns) = (access_of(&mut tables, relation), strings(&copy.attlist));
+                    if copy.is_from {
+                        access.written_all |= columns.is_empty();
+                        access.written.extend(columns);
+                    } else {
+                        access.read_all |= columns.is_empty();
+                        access.read.extend(columns);
+                    }
+                }
+                _ => {}
+            }
+        }
+        tables
+    }
+}
+
+impl ParseResult {
+    /// Returns, for each table the statements reference, the columns they read and write. Columns are
+    /// attributed to tables through aliases, joins, subqueries and CTEs, and a column that can't be told
+    /// apart between several tables, like `id` in `SELECT id FROM a, b`, is attributed to all of them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let result = pg_query::parse("UPDATE users u SET (name, email) = (SELECT o.name, o.email FROM orgs o WHERE o.id = u.org_id) RETURNING *").unwrap();
+    /// let access = result.column_access();
+    /// let users = &access["users"];
+    /// assert_eq!(users.written.iter().collect::<Vec<_>>(), ["email", "name"]);
+    /// assert_eq!(users.read.iter().collect::<Vec<_>>(), ["org_id"]);
+    /// assert!(users.read_all && !users.written_all);
+    /// assert_eq!(access["orgs"].read.iter().collect::<Vec<_>>(), ["email", "id", "name"]);
+    /// ```
+    pub fn column_access(&self) -> BTreeMap<String, ColumnAccess> {
+        self.protobuf.column_access()
+    }
+}
+
+impl ColumnAccess {
+    fn write(&mut self, columns: Vec<String>, all: bool) {
+        self.written.extend(columns);
+        self.written_all |= all;
+    }
+}
+
+fn access_of<'t>(tables: &'t mut BTreeMap<String, ColumnAccess>, relation: &RangeVar) -> &'t mut ColumnAccess {
+    tables.entry(relation_name(relation)).or_default()
+}
+
+/// Returns the column names of the `ResTarget`s in `targets`.
+fn target_names(targets: &[protobuf::Node]) -> Vec<String> {
+    targets
+        .iter()
+        .filter_map(|target| match &target.node {
+            Some(NodeEnum::ResTarget(target)) if !target.name.is_empty() => Some(target.name.clone()),
+            _ => None,
+        })
+        .collect()
+}
@@ -1,5 +1,12 @@
+use std::ffi::CStr;
+use std::fmt;
+use std::os::raw::c_char;
+
 use thiserror::Error;
 
+use crate::bindings::PgQueryError;
+use crate::limits::LimitKind;
+
 /// Error structure representing the basic error scenarios for `pg_query`.
 #[derive(Debug, Error, Eq, PartialEq)]
 pub enum Error {
@@ -8,9 +15,23 @@ pub enum Error {
     #[error("Error decoding result: {0}")]
     Decode(#[from] prost::DecodeError),
     #[error("Invalid statement: {0}")]
-    Parse(String),
+    Syntax(Box<ParseError>),
+    #[error("Error deparsing: {0}")]
+    Deparse(String),
+    #[error("Unexpected input: {0}")]
+    UnexpectedInput(String),
     #[error("Error parsing JSON: {0}")]
     InvalidJson(String),
+    #[error("Unhandled node in raw parse tree: {0}")]
+    UnhandledNode(String),
+    #[error("Statement exceeds the {kind} limit of {limit}")]
+    LimitExceeded { kind: LimitKind, limit: usize },
+    #[error("Invalid node path: {0}")]
+    InvalidPath(String),
+    #[error("Invalid selector: {0}")]
+    InvalidSelector(String),
+    #[error("Invalid statement kind: {0}")]
+    InvalidStatementKind(String),
     #[error("Invalid pointer")]
     InvalidPointer,
     #[error("Error scanning: {0}")]
@@ -19,5 +40,144 @@ pub enum Error {
     Split(String),
 }
 
+/// Detailed information about a statement that failed to parse.
+///
+/// # Example
+///
+/// ```rust
+/// let sql = "SELECT * FROM contacts\nWHERE id = = 1";
+/// let error = match pg_query::parse(sql) {
+///     Err(pg_query::Error::Syntax(error)) => error,
+///     _ => unreachable!(),
+/// };
+/// assert_eq!(error.message, "syntax error at or near \"=\"");
+/// assert_eq!((error.line, error.column), (Some(2), Some(12)));
+/// assert_eq!(error.token.as_deref(), Some("="));
+/// assert_eq!(error.sqlstate, Some("42601"));
+/// assert_eq!(error.render(sql), "ERROR:  syntax error at or near \"=\"\nLINE 2: WHERE id = = 1\n                   ^");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    /// Byte offset into the statement at which the error occurred
+    pub cursor: Option<usize>,
+    /// 1-based line of the cursor
+    pub line: Option<usize>,
+    /// 1-based column of the cursor, counted in characters
+    pub column: Option<usize>,
+    /// The text of the token the parser failed on, if it was reported
+    pub token: Option<String>,
+    /// The SQLSTATE error code, where it can be derived from the message
+    pub sqlstate: Option<&'static str>,
+    /// Postgres source function that raised the error (e.g. `base_yyparse`)
+    pub funcname: Option<String>,
+    /// Postgres source file that raised the error (e.g. `scan.l`)
+    pub filename: Option<String>,
+    /// Line in the Postgres source file that raised the error
+    pub lineno: i32,
+    pub context: Option<String>,
+}
+
+impl ParseError {
+    /// Reads a libpg_query error, resolving its cursor position against the statement that was parsed.
+    ///
+    /// # Safety
+    ///
+    /// `error` must point to a valid `PgQueryError`. The raw bindings use the same C struct, so their
+    /// pointers may be cast to the basic bindings type.
+    pub(crate) unsafe fn from_c(statement: &str, error: *const PgQueryError) -> Self {
+        let error = &*error;
+        let message = c_string(error.message).unwrap_or_default();
+        // cursorpos is a 1-based character position, 0 if unknown
+        let cursor = match error.cursorpos {
+            n if n > 0 => statement.char_indices().map(|(i, _)| i).chain(std::iter::once(statement.len())).nth(n as usize - 1),
+            _ => None,
+        };
+        let (line, column) = match cursor {
+            Some(cursor) => {
+                let (line, column) = line_column(statement, cursor);
+                (Some(line), Some(column))
+            }
+            None => (None, None),
+        };
+        Self {
+            token: token(&message),
+            sqlstate: sqlstate(&message),
+            message,
+            cursor,
+            line,
+            column,
+            funcname: c_string(error.funcname),
+            filename: c_string(error.filename),
+            lineno: error.lineno,
+            context: c_string(error.context),
+        }
+    }
+
+    /// Renders the error together with the offending line of `statement`, with the failing token underlined.
+    ///
+    /// The output mirrors what `psql` prints for syntax errors. `statement` should be the statement that
+    /// failed to parse; if the cursor isn't within it, only the message is rendered.
+    pub fn render(&self, statement: &str) -> String {
+        let mut output = format!("ERROR:  {}", self.message);
+        let (Some(cursor), Some(line)) = (self.cursor, self.line) else {
+            return output;
+        };
+        let Some(before) = statement.get(..cursor) else {
+            return output;
+        };
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line_end = statement[cursor..].find('\n').map(|i| cursor + i).unwrap_or(statement.len());
+        let prefix = format!("LINE {line}: ");
+        let indent = prefix.len() + statement[line_start..cursor].chars().count();
+        let width = self.token.as_ref().map(|t| t.chars().count().min(statement[cursor..line_end].chars().count())).unwrap_or(0).max(1);
+        output.push_str(&format!("\n{prefix}{}\n{}{}", &statement[line_start..line_end], " ".repeat(indent), "^".repeat(width)));
+        output
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// Returns the 1-based line and character column of a byte offset into `statement`. The offset is moved
+/// back to the start of the character it falls within, and to the end of the statement if it's past it.
+pub(crate) fn line_column(statement: &str, offset: usize) -> (usize, usize) {
+    let offset = (0..=offset.min(statement.len())).rev().find(|&offset| statement.is_char_boundary(offset)).unwrap_or(0);
+    let before = &statement[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+    (line, column)
+}
+
+unsafe fn c_string(ptr: *const c_char) -> Option<String> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(ptr).to_string_lossy().to_string())
+    }
+}
+
+// Scanner and grammar errors report the failing token as `... at or near "token"`.
+fn token(message: &str) -> Option<String> {
+    let start = message.rfind(" at or near \"")? + " at or near \"".len();
+    message[start..].strip_suffix('"').map(|t| t.to_string())
+}
+
+// The raw parser only raises a handful of error codes, so these can be told apart by their message.
+fn sqlstate(message: &str) -> Option<&'static str> {
+    if message.starts_with("stack depth limit exceeded") {
+        Some("54001") // statement_too_complex
+    } else if message.contains("not supported") || message.contains("not implemented") {
+        Some("0A000") // feature_not_supported
+    } else if message.starts_with("syntax error") || message.contains(" at or near ") || message.contains(" at end of input") {
+        Some("42601") // syntax_error
+    } else {
+        None
+    }
+}
+
 /// Convenient Result alias for returning `pg_query::Error`.
 pub type Result<T> = core::result::Result<T, Error>;
@@ -40,9 +40,16 @@
 
 mod bindings;
 mod bindings_raw;
+mod classify;
+mod column_access;
 mod error;
+mod libpg_query_json;
+mod limits;
+mod lineage;
+mod locks;
 mod node_enum;
 mod node_mut;
+mod node_path;
 mod node_ref;
 mod node_structs;
 mod parse_result;
@@ -53,23 +60,52 @@ mod raw_deparse;
 mod raw_fingerprint;
 mod raw_parse;
 mod raw_scan;
+mod raw_tree;
+mod resolve;
+mod roles;
+mod script;
+mod selector;
+mod span;
+mod statement_kind;
+mod structural;
 mod summary;
 mod summary_result;
+mod template;
+mod transaction;
+mod triggers;
 mod truncate;
+pub mod visitor;
 
+pub use classify::*;
+pub use column_access::*;
 pub use error::*;
+pub use limits::*;
+pub use lineage::*;
+pub use locks::*;
 pub use node_enum::*;
 pub use node_mut::*;
+pub use node_path::*;
 pub use node_ref::*;
 pub use parse_result::*;
 pub use query::*;
 pub use raw_deparse::deparse_raw;
 pub use raw_fingerprint::fingerprint_raw;
-pub use raw_parse::parse_raw;
+pub use raw_parse::{parse_raw, parse_raw_strict, parse_raw_strict_with_options, parse_raw_with_options};
 pub use raw_scan::scan_raw;
+pub use raw_tree::*;
+pub use resolve::*;
+pub use roles::*;
+pub use script::*;
+pub use selector::*;
+pub use statement_kind::*;
+pub use structural::*;
 pub use summary::*;
 pub use summary_result::*;
+pub use template::*;
+pub use transaction::*;
+pub use triggers::*;
 pub use truncate::*;
+pub use visitor::{Visitor, VisitorMut};
 
 pub use protobuf::Node;
 
@@ -0,0 +1,117 @@
+//! Reading parse trees in the JSON format of libpg_query, as exchanged by the Ruby, Go and Python bindings.
+//!
+//! libpg_query names fields and node types after the Postgres structs (`targetList`, `A_Const`), encodes
+//! enums by name, and leaves out fields holding default values. The serde representation of the protobuf
+//! types (see [crate::ParseResult::to_json]) uses the Rust names and numeric enums instead, and wraps nodes
+//! in a `Node` message. Reading libpg_query JSON rewrites it into the latter, using a schema generated from
+//! the protobuf types, which lists the fields of each message that aren't scalars.
+
+use serde_json::{Map, Value};
+
+use crate::protobuf;
+use crate::{Error, Result};
+
+#[derive(Clone, Copy)]
+enum Field {
+    Node,
+    Message(&'static str),
+    Enum(&'static str),
+}
+use Field::*;
+
+/// Rewrites a libpg_query JSON parse tree into the serde representation of [protobuf::ParseResult].
+pub(crate) fn convert(value: Value) -> Result<Value> {
+    convert_message(value, "ParseResult").map_err(Error::InvalidJson)
+}
+
+fn convert_message(value: Value, message: &str) -> std::result::Result<Value, String> {
+    // Messages nest as deep as the tree, so grow the stack like serde_stacker does while reading the JSON
+    stacker::maybe_grow(64 * 1024, 2 * 1024 * 1024, || convert_fields(value, message))
+}
+
+fn convert_fields(value: Value, message: &str) -> std::result::Result<Value, String> {
+    let Value::Object(object) = value else {
+        return Err(format!("expected an object for {message}, got {value}"));
+    };
+    let mut converted = Map::new();
+    for (key, value) in object {
+        let name = snake_case(&key);
+        // A_Const holds its value in a oneof, whose fields libpg_query writes inline
+        if message == "AConst" {
+            if let Some(value_message) = a_const_value(&name) {
+                converted.insert("val".into(), Value::Object(Map::from_iter([(upper_camel_case(&name), convert_message(value, value_message)?)])));
+                continue;
+            }
+        }
+        let field = fields(message).and_then(|fields| fields.iter().find(|(field, _)| *field == name)).map(|(_, field)| *field);
+        converted.insert(name, convert_value(value, field)?);
+    }
+    Ok(Value::Object(converted))
+}
+
+fn convert_value(value: Value, field: Option<Field>) -> std::result::Result<Value, String> {
+    match (value, field) {
+        (Value::Array(values), Some(_)) => {
+            Ok(Value::Array(values.into_iter().map(|value| convert_value(value, field)).collect::<std::result::Result<_, _>>()?))
+        }
+        (value, Some(Node)) => convert_node(value),
+        (value, Some(Message(message))) => convert_message(value, message),
+        (Value::String(name), Some(Enum(enumeration))) => {
+            enum_value(enumeration, &name).map(Value::from).ok_or_else(|| format!("unknown {enumeration} value: {name}"))
+        }
+        (value, _) => Ok(value),
+    }
+}
+
+/// libpg_query writes nodes as `{"A_Expr": {...}}`, where the key is the name of the Postgres struct.
+fn convert_node(value: Value) -> std::result::Result<Value, String> {
+    let node = match value {
+        Value::Object(object) if object.len() == 1 => object.into_iter().next().unwrap(),
+        value => return Err(format!("expected a node, got {value}")),
+    };
+    let variant = upper_camel_case(&snake_case(&node.0));
+    if fields(node_message(&variant)).is_none() {
+        return Err(format!("unknown node type: {}", node.0));
+    }
+    let converted = convert_message(node.1, node_message(&variant))?;
+    Ok(Value::Object(Map::from_iter([("node".into(), Value::Object(Map::from_iter([(variant, converted)])))])))
+}
+
+fn a_const_value(field: &str) -> Option<&'static str> {
+    match field {
+        "ival" => Some("Integer"),
+        "fval" => Some("Float"),
+        "boolval" => Some("Boolean"),
+        "sval" => Some("String"),
+        "bsval" => Some("BitString"),
+        _ => None,
+    }
+}
+
+/// `targetList` → `target_list`, `A_Expr` → `a_expr`, `PLAssignStmt` → `plassign_stmt`, matching how the
+/// protobuf definitions derive their names from the Postgres ones.
+fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    let mut previous: Option<char> = None;
+    for c in name.chars() {
+        if c.is_ascii_uppercase() && previous.is_some_and(|p| p.is_ascii_lowercase() || p.is_ascii_digit()) {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase());
+        previous = Some(c);
+    }
+    snake
+}
+
+/// `a_expr` → `AExpr`, like prost names the variants of a oneof.
+fn upper_camel_case(name: &str) -> String {
+    name.split('_')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars.next().map(|c| c.to_ascii_uppercase()).into_iter().chain(chars)
+        })
+        .collect()
+}
+
+// The schema, generated from the protobuf types by build.rs
+include!(concat!(env!("OUT_DIR"), "/libpg_query_json.rs"));
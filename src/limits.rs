@@ -0,0 +1,151 @@
+use std::fmt;
+
+use crate::{Error, Result};
+
+/// Limits on the size of statements and their parse trees, for parsing SQL from untrusted sources.
+///
+/// Deeply nested statements can overflow the stack when their tree is decoded, converted or dropped,
+/// and large ones can exhaust memory. Set the limits in [crate::ParseOptions::limits] to have
+/// [crate::parse_with_options], [crate::parse_raw_with_options] and [crate::summary_with_options]
+/// return [Error::LimitExceeded] instead. The depth and node count of a tree are checked before it is
+/// built, so [crate::summary_with_options], which doesn't build one, only checks the input length and
+/// statement count. [crate::protobuf::ParseResult::nodes_with_limits] checks them while walking a tree.
+///
+/// Every limit is unset by default.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::{Error, LimitKind, ParseLimits, ParseOptions};
+///
+/// let options = ParseOptions { limits: ParseLimits { max_depth: Some(32), ..ParseLimits::default() }, ..ParseOptions::default() };
+/// let query = format!("SELECT 1{}", " + 1".repeat(50));
+/// assert!(matches!(pg_query::parse_with_options(&query, options), Err(Error::LimitExceeded { kind: LimitKind::Depth, limit: 32 })));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ParseLimits {
+    /// Maximum length of the statement, in bytes
+    pub max_input_bytes: Option<usize>,
+    /// Maximum nesting depth of nodes, counting each statement as depth 1
+    pub max_depth: Option<usize>,
+    /// Maximum number of nodes across all statements
+    pub max_nodes: Option<usize>,
+    /// Maximum number of statements
+    pub max_statements: Option<usize>,
+}
+
+/// The limit that was exceeded, see [Error::LimitExceeded].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LimitKind {
+    InputBytes,
+    Depth,
+    Nodes,
+    Statements,
+}
+
+impl fmt::Display for LimitKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LimitKind::InputBytes => "input length",
+            LimitKind::Depth => "nesting depth",
+            LimitKind::Nodes => "node count",
+            LimitKind::Statements => "statement count",
+        })
+    }
+}
+
+impl ParseLimits {
+    /// Returns the limit of the given kind, if it is set.
+    pub fn limit(&self, kind: LimitKind) -> Option<usize> {
+        match kind {
+            LimitKind::InputBytes => self.max_input_bytes,
+            LimitKind::Depth => self.max_depth,
+            LimitKind::Nodes => self.max_nodes,
+            LimitKind::Statements => self.max_statements,
+        }
+    }
+
+    /// Fails if `value` is over the limit of the given kind.
+    pub(crate) fn check(&self, kind: LimitKind, value: usize) -> Result<()> {
+        match self.limit(kind) {
+            Some(limit) if value > limit => Err(Error::LimitExceeded { kind, limit }),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn check_input(&self, statement: &str) -> Result<()> {
+        self.check(LimitKind::InputBytes, statement.len())
+    }
+
+    /// Checks the depth, node and statement limits against an encoded [crate::protobuf::ParseResult].
+    ///
+    /// Decoding recurses once per nesting level, so this walks the encoding instead, using
+    /// [MESSAGE_FIELDS] to find nested messages. Nodes are counted as in [crate::parse_raw]: every
+    /// non-empty `Node`, plus the arms of set operations, which are nested `SelectStmt`s.
+    pub(crate) fn check_encoded(&self, data: &[u8]) -> Result<()> {
+        if self.max_depth.is_none() && self.max_nodes.is_none() && self.max_statements.is_none() {
+            return Ok(());
+        }
+        // (message, end offset, whether it counts as a node)
+        let mut stack = vec![(PARSE_RESULT, data.len(), false)];
+        let (mut pos, mut depth, mut nodes, mut statements) = (0, 0, 0, 0);
+        while let Some(&(message, end, is_node)) = stack.last() {
+            if pos >= end {
+                stack.pop();
+                depth -= is_node as usize;
+                continue;
+            }
+            // Malformed input is left for the decoder to report
+            let Some(key) = read_varint(data, &mut pos) else { break };
+            match key & 7 {
+                0 => {
+                    read_varint(data, &mut pos);
+                }
+                1 => pos += 8,
+                2 => {
+                    let Some(len) = read_varint(data, &mut pos) else { break };
+                    let (start, len) = (pos, len as usize);
+                    pos = pos.saturating_add(len);
+                    if message == PARSE_RESULT {
+                        statements += 1;
+                        self.check(LimitKind::Statements, statements)?;
+                    }
+                    let fields = MESSAGE_FIELDS[message as usize];
+                    let Ok(field) = fields.binary_search_by_key(&(key >> 3), |&(tag, _)| tag as u64) else { continue };
+                    let child = fields[field].1;
+                    if len == 0 {
+                        continue;
+                    }
+                    let child_is_node = child == NODE || (child == SELECT_STMT && message == SELECT_STMT);
+                    if child_is_node {
+                        depth += 1;
+                        nodes += 1;
+                        self.check(LimitKind::Depth, depth)?;
+                        self.check(LimitKind::Nodes, nodes)?;
+                    }
+                    stack.push((child, pos, child_is_node));
+                    pos = start;
+                }
+                5 => pos += 4,
+                _ => break,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn read_varint(data: &[u8], pos: &mut usize) -> Option<u64> {
+    let mut value = 0;
+    for shift in (0..64).step_by(7) {
+        let byte = *data.get(*pos)?;
+        *pos += 1;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte < 0x80 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+// The message fields, generated from the protobuf types by build.rs
+include!(concat!(env!("OUT_DIR"), "/limits.rs"));
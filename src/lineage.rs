@@ -0,0 +1,449 @@
+//! Column-level lineage: the table columns each output column of a query is computed from.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::rc::Rc;
+
+use crate::protobuf::{self, ColumnRef};
+use crate::resolve::{column_name, strings};
+use crate::roles::relation_name;
+use crate::*;
+
+/// A column of a table that an output column is computed from.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SourceColumn {
+    /// The name of the table, with its schema if given
+    pub table: String,
+    /// The name of the column, or `*` for all of its columns
+    pub column: String,
+}
+
+impl fmt::Display for SourceColumn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.table, self.column)
+    }
+}
+
+/// Where an output column of a top-level `SELECT` comes from. See [ParseResult::column_lineage].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnLineage {
+    /// The name of the output column, or `None` for `*`
+    pub name: Option<String>,
+    /// The path of the `ResTarget`, or of the expression in the first row of `VALUES`
+    pub path: NodePath,
+    /// The table columns the value is computed from
+    pub sources: BTreeSet<SourceColumn>,
+    /// Whether the value is computed by an aggregate or window function, like `sum(amount)`, here or in
+    /// a subquery or CTE it's taken from
+    pub aggregate: bool,
+    /// Whether the value only depends on constants and parameters, not on any column
+    pub constant: bool,
+}
+
+impl protobuf::ParseResult {
+    /// Returns the lineage of the output columns of the `SELECT` statements. See [ParseResult::column_lineage].
+    pub fn column_lineage(&self) -> Vec<ColumnLineage> {
+        let resolution = self.resolve();
+        let mut tracer = Tracer::new(&resolution);
+        let mut lineage = Vec::new();
+        for (i, stmt) in self.stmts.iter().enumerate() {
+            let Some(node) = stmt.stmt.as_ref().and_then(|stmt| stmt.node.as_ref()).map(NodeEnum::to_ref) else {
+                continue;
+            };
+            if let NodeRef::SelectStmt(_) = node {
+                let outputs = tracer.outputs(node, &NodePath::default().join("stmts", Some(i)));
+                lineage.extend(outputs.into_iter().map(|Output { name, path, lineage: traced, .. }| ColumnLineage {
+                    name,
+                    path,
+                    sources: traced.sources,
+                    aggregate: traced.aggregate,
+                    constant: traced.constant,
+                }));
+            }
+        }
+        lineage
+    }
+}
+
+impl ParseResult {
+    /// Returns, for each output column of the `SELECT` statements, the table columns it's computed from,
+    /// following column references through subqueries, CTEs, set operations and expressions.
+    ///
+    /// There is no catalog, so `*` is a single output column, and a column that can't be told apart
+    /// between several tables, like `id` in `SELECT id FROM a, b`, is taken from all of them. Columns of
+    /// functions in `FROM` have no sources.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let result = pg_query::parse(
+    ///     "WITH totals AS (SELECT o.user_id, sum(o.amount * i.qty) AS total FROM orders o JOIN line_items i ON i.order_id = o.id GROUP BY 1)
+    ///      SELECT u.name, t.total, 1 AS one FROM users u JOIN totals t ON t.user_id = u.id",
+    /// )
+    /// .unwrap();
+    /// let lineage = result.column_lineage();
+    /// let sources: Vec<Vec<String>> = lineage.iter().map(|column| column.sources.iter().map(ToString::to_string).collect()).collect();
+    /// assert_eq!(sources, [vec!["users.name"], vec!["line_items.qty", "orders.amount"], vec![]]);
+    /// assert_eq!(lineage[1].name.as_deref(), Some("total"));
+    /// assert!(lineage[1].aggregate && !lineage[0].aggregate);
+    /// assert!(lineage[2].constant);
+    /// ```
+    pub fn column_lineage(&self) -> Vec<ColumnLineage> {
+        self.protobuf.column_lineage()
+    }
+}
+
+/// Functions that are known to be aggregates without a catalog, even without `DISTINCT`, `ORDER BY`,
+/// `FILTER` or `OVER`.
+const AGGREGATES: &[&str] = &[
+    "any_value",
+    "array_agg",
+    "avg",
+    "bit_and",
+    "bit_or",
+    "bit_xor",
+    "bool_and",
+    "bool_or",
+    "corr",
+    "count",
+    "covar_pop",
+    "covar_samp",
+    "every",
+    "json_agg",
+    "json_object_agg",
+    "jsonb_agg",
+    "jsonb_object_agg",
+    "max",
+    "min",
+    "mode",
+    "percentile_cont",
+    "percentile_disc",
+    "range_agg",
+    "range_intersect_agg",
+    "stddev",
+    "stddev_pop",
+    "stddev_samp",
+    "string_agg",
+    "sum",
+    "var_pop",
+    "var_samp",
+    "variance",
+    "xmlagg",
+];
+
+#[derive(Clone, Default)]
+struct Lineage {
+    sources: BTreeSet<SourceColumn>,
+    aggregate: bool,
+    constant: bool,
+}
+
+impl Lineage {
+    fn constant() -> Self {
+        Self { constant: true, ..Self::default() }
+    }
+
+    fn source(table: String, column: &str) -> Self {
+        Self { sources: BTreeSet::from([SourceColumn { table, column: column.to_string() }]), ..Self::default() }
+    }
+
+    fn merge(&mut self, other: Lineage) {
+        self.sources.extend(other.sources);
+        self.aggregate |= other.aggregate;
+        self.constant &= other.constant;
+    }
+}
+
+/// An output column of a query.
+struct Output {
+    name: Option<String>,
+    path: NodePath,
+    lineage: Lineage,
+    /// The range entries of `*`
+    star: Vec<usize>,
+}
+
+/// Traces columns through the queries of a [Resolution].
+pub(crate) struct Tracer<'a, 'r> {
+    resolution: &'r Resolution<'a>,
+    /// The CTEs being traced, to stop at recursive references
+    ctes: Vec<usize>,
+    /// The number of recursive references stopped at so far
+    cuts: usize,
+    /// The output columns of the queries of subqueries and CTEs, by the address of their node
+    traced: HashMap<usize, Rc<Vec<Output>>>,
+}
+
+impl<'a, 'r> Tracer<'a, 'r> {
+    pub(crate) fn new(resolution: &'r Resolution<'a>) -> Self {
+        Self { resolution, ctes: Vec::new(), cuts: 0, traced: HashMap::new() }
+    }
+
+    /// Returns the table columns a column reference is taken from, through subqueries and CTEs. `*`
+    /// stands for all the columns of a table.
+    pub(crate) fn column_sources(&mut self, column: &ColumnRef) -> BTreeSet<SourceColumn> {
+        self.column(column).sources
+    }
+
+    /// Returns the output columns of `query` at `path`, which is a `SELECT`, or a DML statement with `RETURNING`.
+    fn outputs(&mut self, query: NodeRef<'a>, path: &NodePath) -> Vec<Output> {
+        let select = match query {
+            NodeRef::SelectStmt(select) => select,
+            NodeRef::InsertStmt(insert) => return self.targets(&insert.returning_list, path, "returning_list"),
+            NodeRef::UpdateStmt(update) => return self.targets(&update.returning_list, path, "returning_list"),
+            NodeRef::DeleteStmt(delete) => return self.targets(&delete.returning_list, path, "returning_list"),
+            NodeRef::MergeStmt(merge) => return self.targets(&merge.returning_list, path, "returning_list"),
+            _ => return Vec::new(),
+        };
+        if let (Some(larg), Some(rarg)) = (&select.larg, &select.rarg) {
+            // The columns of a set operation are named after its left side, and come from both
+            let mut outputs = self.outputs(NodeRef::SelectStmt(larg), &path.join("larg", None));
+            for (output, other) in outputs.iter_mut().zip(self.outputs(NodeRef::SelectStmt(rarg), &path.join("rarg", None))) {
+                output.lineage.merge(other.lineage);
+                output.star.extend(other.star);
+            }
+            return outputs;
+        }
+        if !select.values_lists.is_empty() {
+            let mut outputs: Vec<Output> = Vec::new();
+            for (i, row) in select.values_lists.iter().enumerate() {
+                let Some(NodeEnum::List(row)) = &row.node else {
+                    continue;
+                };
+                for (j, item) in row.items.iter().enumerate() {
+                    let lineage = item.node.as_ref().map_or_else(Lineage::constant, |item| self.expression(item.to_ref()));
+                    match outputs.get_mut(j) {
+                        Some(output) => output.lineage.merge(lineage),
+                        None => {
+                            let path = path.join("values_lists", Some(i)).join("items", Some(j));
+                            outputs.push(Output { name: Some(format!("column{}", j + 1)), path, lineage, star: Vec::new() });
+                        }
+                    }
+                }
+            }
+            return outputs;
+        }
+        self.targets(&select.target_list, path, "target_list")
+    }
+
+    /// Returns the output columns of the target list in `field` of the query at `path`.
+    fn targets(&mut self, targets: &'a [protobuf::Node], path: &NodePath, field: &'static str) -> Vec<Output> {
+        let mut outputs = Vec::new();
+        for (i, target) in targets.iter().enumerate() {
+            let Some(NodeEnum::ResTarget(target)) = &target.node else {
+                continue;
+            };
+            let Some(val) = target.val.as_ref().and_then(|val| val.node.as_ref()) else {
+                continue;
+            };
+            let star = match val {
+                NodeEnum::ColumnRef(column) if matches!(column.fields.last().and_then(|field| field.node.as_ref()), Some(NodeEnum::AStar(_))) => {
+                    match self.resolution.column(column).map(|column| &column.binding) {
+                        Some(ColumnBinding::Star(entries)) => Some(entries.clone()),
+                        Some(ColumnBinding::Entry(entry)) => Some(vec![*entry]),
+                        _ => Some(Vec::new()),
+                    }
+                }
+                _ => None,
+            };
+            let name = if target.name.is_empty() { column_name(val) } else { Some(target.name.clone()) };
+            outputs.push(Output { name, path: path.join(field, Some(i)), lineage: self.expression(val.to_ref()), star: star.unwrap_or_default() });
+        }
+        outputs
+    }
+
+    /// Returns the lineage of the value of an expression.
+    fn expression(&mut self, node: NodeRef<'a>) -> Lineage {
+        let mut lineage = Lineage::constant();
+        match node {
+            NodeRef::ColumnRef(column) => return self.column(column),
+            NodeRef::SubLink(link) => {
+                // The value comes from the output of the subquery, not from the columns it filters on
+                if let Some(test) = link.testexpr.as_ref().and_then(|test| test.node.as_ref()) {
+                    lineage.merge(self.expression(test.to_ref()));
+                }
+                if let Some(subselect) = link.subselect.as_ref().and_then(|subselect| subselect.node.as_ref()) {
+                    for output in self.outputs(subselect.to_ref(), &NodePath::default()) {
+                        lineage.merge(output.lineage);
+                    }
+                }
+                return lineage;
+            }
+            NodeRef::FuncCall(call) => lineage.aggregate = is_aggregate(call),
+            _ => {}
+        }
+        let mut nodes = Vec::new();
+        children(node, &mut |_, _, child| nodes.push(child));
+        for child in nodes {
+            lineage.merge(self.expression(child));
+        }
+        lineage
+    }
+
+    fn column(&mut self, column: &ColumnRef) -> Lineage {
+        let name = match column.fields.last().and_then(|field| field.node.as_ref()) {
+            Some(NodeEnum::String(name)) => Some(name.sval.as_str()),
+            _ => None,
+        };
+        let resolution = self.resolution;
+        let Some(reference) = resolution.column(column) else {
+            return Lineage::default();
+        };
+        match (&reference.binding, name) {
+            (ColumnBinding::Entry(entry), Some(name)) if column.fields.len() == 1 && self.is_whole_row(*entry, name) => self.entry_star(*entry),
+            (ColumnBinding::Entry(entry), Some(name)) => self.entry_column(*entry, name),
+            (ColumnBinding::Entry(entry), None) => self.entry_star(*entry),
+            (ColumnBinding::Ambiguous(entries), Some(name)) => self.merged(entries, |tracer, entry| tracer.entry_column(entry, name)),
+            (ColumnBinding::Star(entries), _) => self.merged(entries, |tracer, entry| tracer.entry_star(entry)),
+            _ => Lineage::default(),
+        }
+    }
+
+    fn merged(&mut self, entries: &[usize], mut lineage_of: impl FnMut(&mut Self, usize) -> Lineage) -> Lineage {
+        let mut lineage = Lineage::constant();
+        for &entry in entries {
+            lineage.merge(lineage_of(self, entry));
+        }
+        lineage
+    }
+
+    /// Returns the lineage of the column `name` of a range entry.
+    fn entry_column(&mut self, entry: usize, name: &str) -> Lineage {
+        let kind = self.resolution.entries()[entry].kind;
+        match kind {
+            RangeEntryKind::Relation(relation, None) => Lineage::source(relation_name(relation), name),
+            RangeEntryKind::Relation(_, Some(cte)) => self.cte(cte, |tracer, query, columns| tracer.query_column(query, &columns, name)),
+            RangeEntryKind::Cte(_) => self.cte(entry, |tracer, query, columns| tracer.query_column(query, &columns, name)),
+            RangeEntryKind::Subquery(subselect) => {
+                let columns = subselect.alias.as_ref().map_or_else(Vec::new, |alias| strings(&alias.colnames));
+                self.query_column(subselect.subquery.as_deref(), &columns, name)
+            }
+            RangeEntryKind::Join(_) => {
+                let sides: Vec<_> = self.join_sides(entry).into_iter().filter(|&side| self.may_have(side, name)).collect();
+                self.merged(&sides, |tracer, side| tracer.entry_column(side, name))
+            }
+            RangeEntryKind::Function(_) | RangeEntryKind::TableFunction(_) | RangeEntryKind::Excluded => Lineage::default(),
+        }
+    }
+
+    /// Returns the lineage of all the columns of a range entry, as for `t.*`.
+    fn entry_star(&mut self, entry: usize) -> Lineage {
+        let kind = self.resolution.entries()[entry].kind;
+        match kind {
+            RangeEntryKind::Relation(relation, None) => Lineage::source(relation_name(relation), "*"),
+            RangeEntryKind::Relation(_, Some(cte)) => self.cte(cte, |tracer, query, _| tracer.query_star(query)),
+            RangeEntryKind::Cte(_) => self.cte(entry, |tracer, query, _| tracer.query_star(query)),
+            RangeEntryKind::Subquery(subselect) => self.query_star(subselect.subquery.as_deref()),
+            RangeEntryKind::Join(_) => {
+                let sides = self.join_sides(entry);
+                self.merged(&sides, |tracer, side| tracer.entry_star(side))
+            }
+            RangeEntryKind::Function(_) | RangeEntryKind::TableFunction(_) | RangeEntryKind::Excluded => Lineage::default(),
+        }
+    }
+
+    /// Traces the query of the CTE `entry` with its column names, unless it's already being traced.
+    fn cte(&mut self, entry: usize, trace: impl FnOnce(&mut Self, Option<&'a protobuf::Node>, Vec<String>) -> Lineage) -> Lineage {
+        let RangeEntryKind::Cte(cte) = self.resolution.entries()[entry].kind else {
+            return Lineage::default();
+        };
+        if self.ctes.contains(&entry) {
+            self.cuts += 1;
+            return Lineage::default();
+        }
+        self.ctes.push(entry);
+        let lineage = trace(self, cte.ctequery.as_deref(), strings(&cte.aliascolnames));
+        self.ctes.pop();
+        lineage
+    }
+
+    /// Returns the lineage of the output column `name` of a query, whose columns may be renamed by `columns`.
+    fn query_column(&mut self, query: Option<&'a protobuf::Node>, columns: &[String], name: &str) -> Lineage {
+        let Some(query) = query.and_then(|query| query.node.as_ref()) else {
+            return Lineage::default();
+        };
+        let outputs = self.query_outputs(query);
+        // Aliased column names rename the first output columns
+        let index = columns
+            .iter()
+            .position(|column| column == name)
+            .or_else(|| outputs.iter().skip(columns.len()).position(|output| output.name.as_deref() == Some(name)).map(|index| index + columns.len()));
+        if let Some(index) = index {
+            return outputs.get(index).map_or_else(Lineage::default, |output| output.lineage.clone());
+        }
+        // Otherwise the column can only come from a `*`
+        let entries: Vec<_> = outputs.iter().flat_map(|output| output.star.iter().copied()).filter(|&entry| self.may_have(entry, name)).collect();
+        if entries.is_empty() {
+            return Lineage::default();
+        }
+        self.merged(&entries, |tracer, entry| tracer.entry_column(entry, name))
+    }
+
+    fn query_star(&mut self, query: Option<&'a protobuf::Node>) -> Lineage {
+        let mut lineage = Lineage::constant();
+        if let Some(query) = query.and_then(|query| query.node.as_ref()) {
+            for output in self.query_outputs(query).iter() {
+                lineage.merge(output.lineage.clone());
+            }
+        }
+        lineage
+    }
+
+    /// Returns the output columns of the query of a subquery or CTE, tracing them only once. Outputs that
+    /// stopped at a recursive reference depend on the CTEs being traced, so they aren't kept.
+    fn query_outputs(&mut self, query: &'a NodeEnum) -> Rc<Vec<Output>> {
+        let address = query as *const NodeEnum as usize;
+        if let Some(outputs) = self.traced.get(&address) {
+            return outputs.clone();
+        }
+        let cuts = self.cuts;
+        let outputs = Rc::new(self.outputs(query.to_ref(), &NodePath::default()));
+        if self.cuts == cuts {
+            self.traced.insert(address, outputs.clone());
+        }
+        outputs
+    }
+
+    /// Returns whether a range entry has, or may have, the column `name`.
+    fn may_have(&self, entry: usize, name: &str) -> bool {
+        self.resolution.entries()[entry].columns.as_ref().is_none_or(|columns| columns.iter().any(|column| column == name))
+    }
+
+    /// Returns whether the unqualified column name `name` bound to `entry` stands for its whole row, as it
+    /// names the entry rather than one of its known columns.
+    fn is_whole_row(&self, entry: usize, name: &str) -> bool {
+        let entry = &self.resolution.entries()[entry];
+        entry.name.as_deref() == Some(name) && !entry.columns.as_ref().is_some_and(|columns| columns.iter().any(|column| column == name))
+    }
+
+    /// Returns the range entries directly within the join `entry`, looking through joins without an entry.
+    fn join_sides(&self, entry: usize) -> Vec<usize> {
+        let entries = self.resolution.entries();
+        let join = &entries[entry];
+        let within: Vec<_> = (0..entry).filter(|&other| entries[other].scope == join.scope && is_within(&entries[other].path, &join.path)).collect();
+        within
+            .iter()
+            .copied()
+            .filter(|&side| {
+                !within.iter().any(|&outer| {
+                    matches!(entries[outer].kind, RangeEntryKind::Join(_)) && is_within(&entries[side].path, &entries[outer].path)
+                })
+            })
+            .collect()
+    }
+}
+
+fn is_aggregate(call: &protobuf::FuncCall) -> bool {
+    call.agg_star
+        || call.agg_distinct
+        || call.agg_within_group
+        || call.agg_filter.is_some()
+        || call.over.is_some()
+        || !call.agg_order.is_empty()
+        || strings(&call.funcname).last().is_some_and(|name| AGGREGATES.contains(&name.as_str()))
+}
+
+/// Returns whether `path` is strictly within `ancestor`.
+fn is_within(path: &NodePath, ancestor: &NodePath) -> bool {
+    std::iter::successors(path.parent(), |path| path.parent()).any(|path| path == ancestor)
+}
@@ -0,0 +1,304 @@
+//! The table-level locks statements acquire on the relations they reference.
+
+use crate::protobuf::{self, AlterTableType, ConstrType, ObjectType, RangeVar};
+use crate::resolve::strings;
+use crate::roles::relation_name;
+use crate::*;
+
+/// The lock a statement acquires on a relation. See [ParseResult::locks].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RelationLock {
+    /// The index of the statement
+    pub statement: usize,
+    /// The name of the relation, with its schema if given
+    pub relation: String,
+    pub mode: LockMode,
+}
+
+impl LockMode {
+    /// Returns whether the two lock modes conflict, so that a transaction holding one of them on a
+    /// relation blocks other transactions from acquiring the other.
+    pub fn conflicts_with(self, other: LockMode) -> bool {
+        // From Postgres source: src/backend/storage/lmgr/lock.c (LockConflicts), with a bit per mode
+        let conflicts: u16 = match self {
+            LockMode::NoLock => 0,
+            LockMode::AccessShareLock => 0b1_0000_0000,
+            LockMode::RowShareLock => 0b1_1000_0000,
+            LockMode::RowExclusiveLock => 0b1_1110_0000,
+            LockMode::ShareUpdateExclusiveLock => 0b1_1111_0000,
+            LockMode::ShareLock => 0b1_1101_1000,
+            LockMode::ShareRowExclusiveLock => 0b1_1111_1000,
+            LockMode::ExclusiveLock => 0b1_1111_1100,
+            LockMode::AccessExclusiveLock => 0b1_1111_1110,
+        };
+        conflicts & (1 << other as u16) != 0
+    }
+
+    /// Returns whether the lock blocks `SELECT`s of the relation.
+    pub fn blocks_reads(self) -> bool {
+        self.conflicts_with(LockMode::AccessShareLock)
+    }
+
+    /// Returns whether the lock blocks `INSERT`s, `UPDATE`s and `DELETE`s of the relation.
+    pub fn blocks_writes(self) -> bool {
+        self.conflicts_with(LockMode::RowExclusiveLock)
+    }
+}
+
+impl protobuf::ParseResult {
+    /// Returns the locks the statements acquire on the relations they reference. See [ParseResult::locks].
+    pub fn locks(&self) -> Vec<RelationLock> {
+        let uses = self.table_uses();
+        let mut locks = Vec::new();
+        for (i, stmt) in self.stmts.iter().enumerate() {
+            let Some(node) = stmt.stmt.as_ref().and_then(|stmt| stmt.node.as_ref()) else {
+                continue;
+            };
+            let mut statement = Locks { statement: i, locks: Vec::new() };
+            statement.utility(node.to_ref());
+            let in_statement = uses.iter().filter(|table| table.path.segments().first().and_then(|segment| segment.index) == Some(i));
+            for table in in_statement {
+                let mode = match table.role {
+                    TableRole::Select | TableRole::ReadInDml | TableRole::MergeSource => LockMode::AccessShareLock,
+                    TableRole::InsertTarget | TableRole::UpdateTarget | TableRole::DeleteTarget | TableRole::MergeTarget => {
+                        LockMode::RowExclusiveLock
+                    }
+                    TableRole::Truncate => LockMode::AccessExclusiveLock,
+                    // The mode of LOCK TABLE is added above
+                    TableRole::Lock if matches!(node, NodeEnum::LockStmt(_)) => continue,
+                    TableRole::Lock => LockMode::RowShareLock,
+                    TableRole::Ddl => continue,
+                };
+                statement.add(table.name.clone(), mode);
+            }
+            locks.extend(statement.locks);
+        }
+        locks
+    }
+}
+
+impl ParseResult {
+    /// Returns the table-level locks the statements acquire on the relations they reference, in the order
+    /// the relations appear, with the strongest mode for relations referenced several times:
+    ///
+    /// * `AccessShareLock` on tables read, `RowShareLock` on tables locked by `FOR UPDATE`, `FOR SHARE`, ...
+    ///   and `RowExclusiveLock` on the targets of `INSERT`, `UPDATE`, `DELETE` and `MERGE`
+    /// * The mode of `LOCK TABLE`, `AccessExclusiveLock` for `TRUNCATE`, and `RowExclusiveLock` for
+    ///   `COPY ... FROM` or `AccessShareLock` for `COPY ... TO`
+    /// * The strongest mode the subcommands of `ALTER TABLE` need, e.g. `AccessExclusiveLock` to add a
+    ///   column, `ShareRowExclusiveLock` to add a foreign key (on both tables) and `ShareUpdateExclusiveLock`
+    ///   to validate a constraint
+    /// * The modes of `CREATE INDEX` (`CONCURRENTLY`), `DROP`, `CREATE TRIGGER`, `VACUUM`, `CLUSTER`, `REINDEX`,
+    ///   `REFRESH MATERIALIZED VIEW` and renames
+    ///
+    /// Without a catalog, the locks on indexes, sequences or partitions that a statement implies
+    /// aren't known.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pg_query::LockMode;
+    ///
+    /// let result = pg_query::parse("CREATE INDEX CONCURRENTLY ON orders (user_id); ALTER TABLE orders ADD COLUMN note text").unwrap();
+    /// let locks: Vec<_> = result.locks().into_iter().map(|lock| (lock.statement, lock.relation, lock.mode)).collect();
+    /// assert_eq!(
+    ///     locks,
+    ///     [(0, "orders".to_string(), LockMode::ShareUpdateExclusiveLock), (1, "orders".to_string(), LockMode::AccessExclusiveLock)]
+    /// );
+    /// assert!(!LockMode::ShareUpdateExclusiveLock.blocks_writes() && LockMode::AccessExclusiveLock.blocks_reads());
+    /// ```
+    pub fn locks(&self) -> Vec<RelationLock> {
+        self.protobuf.locks()
+    }
+}
+
+/// The locks of a statement.
+struct Locks {
+    statement: usize,
+    locks: Vec<RelationLock>,
+}
+
+impl Locks {
+    fn add(&mut self, relation: String, mode: LockMode) {
+        match self.locks.iter_mut().find(|lock| lock.relation == relation) {
+            Some(lock) => lock.mode = lock.mode.max(mode),
+            None => self.locks.push(RelationLock { statement: self.statement, relation, mode }),
+        }
+    }
+
+    fn relation(&mut self, relation: Option<&RangeVar>, mode: LockMode) {
+        if let Some(relation) = relation {
+            self.add(relation_name(relation), mode);
+        }
+    }
+
+    /// Adds the locks of utility statements, which aren't the locks of the table references in them.
+    fn utility(&mut self, node: NodeRef) {
+        match node {
+            NodeRef::LockStmt(lock) => {
+                let mode = LockMode::try_from(lock.mode).unwrap_or(LockMode::AccessExclusiveLock);
+                for relation in &lock.relations {
+                    if let Some(NodeEnum::RangeVar(relation)) = &relation.node {
+                        self.add(relation_name(relation), mode);
+                    }
+                }
+            }
+            NodeRef::AlterTableStmt(alter) => {
+                let mut mode = LockMode::NoLock;
+                let mut others = Vec::new();
+                for cmd in &alter.cmds {
+                    if let Some(NodeEnum::AlterTableCmd(cmd)) = &cmd.node {
+                        mode = mode.max(alter_table_lock(cmd, &mut others));
+                    }
+                }
+                self.relation(alter.relation.as_ref(), mode);
+                for (relation, mode) in others {
+                    self.relation(Some(relation), mode);
+                }
+            }
+            NodeRef::IndexStmt(index) => {
+                let mode = if index.concurrent { LockMode::ShareUpdateExclusiveLock } else { LockMode::ShareLock };
+                self.relation(index.relation.as_ref(), mode);
+            }
+            NodeRef::CreateStmt(create) => {
+                for element in create.table_elts.iter().chain(&create.constraints) {
+                    let constraints = match &element.node {
+                        Some(NodeEnum::ColumnDef(column)) => &column.constraints[..],
+                        _ => std::slice::from_ref(element),
+                    };
+                    for constraint in constraints {
+                        if let Some(NodeEnum::Constraint(constraint)) = &constraint.node {
+                            self.relation(foreign_table(constraint), LockMode::ShareRowExclusiveLock);
+                        }
+                    }
+                }
+                if create.partbound.is_some() {
+                    for parent in &create.inh_relations {
+                        if let Some(NodeEnum::RangeVar(parent)) = &parent.node {
+                            self.relation(Some(parent), LockMode::AccessExclusiveLock);
+                        }
+                    }
+                }
+            }
+            NodeRef::CreateTrigStmt(trigger) => self.relation(trigger.relation.as_ref(), LockMode::ShareRowExclusiveLock),
+            NodeRef::DropStmt(drop) => {
+                let mode = if drop.concurrent { LockMode::ShareUpdateExclusiveLock } else { LockMode::AccessExclusiveLock };
+                let dropped = match ObjectType::try_from(drop.remove_type) {
+                    Ok(
+                        ObjectType::ObjectTable
+                        | ObjectType::ObjectView
+                        | ObjectType::ObjectMatview
+                        | ObjectType::ObjectIndex
+                        | ObjectType::ObjectSequence
+                        | ObjectType::ObjectForeignTable,
+                    ) => 0,
+                    // The last name is the name of the trigger, rule or policy on the table
+                    Ok(ObjectType::ObjectTrigger | ObjectType::ObjectRule | ObjectType::ObjectPolicy) => 1,
+                    _ => return,
+                };
+                for object in &drop.objects {
+                    if let Some(NodeEnum::List(list)) = &object.node {
+                        let names = strings(&list.items);
+                        if names.len() > dropped {
+                            self.add(names[..names.len() - dropped].join("."), mode);
+                        }
+                    }
+                }
+            }
+            NodeRef::RenameStmt(rename) => self.relation(rename.relation.as_ref(), LockMode::AccessExclusiveLock),
+            NodeRef::CopyStmt(copy) => {
+                let mode = if copy.is_from { LockMode::RowExclusiveLock } else { LockMode::AccessShareLock };
+                self.relation(copy.relation.as_ref(), mode);
+            }
+            NodeRef::VacuumStmt(vacuum) => {
+                let full = vacuum.is_vacuumcmd && has_option(&vacuum.options, "full");
+                let mode = if full { LockMode::AccessExclusiveLock } else { LockMode::ShareUpdateExclusiveLock };
+                for relation in &vacuum.rels {
+                    if let Some(NodeEnum::VacuumRelation(relation)) = &relation.node {
+                        self.relation(relation.relation.as_ref(), mode);
+                    }
+                }
+            }
+            NodeRef::ClusterStmt(cluster) => self.relation(cluster.relation.as_ref(), LockMode::AccessExclusiveLock),
+            NodeRef::ReindexStmt(reindex) => {
+                let mode = match protobuf::ReindexObjectType::try_from(reindex.kind) {
+                    _ if has_option(&reindex.params, "concurrently") => LockMode::ShareUpdateExclusiveLock,
+                    Ok(protobuf::ReindexObjectType::ReindexObjectIndex) => LockMode::AccessExclusiveLock,
+                    _ => LockMode::ShareLock,
+                };
+                self.relation(reindex.relation.as_ref(), mode);
+            }
+            NodeRef::RefreshMatViewStmt(refresh) => {
+                let mode = if refresh.concurrent { LockMode::ExclusiveLock } else { LockMode::AccessExclusiveLock };
+                self.relation(refresh.relation.as_ref(), mode);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns the lock an `ALTER TABLE` subcommand needs on the table, adding the locks it needs on other
+/// tables to `others`. From Postgres source: src/backend/commands/tablecmds.c (AlterTableGetLockLevel)
+fn alter_table_lock<'a>(cmd: &'a protobuf::AlterTableCmd, others: &mut Vec<(&'a RangeVar, LockMode)>) -> LockMode {
+    let def = cmd.def.as_ref().and_then(|def| def.node.as_ref());
+    match AlterTableType::try_from(cmd.subtype) {
+        Ok(AlterTableType::AtAddConstraint | AlterTableType::AtReAddConstraint) => {
+            if let Some(NodeEnum::Constraint(constraint)) = def {
+                if let Some(table) = foreign_table(constraint) {
+                    others.push((table, LockMode::ShareRowExclusiveLock));
+                    return LockMode::ShareRowExclusiveLock;
+                }
+            }
+            LockMode::AccessExclusiveLock
+        }
+        Ok(
+            AlterTableType::AtSetStatistics
+            | AlterTableType::AtSetOptions
+            | AlterTableType::AtResetOptions
+            | AlterTableType::AtClusterOn
+            | AlterTableType::AtDropCluster
+            | AlterTableType::AtSetRelOptions
+            | AlterTableType::AtResetRelOptions
+            | AlterTableType::AtReplaceRelOptions
+            | AlterTableType::AtValidateConstraint
+            | AlterTableType::AtDetachPartitionFinalize,
+        ) => LockMode::ShareUpdateExclusiveLock,
+        Ok(
+            AlterTableType::AtEnableTrig
+            | AlterTableType::AtEnableAlwaysTrig
+            | AlterTableType::AtEnableReplicaTrig
+            | AlterTableType::AtDisableTrig
+            | AlterTableType::AtEnableTrigAll
+            | AlterTableType::AtDisableTrigAll
+            | AlterTableType::AtEnableTrigUser
+            | AlterTableType::AtDisableTrigUser,
+        ) => LockMode::ShareRowExclusiveLock,
+        Ok(AlterTableType::AtAddIndex) => LockMode::ShareLock,
+        Ok(AlterTableType::AtReplicaIdentity) => LockMode::ExclusiveLock,
+        Ok(AlterTableType::AtAttachPartition | AlterTableType::AtDetachPartition) => {
+            let Some(NodeEnum::PartitionCmd(partition)) = def else {
+                return LockMode::AccessExclusiveLock;
+            };
+            let mode = if partition.concurrent { LockMode::ShareUpdateExclusiveLock } else { LockMode::AccessExclusiveLock };
+            if let Some(table) = &partition.name {
+                others.push((table, mode));
+            }
+            if cmd.subtype == AlterTableType::AtAttachPartition as i32 {
+                LockMode::ShareUpdateExclusiveLock
+            } else {
+                mode
+            }
+        }
+        _ => LockMode::AccessExclusiveLock,
+    }
+}
+
+/// Returns the table a foreign key constraint references.
+fn foreign_table(constraint: &protobuf::Constraint) -> Option<&RangeVar> {
+    constraint.pktable.as_ref().filter(|_| constraint.contype == ConstrType::ConstrForeign as i32)
+}
+
+/// Returns whether the `DefElem` options include `name`.
+pub(crate) fn has_option(options: &[protobuf::Node], name: &str) -> bool {
+    options.iter().any(|option| matches!(&option.node, Some(NodeEnum::DefElem(option)) if option.defname == name))
+}
@@ -1,3 +1,5 @@
+use std::collections::VecDeque;
+
 use crate::*;
 
 pub use protobuf::node::Node as NodeEnum;
@@ -27,11 +29,24 @@ impl NodeEnum {
     }
 
     pub fn nodes(&self) -> Vec<(NodeRef<'_>, i32, Context, bool)> {
-        let mut iter = vec![(self.to_ref(), 0, Context::None, false)];
+        self.nodes_with_limits(&ParseLimits::default()).expect("nodes are unlimited")
+    }
+
+    /// Same as [NodeEnum::nodes], failing with [Error::LimitExceeded] once a node is nested deeper than
+    /// `limits.max_depth`, or more than `limits.max_nodes` nodes are visited.
+    pub fn nodes_with_limits(&self, limits: &ParseLimits) -> Result<Vec<(NodeRef<'_>, i32, Context, bool)>> {
         let mut nodes = Vec::new();
-        while !iter.is_empty() {
-            let (node, depth, context, has_filter_columns) = iter.remove(0);
+        self.collect_nodes(limits, &mut nodes)?;
+        Ok(nodes)
+    }
+
+    /// Appends the nodes to `nodes`, counting the ones already there towards `limits.max_nodes`.
+    pub(crate) fn collect_nodes<'a>(&'a self, limits: &ParseLimits, nodes: &mut Vec<(NodeRef<'a>, i32, Context, bool)>) -> Result<()> {
+        let mut iter = VecDeque::from([(self.to_ref(), 0, Context::None, false)]);
+        while let Some((node, depth, context, has_filter_columns)) = iter.pop_front() {
             let depth = depth + 1;
+            limits.check(LimitKind::Depth, depth as usize)?;
+            limits.check(LimitKind::Nodes, nodes.len() + 1)?;
             match node {
                 //
                 // The following statement types do not modify tables
@@ -39,33 +54,33 @@ impl NodeEnum {
                 NodeRef::SelectStmt(s) => {
                     s.target_list.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::Select, false));
+                            iter.push_back((n.to_ref(), depth, Context::Select, false));
                         }
                     });
                     if let Some(n) = &s.where_clause {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::Select, true));
+                            iter.push_back((n.to_ref(), depth, Context::Select, true));
                         }
                     }
                     s.sort_clause.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::Select, false));
+                            iter.push_back((n.to_ref(), depth, Context::Select, false));
                         }
                     });
                     s.group_clause.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::Select, false));
+                            iter.push_back((n.to_ref(), depth, Context::Select, false));
                         }
                     });
                     if let Some(n) = &s.having_clause {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::Select, false));
+                            iter.push_back((n.to_ref(), depth, Context::Select, false));
                         }
                     }
                     if let Some(clause) = &s.with_clause {
                         clause.ctes.iter().for_each(|n| {
                             if let Some(n) = n.node.as_ref() {
-                                iter.push((n.to_ref(), depth, Context::Select, false));
+                                iter.push_back((n.to_ref(), depth, Context::Select, false));
                             }
                         });
                     }
@@ -73,32 +88,32 @@ impl NodeEnum {
                         Ok(protobuf::SetOperation::SetopNone) => {
                             s.from_clause.iter().for_each(|n| {
                                 if let Some(n) = n.node.as_ref() {
-                                    iter.push((n.to_ref(), depth, Context::Select, false));
+                                    iter.push_back((n.to_ref(), depth, Context::Select, false));
                                 }
                             });
                         }
                         Ok(protobuf::SetOperation::SetopUnion) => {
                             if let Some(left) = s.larg.as_ref() {
-                                iter.push((left.to_ref(), depth, Context::Select, false));
+                                iter.push_back((left.to_ref(), depth, Context::Select, false));
                             }
                             if let Some(right) = s.rarg.as_ref() {
-                                iter.push((right.to_ref(), depth, Context::Select, false));
+                                iter.push_back((right.to_ref(), depth, Context::Select, false));
                             }
                         }
                         Ok(protobuf::SetOperation::SetopExcept) => {
                             if let Some(left) = s.larg.as_ref() {
-                                iter.push((left.to_ref(), depth, Context::Select, false));
+                                iter.push_back((left.to_ref(), depth, Context::Select, false));
                             }
                             if let Some(right) = s.rarg.as_ref() {
-                                iter.push((right.to_ref(), depth, Context::Select, false));
+                                iter.push_back((right.to_ref(), depth, Context::Select, false));
                             }
                         }
                         Ok(protobuf::SetOperation::SetopIntersect) => {
                             if let Some(left) = s.larg.as_ref() {
-                                iter.push((left.to_ref(), depth, Context::Select, false));
+                                iter.push_back((left.to_ref(), depth, Context::Select, false));
                             }
                             if let Some(right) = s.rarg.as_ref() {
-                                iter.push((right.to_ref(), depth, Context::Select, false));
+                                iter.push_back((right.to_ref(), depth, Context::Select, false));
                             }
                         }
                         Ok(protobuf::SetOperation::Undefined) | Err(_) => (),
@@ -107,46 +122,46 @@ impl NodeEnum {
                 NodeRef::InsertStmt(s) => {
                     if let Some(n) = &s.select_stmt {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DML, false));
+                            iter.push_back((n.to_ref(), depth, Context::DML, false));
                         }
                     }
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DML, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DML, false));
                     }
                     if let Some(clause) = &s.with_clause {
                         clause.ctes.iter().for_each(|n| {
                             if let Some(n) = n.node.as_ref() {
-                                iter.push((n.to_ref(), depth, Context::DML, false));
+                                iter.push_back((n.to_ref(), depth, Context::DML, false));
                             }
                         });
                     }
                     if let Some(n) = &s.on_conflict_clause {
-                        iter.push((n.to_ref(), depth, Context::DML, false));
+                        iter.push_back((n.to_ref(), depth, Context::DML, false));
                     }
                 }
                 NodeRef::UpdateStmt(s) => {
                     s.target_list.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DML, false));
+                            iter.push_back((n.to_ref(), depth, Context::DML, false));
                         }
                     });
                     s.where_clause.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DML, true));
+                            iter.push_back((n.to_ref(), depth, Context::DML, true));
                         }
                     });
                     s.from_clause.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::Select, false));
+                            iter.push_back((n.to_ref(), depth, Context::Select, false));
                         }
                     });
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DML, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DML, false));
                     }
                     if let Some(clause) = &s.with_clause {
                         clause.ctes.iter().for_each(|n| {
                             if let Some(n) = n.node.as_ref() {
-                                iter.push((n.to_ref(), depth, Context::DML, false));
+                                iter.push_back((n.to_ref(), depth, Context::DML, false));
                             }
                         });
                     }
@@ -154,74 +169,74 @@ impl NodeEnum {
                 NodeRef::DeleteStmt(s) => {
                     if let Some(n) = &s.where_clause {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DML, true));
+                            iter.push_back((n.to_ref(), depth, Context::DML, true));
                         }
                     }
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DML, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DML, false));
                     }
                     if let Some(clause) = &s.with_clause {
                         clause.ctes.iter().for_each(|n| {
                             if let Some(n) = n.node.as_ref() {
-                                iter.push((n.to_ref(), depth, Context::DML, false));
+                                iter.push_back((n.to_ref(), depth, Context::DML, false));
                             }
                         });
                     }
                     s.using_clause.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::Select, false));
+                            iter.push_back((n.to_ref(), depth, Context::Select, false));
                         }
                     });
                 }
                 NodeRef::MergeStmt(m) => {
                     if let Some(t) = m.relation.as_ref() {
-                        iter.push((t.to_ref(), depth, Context::DML, false));
+                        iter.push_back((t.to_ref(), depth, Context::DML, false));
                     }
 
                     if let Some(clause) = &m.with_clause {
                         clause.ctes.iter().for_each(|n| {
                             if let Some(n) = n.node.as_ref() {
-                                iter.push((n.to_ref(), depth, Context::DML, false));
+                                iter.push_back((n.to_ref(), depth, Context::DML, false));
                             }
                         });
                     }
 
                     m.source_relation.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::Select, false));
+                            iter.push_back((n.to_ref(), depth, Context::Select, false));
                         }
                     });
                     m.merge_when_clauses.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DML, true));
+                            iter.push_back((n.to_ref(), depth, Context::DML, true));
                         }
                     });
                     m.join_condition.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::Select, false));
+                            iter.push_back((n.to_ref(), depth, Context::Select, false));
                         }
                     });
                 }
                 NodeRef::CommonTableExpr(s) => {
                     if let Some(n) = &s.ctequery {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, false));
+                            iter.push_back((n.to_ref(), depth, context, false));
                         }
                     }
                 }
                 NodeRef::CopyStmt(s) => {
                     if let Some(n) = &s.query {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DML, false));
+                            iter.push_back((n.to_ref(), depth, Context::DML, false));
                         }
                     }
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DML, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DML, false));
                     }
                 }
                 NodeRef::CallStmt(s) => {
                     if let Some(n) = s.funccall.as_ref() {
-                        iter.push((n.to_ref(), depth, Context::Call, false));
+                        iter.push_back((n.to_ref(), depth, Context::Call, false));
                     }
                 }
                 //
@@ -229,91 +244,91 @@ impl NodeEnum {
                 //
                 NodeRef::AlterTableStmt(s) => {
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DDL, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DDL, false));
                     }
                 }
                 NodeRef::CreateStmt(s) => {
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DDL, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DDL, false));
                     }
                 }
                 NodeRef::CreateTableAsStmt(s) => {
                     if let Some(n) = &s.query {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DDL, false));
+                            iter.push_back((n.to_ref(), depth, Context::DDL, false));
                         }
                     }
                     if let Some(n) = &s.into {
                         if let Some(rel) = n.rel.as_ref() {
-                            iter.push((rel.to_ref(), depth, Context::DDL, false));
+                            iter.push_back((rel.to_ref(), depth, Context::DDL, false));
                         }
                     }
                 }
                 NodeRef::TruncateStmt(s) => {
                     s.relations.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DDL, false));
+                            iter.push_back((n.to_ref(), depth, Context::DDL, false));
                         }
                     });
                 }
                 NodeRef::ViewStmt(s) => {
                     if let Some(n) = &s.query {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DDL, false));
+                            iter.push_back((n.to_ref(), depth, Context::DDL, false));
                         }
                     }
                     if let Some(rel) = s.view.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DDL, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DDL, false));
                     }
                 }
                 NodeRef::IndexStmt(s) => {
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DDL, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DDL, false));
                     }
                     s.index_params.iter().for_each(|n| {
                         if let Some(NodeEnum::IndexElem(n)) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DDL, false));
+                            iter.push_back((n.to_ref(), depth, Context::DDL, false));
 
                             if let Some(n) = n.expr.as_ref().and_then(|n| n.node.as_ref()) {
-                                iter.push((n.to_ref(), depth, Context::DDL, false));
+                                iter.push_back((n.to_ref(), depth, Context::DDL, false));
                             }
                         }
                     });
                     if let Some(n) = s.where_clause.as_ref() {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DDL, true));
+                            iter.push_back((n.to_ref(), depth, Context::DDL, true));
                         }
                     }
                 }
                 NodeRef::CreateTrigStmt(s) => {
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DDL, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DDL, false));
                     }
                 }
                 NodeRef::RuleStmt(s) => {
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DDL, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DDL, false));
                     }
                 }
                 NodeRef::VacuumStmt(s) => {
                     for node in &s.rels {
                         if let Some(NodeEnum::VacuumRelation(r)) = &node.node {
                             if let Some(rel) = r.relation.as_ref() {
-                                iter.push((rel.to_ref(), depth, Context::DDL, false));
+                                iter.push_back((rel.to_ref(), depth, Context::DDL, false));
                             }
                         }
                     }
                 }
                 NodeRef::RefreshMatViewStmt(s) => {
                     if let Some(rel) = s.relation.as_ref() {
-                        iter.push((rel.to_ref(), depth, Context::DDL, false));
+                        iter.push_back((rel.to_ref(), depth, Context::DDL, false));
                     }
                 }
                 NodeRef::GrantStmt(s) => {
                     if let Ok(protobuf::ObjectType::ObjectTable) = protobuf::ObjectType::try_from(s.objtype) {
                         s.objects.iter().for_each(|n| {
                             if let Some(n) = n.node.as_ref() {
-                                iter.push((n.to_ref(), depth, Context::DDL, false));
+                                iter.push_back((n.to_ref(), depth, Context::DDL, false));
                             }
                         });
                     }
@@ -321,14 +336,14 @@ impl NodeEnum {
                 NodeRef::LockStmt(s) => {
                     s.relations.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, Context::DDL, false));
+                            iter.push_back((n.to_ref(), depth, Context::DDL, false));
                         }
                     });
                 }
                 NodeRef::ExplainStmt(s) => {
                     if let Some(n) = &s.query {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, false));
+                            iter.push_back((n.to_ref(), depth, context, false));
                         }
                     }
                 }
@@ -338,106 +353,106 @@ impl NodeEnum {
                 NodeRef::AExpr(e) => {
                     if let Some(n) = &e.lexpr {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                     if let Some(n) = &e.rexpr {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
                 NodeRef::BoolExpr(e) => {
                     e.args.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     });
                 }
                 NodeRef::BooleanTest(e) => {
                     if let Some(n) = &e.arg {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
                 NodeRef::CoalesceExpr(e) => {
                     e.args.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     });
                 }
                 NodeRef::MinMaxExpr(e) => {
                     e.args.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     });
                 }
                 NodeRef::NullTest(e) => {
                     if let Some(n) = &e.arg {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
                 NodeRef::ResTarget(t) => {
                     if let Some(n) = &t.val {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
                 NodeRef::SubLink(l) => {
                     if let Some(n) = &l.subselect {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
                 NodeRef::FuncCall(c) => {
                     c.args.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     });
                 }
                 NodeRef::CaseExpr(c) => {
                     c.args.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     });
                     if let Some(n) = &c.defresult {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
                 NodeRef::CaseWhen(w) => {
                     if let Some(n) = &w.expr {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                     if let Some(n) = &w.result {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
                 NodeRef::SortBy(n) => {
                     if let Some(n) = &n.node {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
                 NodeRef::TypeCast(n) => {
                     if let Some(n) = &n.arg {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
@@ -447,7 +462,7 @@ impl NodeEnum {
                 NodeRef::List(l) => {
                     l.items.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     });
                 }
@@ -455,7 +470,7 @@ impl NodeEnum {
                     [&e.larg, &e.rarg, &e.quals].iter().for_each(|n| {
                         if let Some(n) = n {
                             if let Some(n) = n.node.as_ref() {
-                                iter.push((n.to_ref(), depth, context, has_filter_columns));
+                                iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                             }
                         }
                     });
@@ -463,21 +478,21 @@ impl NodeEnum {
                 NodeRef::RowExpr(e) => {
                     e.args.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     });
                 }
                 NodeRef::RangeSubselect(s) => {
                     if let Some(n) = &s.subquery {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     }
                 }
                 NodeRef::RangeFunction(f) => {
                     f.functions.iter().for_each(|n| {
                         if let Some(n) = n.node.as_ref() {
-                            iter.push((n.to_ref(), depth, context, has_filter_columns));
+                            iter.push_back((n.to_ref(), depth, context, has_filter_columns));
                         }
                     });
                 }
@@ -485,7 +500,7 @@ impl NodeEnum {
             }
             nodes.push((node, depth, context, has_filter_columns));
         }
-        nodes
+        Ok(())
     }
 
     /// Returns a mutable reference to nested nodes.
@@ -494,6 +509,7 @@ impl NodeEnum {
     ///
     /// The caller may have to deal with dangling pointers, and passing an
     /// invalid tree back to libpg_query may cause it to panic.
+    #[deprecated(note = "use `VisitorMut`, which rewrites trees without raw pointers")]
     pub unsafe fn nodes_mut(&mut self) -> Vec<(NodeMut, i32, Context)> {
         let mut iter = vec![(self.to_mut(), 0, Context::None)];
         let mut nodes = Vec::new();
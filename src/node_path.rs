@@ -0,0 +1,306 @@
+//! Lazy traversal of parse trees, locating every node by its path from the root.
+//!
+//! The child lists and field accessors are generated from `protobuf.rs` by the build script, like the
+//! [crate::visitor], so every node type is covered.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+use std::str::FromStr;
+
+use crate::*;
+
+include!(concat!(env!("OUT_DIR"), "/node_path.rs"));
+
+/// The order in which [NodeIter] yields nodes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TraversalOrder {
+    /// Parents before their children
+    #[default]
+    PreOrder,
+    /// Children before their parents
+    PostOrder,
+}
+
+/// One step of a [NodePath]: the field holding a child node, and its index if the field is a list.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PathSegment {
+    pub field: Cow<'static, str>,
+    pub index: Option<usize>,
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.index {
+            Some(index) => write!(f, "{}[{index}]", self.field),
+            None => write!(f, "{}", self.field),
+        }
+    }
+}
+
+/// The location of a node in a tree, as the fields and list indices leading to it from the root,
+/// e.g. `stmts[0].where_clause.args[1]`.
+///
+/// Field names are those of [crate::protobuf]. Paths into a [protobuf::ParseResult] start at a
+/// statement (`stmts[0]`), and `Node` wrappers don't appear in them. Paths are displayed in the
+/// format above, and can be parsed from it with [str::parse].
+#[derive(Clone, Default)]
+pub struct NodePath(Option<Rc<PathLink>>);
+
+// Paths share their prefix with their parent's, so that iterating over a tree stays linear in its size
+struct PathLink {
+    parent: NodePath,
+    segment: PathSegment,
+    len: usize,
+}
+
+impl NodePath {
+    /// Returns the number of segments in the path.
+    pub fn len(&self) -> usize {
+        self.0.as_ref().map_or(0, |link| link.len)
+    }
+
+    /// Returns whether this is the path of the root.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// Returns the path of the parent node, or `None` for the root.
+    pub fn parent(&self) -> Option<&NodePath> {
+        self.0.as_ref().map(|link| &link.parent)
+    }
+
+    /// Returns the last segment of the path, or `None` for the root.
+    pub fn last(&self) -> Option<&PathSegment> {
+        self.0.as_ref().map(|link| &link.segment)
+    }
+
+    /// Returns the segments of the path, from the root.
+    pub fn segments(&self) -> Vec<&PathSegment> {
+        let mut segments = Vec::with_capacity(self.len());
+        let mut path = self;
+        while let Some(link) = &path.0 {
+            segments.push(&link.segment);
+            path = &link.parent;
+        }
+        segments.reverse();
+        segments
+    }
+
+    /// Returns the path of a child, in `field` at `index` if it's a list.
+    pub fn join(&self, field: impl Into<Cow<'static, str>>, index: Option<usize>) -> NodePath {
+        NodePath(Some(Rc::new(PathLink { parent: self.clone(), segment: PathSegment { field: field.into(), index }, len: self.len() + 1 })))
+    }
+}
+
+impl fmt::Display for NodePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.segments().into_iter().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+            write!(f, "{segment}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for NodePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NodePath({:?})", self.to_string())
+    }
+}
+
+impl PartialEq for NodePath {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.segments() == other.segments()
+    }
+}
+
+impl Eq for NodePath {}
+
+impl Hash for NodePath {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.segments().hash(state);
+    }
+}
+
+impl FromStr for NodePath {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut path = NodePath::default();
+        if s.is_empty() {
+            return Ok(path);
+        }
+        for segment in s.split('.') {
+            let (field, index) = match segment.strip_suffix(']').and_then(|segment| segment.split_once('[')) {
+                Some((field, index)) => (field, Some(index.parse().map_err(|_| Error::InvalidPath(s.to_string()))?)),
+                None => (segment, None),
+            };
+            if field.is_empty() || !field.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err(Error::InvalidPath(s.to_string()));
+            }
+            path = path.join(field.to_string(), index);
+        }
+        Ok(path)
+    }
+}
+
+/// A lazy, depth-first iterator over the nodes of a tree and their paths, see
+/// [protobuf::ParseResult::iter_nodes].
+pub struct NodeIter<'a> {
+    order: TraversalOrder,
+    /// Nodes left to visit, last first, and whether their children have been queued already
+    stack: Vec<(NodePath, NodeRef<'a>, bool)>,
+}
+
+impl<'a> NodeIter<'a> {
+    fn new(roots: Vec<(NodePath, NodeRef<'a>)>, order: TraversalOrder) -> Self {
+        NodeIter { order, stack: roots.into_iter().rev().map(|(path, node)| (path, node, false)).collect() }
+    }
+}
+
+impl<'a> Iterator for NodeIter<'a> {
+    type Item = (NodePath, NodeRef<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((path, node, expanded)) = self.stack.pop() {
+            if expanded {
+                return Some((path, node));
+            }
+            if self.order == TraversalOrder::PostOrder {
+                self.stack.push((path.clone(), node, true));
+            }
+            let first = self.stack.len();
+            children(node, &mut |field, index, child| self.stack.push((path.join(field, index), child, false)));
+            self.stack[first..].reverse();
+            if self.order == TraversalOrder::PreOrder {
+                return Some((path, node));
+            }
+        }
+        None
+    }
+}
+
+/// Mutable access to the children of a node, for [protobuf::ParseResult::replace_at_path].
+pub(crate) trait Fields {
+    /// Returns the child in `field`, at `index` if it's a list.
+    fn child_mut(&mut self, _field: &str, _index: Option<usize>) -> Option<ChildMut<'_>> {
+        None
+    }
+
+    /// Replaces the node with `node` and returns the old one, or `None` if `node` has another type.
+    fn replace(&mut self, node: NodeEnum) -> Option<NodeEnum>;
+}
+
+pub(crate) enum ChildMut<'a> {
+    /// A field that can hold any node
+    Node(&'a mut protobuf::Node),
+    /// A field that holds a node of one type
+    Message(&'a mut dyn Fields),
+}
+
+impl protobuf::ParseResult {
+    /// Returns a lazy iterator over every node of the statements, parents first, together with their
+    /// paths. Unlike [protobuf::ParseResult::nodes], it reaches every node type and runs in linear time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pg_query::NodeRef;
+    ///
+    /// let result = pg_query::parse("SELECT * FROM users WHERE id = $1 AND tenant_id = $2").unwrap();
+    /// let params: Vec<_> =
+    ///     result.protobuf.iter_nodes().filter(|(_, node)| matches!(node, NodeRef::ParamRef(_))).map(|(path, _)| path.to_string()).collect();
+    /// assert_eq!(params, ["stmts[0].where_clause.args[0].rexpr", "stmts[0].where_clause.args[1].rexpr"]);
+    /// ```
+    pub fn iter_nodes(&self) -> NodeIter<'_> {
+        self.iter_nodes_with_order(TraversalOrder::PreOrder)
+    }
+
+    /// Same as [protobuf::ParseResult::iter_nodes], in the given order.
+    pub fn iter_nodes_with_order(&self, order: TraversalOrder) -> NodeIter<'_> {
+        let roots = self
+            .stmts
+            .iter()
+            .enumerate()
+            .filter_map(|(i, stmt)| Some((NodePath::default().join("stmts", Some(i)), stmt.stmt.as_ref()?.node.as_ref()?.to_ref())))
+            .collect();
+        NodeIter::new(roots, order)
+    }
+
+    /// Returns the node at `path`, if there is one.
+    pub fn get_by_path(&self, path: &NodePath) -> Option<NodeRef<'_>> {
+        let segments = path.segments();
+        let (first, rest) = segments.split_first()?;
+        if first.field != "stmts" {
+            return None;
+        }
+        let mut node = self.stmts.get(first.index?)?.stmt.as_ref()?.node.as_ref()?.to_ref();
+        for segment in rest {
+            let mut found = None;
+            children(node, &mut |field, index, child| {
+                if found.is_none() && field == segment.field && index == segment.index {
+                    found = Some(child);
+                }
+            });
+            node = found?;
+        }
+        Some(node)
+    }
+
+    /// Replaces the node at `path` with `node`, returning the old one.
+    ///
+    /// Fails with [Error::InvalidPath] if there is no node at `path`, or if the field only holds nodes
+    /// of another type (e.g. the `relation` of an `UpdateStmt`, which is always a `RangeVar`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pg_query::NodeEnum;
+    ///
+    /// let path = "stmts[0].from_clause[0]".parse().unwrap();
+    /// let replacement = pg_query::parse("SELECT * FROM accounts").unwrap().protobuf.get_by_path(&path).unwrap().to_enum();
+    ///
+    /// let mut result = pg_query::parse("SELECT * FROM users WHERE id = 1").unwrap();
+    /// let old = result.protobuf.replace_at_path(&path, replacement).unwrap();
+    /// assert!(matches!(old, NodeEnum::RangeVar(table) if table.relname == "users"));
+    /// assert_eq!(result.protobuf.deparse().unwrap(), "SELECT * FROM accounts WHERE id = 1");
+    /// ```
+    pub fn replace_at_path(&mut self, path: &NodePath, node: NodeEnum) -> Result<NodeEnum> {
+        let invalid = || Error::InvalidPath(path.to_string());
+        let segments = path.segments();
+        let (first, rest) = segments.split_first().ok_or_else(invalid)?;
+        let stmt = match (first.field.as_ref(), first.index) {
+            ("stmts", Some(i)) => self.stmts.get_mut(i).and_then(|stmt| stmt.stmt.as_deref_mut()),
+            _ => None,
+        };
+        let mut slot = ChildMut::Node(stmt.ok_or_else(invalid)?);
+        for segment in rest {
+            let fields = match slot {
+                ChildMut::Node(node) => fields_mut(node.node.as_mut().ok_or_else(invalid)?),
+                ChildMut::Message(fields) => fields,
+            };
+            slot = fields.child_mut(&segment.field, segment.index).ok_or_else(invalid)?;
+        }
+        match slot {
+            ChildMut::Node(slot) => Ok(std::mem::replace(slot.node.as_mut().ok_or_else(invalid)?, node)),
+            ChildMut::Message(slot) => slot.replace(node).ok_or_else(|| Error::InvalidPath(format!("{path} holds a different type of node"))),
+        }
+    }
+}
+
+impl NodeEnum {
+    /// Returns a lazy iterator over this node and every node within it, parents first, together with
+    /// their paths relative to this node. See [protobuf::ParseResult::iter_nodes].
+    pub fn iter_nodes(&self) -> NodeIter<'_> {
+        self.iter_nodes_with_order(TraversalOrder::PreOrder)
+    }
+
+    /// Same as [NodeEnum::iter_nodes], in the given order.
+    pub fn iter_nodes_with_order(&self, order: TraversalOrder) -> NodeIter<'_> {
+        NodeIter::new(vec![(NodePath::default(), self.to_ref())], order)
+    }
+}
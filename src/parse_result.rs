@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::iter::FromIterator;
 use std::string::String;
+use std::sync::OnceLock;
 
 use itertools::join;
 
@@ -38,12 +39,27 @@ impl protobuf::ParseResult {
             .collect()
     }
 
+    /// Same as [protobuf::ParseResult::nodes], failing with [Error::LimitExceeded] if there are more statements
+    /// than `limits.max_statements`, or the nodes visited exceed the depth or node count limits.
+    ///
+    /// Use this to walk trees that weren't parsed with [ParseOptions::limits], such as ones loaded from JSON.
+    pub fn nodes_with_limits(&self, limits: &ParseLimits) -> Result<Vec<(NodeRef<'_>, i32, Context, bool)>> {
+        limits.check(LimitKind::Statements, self.stmts.len())?;
+        let mut nodes = Vec::new();
+        for node in self.stmts.iter().filter_map(|s| s.stmt.as_ref().and_then(|s| s.node.as_ref())) {
+            node.collect_nodes(limits, &mut nodes)?;
+        }
+        Ok(nodes)
+    }
+
     /// Returns a mutable reference to nested nodes.
     ///
     /// # Safety
     ///
     /// The caller may have to deal with dangling pointers, and passing an
     /// invalid tree back to libpg_query may cause it to panic.
+    #[deprecated(note = "use `VisitorMut`, which rewrites trees without raw pointers")]
+    #[allow(deprecated)]
     pub unsafe fn nodes_mut(&mut self) -> Vec<(NodeMut, i32, Context)> {
         self.stmts
             .iter_mut()
@@ -59,6 +75,9 @@ impl protobuf::ParseResult {
 #[derive(Debug)]
 pub struct ParseResult {
     pub protobuf: protobuf::ParseResult,
+    pub(crate) statement: Option<String>,
+    /// The tokens of `statement` without comments, scanned on the first call to [ParseResult::span]
+    pub(crate) tokens: OnceLock<Option<Vec<protobuf::ScanToken>>>,
     pub warnings: Vec<String>,
     pub tables: Vec<(String, Context)>,
     pub aliases: HashMap<String, String>,
@@ -76,20 +95,13 @@ impl ParseResult {
         let mut functions: HashSet<(String, Context)> = HashSet::new();
         let mut filter_columns: HashSet<(Option<String>, String)> = HashSet::new();
 
+        let mut relations: Vec<(&protobuf::RangeVar, Context)> = Vec::new();
         for (node, _depth, context, has_filter_columns) in protobuf.nodes().into_iter() {
             match node {
                 NodeRef::CommonTableExpr(s) => {
                     cte_names.insert(s.ctename.to_owned());
                 }
-                NodeRef::RangeVar(v) => {
-                    // TODO: this incorrectly returns no tables: parse('with f as (select * from f limit 1) select * from f')
-                    let table = if !v.schemaname.is_empty() { format!("{}.{}", v.schemaname, v.relname) } else { v.relname.to_owned() };
-                    if cte_names.contains(&table) {
-                        continue;
-                    }
-                    tables.insert((table.to_owned(), context));
-                    v.alias.as_ref().and_then(|alias| aliases.insert(alias.aliasname.to_owned(), table));
-                }
+                NodeRef::RangeVar(v) => relations.push((v, context)),
                 NodeRef::FuncCall(c) => {
                     let funcname = join(c.funcname.iter().filter_map(|n| n.node.as_ref().map(|n| &cast!(n, NodeEnum::String).sval)), ".");
                     functions.insert((funcname, Context::Call));
@@ -168,8 +180,21 @@ impl ParseResult {
             }
         }
 
+        // Names can only refer to CTEs in statements with a WITH clause, so only those need resolving
+        let resolution = if cte_names.is_empty() { None } else { Some(protobuf.resolve()) };
+        for (v, context) in relations {
+            if resolution.as_ref().and_then(|resolution| resolution.table(v)).is_some_and(|table| table.cte.is_some()) {
+                continue;
+            }
+            let table = if !v.schemaname.is_empty() { format!("{}.{}", v.schemaname, v.relname) } else { v.relname.to_owned() };
+            tables.insert((table.to_owned(), context));
+            v.alias.as_ref().and_then(|alias| aliases.insert(alias.aliasname.to_owned(), table));
+        }
+
         Self {
             protobuf,
+            statement: None,
+            tokens: OnceLock::new(),
             warnings,
             tables: Vec::from_iter(tables),
             aliases,
@@ -179,6 +204,27 @@ impl ParseResult {
         }
     }
 
+    /// Sets the SQL text the tree was parsed from, e.g. for a tree loaded from JSON, so that
+    /// [ParseResult::span] and [ParseResult::line_column] can find node locations in it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let query = "SELECT *\nFROM users";
+    /// let loaded = pg_query::ParseResult::from_json(&pg_query::parse(query).unwrap().to_json()).unwrap();
+    /// assert_eq!(loaded.line_column(14), None);
+    /// assert_eq!(loaded.with_statement(query).line_column(14), Some((2, 6)));
+    /// ```
+    pub fn with_statement(self, statement: &str) -> Self {
+        Self { statement: Some(statement.to_string()), tokens: OnceLock::new(), ..self }
+    }
+
+    /// Returns the SQL text the tree was parsed from, which node locations point into. `None` for trees
+    /// built with [ParseResult::new] or loaded from JSON, unless set with [ParseResult::with_statement].
+    pub fn statement(&self) -> Option<&str> {
+        self.statement.as_deref()
+    }
+
     /// Returns all referenced tables in the query
     pub fn tables(&self) -> Vec<String> {
         let mut tables = HashSet::new();
@@ -262,6 +308,52 @@ impl ParseResult {
         crate::deparse_raw(&self.protobuf)
     }
 
+    /// Serializes the parse tree to JSON, which can be loaded back with [ParseResult::from_json].
+    ///
+    /// Nodes are tagged with their type, e.g. `{"node":{"ColumnRef":{"fields":[...],"location":7}}}`,
+    /// and enum fields hold their protobuf value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let result = pg_query::parse("SELECT * FROM contacts").unwrap();
+    /// let json = result.to_json();
+    /// let loaded = pg_query::ParseResult::from_json(&json).unwrap();
+    /// assert_eq!(loaded.tables(), vec!["contacts"]);
+    /// assert_eq!(loaded.deparse().unwrap(), "SELECT * FROM contacts");
+    /// ```
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.protobuf).expect("parse trees only contain JSON-serializable values")
+    }
+
+    /// Loads a parse tree serialized with [ParseResult::to_json]. Missing fields take their default value.
+    ///
+    /// Like [crate::parse], this doesn't limit the nesting depth: the stack grows as deeply nested JSON
+    /// needs it, instead of failing at serde_json's recursion limit.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let protobuf: protobuf::ParseResult = from_json_str(json)?;
+        Ok(ParseResult::new(protobuf, String::new()))
+    }
+
+    /// Loads a parse tree in the JSON format of libpg_query, as produced by [crate::parse_json] and the
+    /// other pg_query bindings (e.g. `PgQuery.parse(sql).tree.to_json` in Ruby). Like
+    /// [ParseResult::from_json], the nesting depth isn't limited.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let json = r#"{"version":170004,"stmts":[{"stmt":{"SelectStmt":{"targetList":[{"ResTarget":{"val":{"A_Const":{"ival":{"ival":1},"location":7}},"location":7}}],"limitOption":"LIMIT_OPTION_DEFAULT","op":"SETOP_NONE"}}}]}"#;
+    /// let result = pg_query::ParseResult::from_libpg_query_json(json).unwrap();
+    /// assert_eq!(result.deparse().unwrap(), "SELECT 1");
+    /// ```
+    pub fn from_libpg_query_json(json: &str) -> Result<Self> {
+        let value: serde_json::Value = from_json_str(json)?;
+        let value = crate::libpg_query_json::convert(value)?;
+        let protobuf = <protobuf::ParseResult as serde::Deserialize>::deserialize(serde_stacker::Deserializer::new(value))
+            .map_err(|e| Error::InvalidJson(e.to_string()))?;
+        Ok(ParseResult::new(protobuf, String::new()))
+    }
+
     /// Intelligently truncates queries to a max length.
     ///
     /// # Example
@@ -277,129 +369,33 @@ impl ParseResult {
 
     /// Returns all statement types in the query
     pub fn statement_types(&self) -> Vec<&str> {
-        self.protobuf
-            .stmts
-            .iter()
-            .filter_map(|s| match s.stmt.as_ref().and_then(|s| s.node.as_ref()) {
-                Some(NodeEnum::InsertStmt(..)) => Some("InsertStmt"),
-                Some(NodeEnum::DeleteStmt(..)) => Some("DeleteStmt"),
-                Some(NodeEnum::UpdateStmt(..)) => Some("UpdateStmt"),
-                Some(NodeEnum::SelectStmt(..)) => Some("SelectStmt"),
-                Some(NodeEnum::MergeStmt(..)) => Some("MergeStmt"),
-                Some(NodeEnum::AlterTableStmt(..)) => Some("AlterTableStmt"),
-                Some(NodeEnum::AlterTableCmd(..)) => Some("AlterTableCmd"),
-                Some(NodeEnum::AlterDomainStmt(..)) => Some("AlterDomainStmt"),
-                Some(NodeEnum::SetOperationStmt(..)) => Some("SetOperationStmt"),
-                Some(NodeEnum::GrantStmt(..)) => Some("GrantStmt"),
-                Some(NodeEnum::GrantRoleStmt(..)) => Some("GrantRoleStmt"),
-                Some(NodeEnum::AlterDefaultPrivilegesStmt(..)) => Some("AlterDefaultPrivilegesStmt"),
-                Some(NodeEnum::ClosePortalStmt(..)) => Some("ClosePortalStmt"),
-                Some(NodeEnum::ClusterStmt(..)) => Some("ClusterStmt"),
-                Some(NodeEnum::CopyStmt(..)) => Some("CopyStmt"),
-                Some(NodeEnum::CreateStmt(..)) => Some("CreateStmt"),
-                Some(NodeEnum::DefineStmt(..)) => Some("DefineStmt"),
-                Some(NodeEnum::DropStmt(..)) => Some("DropStmt"),
-                Some(NodeEnum::TruncateStmt(..)) => Some("TruncateStmt"),
-                Some(NodeEnum::CommentStmt(..)) => Some("CommentStmt"),
-                Some(NodeEnum::FetchStmt(..)) => Some("FetchStmt"),
-                Some(NodeEnum::IndexStmt(..)) => Some("IndexStmt"),
-                Some(NodeEnum::CreateFunctionStmt(..)) => Some("CreateFunctionStmt"),
-                Some(NodeEnum::AlterFunctionStmt(..)) => Some("AlterFunctionStmt"),
-                Some(NodeEnum::DoStmt(..)) => Some("DoStmt"),
-                Some(NodeEnum::RenameStmt(..)) => Some("RenameStmt"),
-                Some(NodeEnum::RuleStmt(..)) => Some("RuleStmt"),
-                Some(NodeEnum::NotifyStmt(..)) => Some("NotifyStmt"),
-                Some(NodeEnum::ListenStmt(..)) => Some("ListenStmt"),
-                Some(NodeEnum::UnlistenStmt(..)) => Some("UnlistenStmt"),
-                Some(NodeEnum::TransactionStmt(..)) => Some("TransactionStmt"),
-                Some(NodeEnum::ViewStmt(..)) => Some("ViewStmt"),
-                Some(NodeEnum::LoadStmt(..)) => Some("LoadStmt"),
-                Some(NodeEnum::CreateDomainStmt(..)) => Some("CreateDomainStmt"),
-                Some(NodeEnum::CreatedbStmt(..)) => Some("CreatedbStmt"),
-                Some(NodeEnum::DropdbStmt(..)) => Some("DropdbStmt"),
-                Some(NodeEnum::VacuumStmt(..)) => Some("VacuumStmt"),
-                Some(NodeEnum::ExplainStmt(..)) => Some("ExplainStmt"),
-                Some(NodeEnum::CreateTableAsStmt(..)) => Some("CreateTableAsStmt"),
-                Some(NodeEnum::CreateSeqStmt(..)) => Some("CreateSeqStmt"),
-                Some(NodeEnum::AlterSeqStmt(..)) => Some("AlterSeqStmt"),
-                Some(NodeEnum::VariableSetStmt(..)) => Some("VariableSetStmt"),
-                Some(NodeEnum::VariableShowStmt(..)) => Some("VariableShowStmt"),
-                Some(NodeEnum::DiscardStmt(..)) => Some("DiscardStmt"),
-                Some(NodeEnum::CreateTrigStmt(..)) => Some("CreateTrigStmt"),
-                // CreatePLangStmt is capitalized differently to match C implementation.
-                Some(NodeEnum::CreatePlangStmt(..)) => Some("CreatePLangStmt"),
-                Some(NodeEnum::CreateRoleStmt(..)) => Some("CreateRoleStmt"),
-                Some(NodeEnum::AlterRoleStmt(..)) => Some("AlterRoleStmt"),
-                Some(NodeEnum::DropRoleStmt(..)) => Some("DropRoleStmt"),
-                Some(NodeEnum::LockStmt(..)) => Some("LockStmt"),
-                Some(NodeEnum::ConstraintsSetStmt(..)) => Some("ConstraintsSetStmt"),
-                Some(NodeEnum::ReindexStmt(..)) => Some("ReindexStmt"),
-                Some(NodeEnum::CheckPointStmt(..)) => Some("CheckPointStmt"),
-                Some(NodeEnum::CreateSchemaStmt(..)) => Some("CreateSchemaStmt"),
-                Some(NodeEnum::AlterDatabaseStmt(..)) => Some("AlterDatabaseStmt"),
-                Some(NodeEnum::AlterDatabaseSetStmt(..)) => Some("AlterDatabaseSetStmt"),
-                Some(NodeEnum::AlterRoleSetStmt(..)) => Some("AlterRoleSetStmt"),
-                Some(NodeEnum::CreateConversionStmt(..)) => Some("CreateConversionStmt"),
-                Some(NodeEnum::CreateCastStmt(..)) => Some("CreateCastStmt"),
-                Some(NodeEnum::CreateOpClassStmt(..)) => Some("CreateOpClassStmt"),
-                Some(NodeEnum::CreateOpFamilyStmt(..)) => Some("CreateOpFamilyStmt"),
-                Some(NodeEnum::AlterOpFamilyStmt(..)) => Some("AlterOpFamilyStmt"),
-                Some(NodeEnum::PrepareStmt(..)) => Some("PrepareStmt"),
-                Some(NodeEnum::ExecuteStmt(..)) => Some("ExecuteStmt"),
-                Some(NodeEnum::DeallocateStmt(..)) => Some("DeallocateStmt"),
-                Some(NodeEnum::DeclareCursorStmt(..)) => Some("DeclareCursorStmt"),
-                Some(NodeEnum::CreateTableSpaceStmt(..)) => Some("CreateTableSpaceStmt"),
-                Some(NodeEnum::DropTableSpaceStmt(..)) => Some("DropTableSpaceStmt"),
-                Some(NodeEnum::AlterObjectDependsStmt(..)) => Some("AlterObjectDependsStmt"),
-                Some(NodeEnum::AlterObjectSchemaStmt(..)) => Some("AlterObjectSchemaStmt"),
-                Some(NodeEnum::AlterOwnerStmt(..)) => Some("AlterOwnerStmt"),
-                Some(NodeEnum::AlterOperatorStmt(..)) => Some("AlterOperatorStmt"),
-                Some(NodeEnum::AlterTypeStmt(..)) => Some("AlterTypeStmt"),
-                Some(NodeEnum::DropOwnedStmt(..)) => Some("DropOwnedStmt"),
-                Some(NodeEnum::ReassignOwnedStmt(..)) => Some("ReassignOwnedStmt"),
-                Some(NodeEnum::CompositeTypeStmt(..)) => Some("CompositeTypeStmt"),
-                Some(NodeEnum::CreateEnumStmt(..)) => Some("CreateEnumStmt"),
-                Some(NodeEnum::CreateRangeStmt(..)) => Some("CreateRangeStmt"),
-                Some(NodeEnum::AlterEnumStmt(..)) => Some("AlterEnumStmt"),
-                // AlterTSDictionaryStmt is capitalized differently to match C implementation.
-                Some(NodeEnum::AlterTsdictionaryStmt(..)) => Some("AlterTSDictionaryStmt"),
-                // AlterTSConfigurationStmt is capitalized differently to match C implementation.
-                Some(NodeEnum::AlterTsconfigurationStmt(..)) => Some("AlterTSConfigurationStmt"),
-                Some(NodeEnum::CreateFdwStmt(..)) => Some("CreateFdwStmt"),
-                Some(NodeEnum::AlterFdwStmt(..)) => Some("AlterFdwStmt"),
-                Some(NodeEnum::CreateForeignServerStmt(..)) => Some("CreateForeignServerStmt"),
-                Some(NodeEnum::AlterForeignServerStmt(..)) => Some("AlterForeignServerStmt"),
-                Some(NodeEnum::CreateUserMappingStmt(..)) => Some("CreateUserMappingStmt"),
-                Some(NodeEnum::AlterUserMappingStmt(..)) => Some("AlterUserMappingStmt"),
-                Some(NodeEnum::DropUserMappingStmt(..)) => Some("DropUserMappingStmt"),
-                Some(NodeEnum::AlterTableSpaceOptionsStmt(..)) => Some("AlterTableSpaceOptionsStmt"),
-                Some(NodeEnum::AlterTableMoveAllStmt(..)) => Some("AlterTableMoveAllStmt"),
-                Some(NodeEnum::SecLabelStmt(..)) => Some("SecLabelStmt"),
-                Some(NodeEnum::CreateForeignTableStmt(..)) => Some("CreateForeignTableStmt"),
-                Some(NodeEnum::ImportForeignSchemaStmt(..)) => Some("ImportForeignSchemaStmt"),
-                Some(NodeEnum::CreateExtensionStmt(..)) => Some("CreateExtensionStmt"),
-                Some(NodeEnum::AlterExtensionStmt(..)) => Some("AlterExtensionStmt"),
-                Some(NodeEnum::AlterExtensionContentsStmt(..)) => Some("AlterExtensionContentsStmt"),
-                Some(NodeEnum::CreateEventTrigStmt(..)) => Some("CreateEventTrigStmt"),
-                Some(NodeEnum::AlterEventTrigStmt(..)) => Some("AlterEventTrigStmt"),
-                Some(NodeEnum::RefreshMatViewStmt(..)) => Some("RefreshMatViewStmt"),
-                Some(NodeEnum::ReplicaIdentityStmt(..)) => Some("ReplicaIdentityStmt"),
-                Some(NodeEnum::AlterSystemStmt(..)) => Some("AlterSystemStmt"),
-                Some(NodeEnum::CreatePolicyStmt(..)) => Some("CreatePolicyStmt"),
-                Some(NodeEnum::AlterPolicyStmt(..)) => Some("AlterPolicyStmt"),
-                Some(NodeEnum::CreateTransformStmt(..)) => Some("CreateTransformStmt"),
-                Some(NodeEnum::CreateAmStmt(..)) => Some("CreateAmStmt"),
-                Some(NodeEnum::CreatePublicationStmt(..)) => Some("CreatePublicationStmt"),
-                Some(NodeEnum::AlterPublicationStmt(..)) => Some("AlterPublicationStmt"),
-                Some(NodeEnum::CreateSubscriptionStmt(..)) => Some("CreateSubscriptionStmt"),
-                Some(NodeEnum::AlterSubscriptionStmt(..)) => Some("AlterSubscriptionStmt"),
-                Some(NodeEnum::DropSubscriptionStmt(..)) => Some("DropSubscriptionStmt"),
-                Some(NodeEnum::CreateStatsStmt(..)) => Some("CreateStatsStmt"),
-                Some(NodeEnum::AlterCollationStmt(..)) => Some("AlterCollationStmt"),
-                Some(NodeEnum::CallStmt(..)) => Some("CallStmt"),
-                Some(NodeEnum::AlterStatsStmt(..)) => Some("AlterStatsStmt"),
-                _ => None,
-            })
-            .collect()
+        self.statement_kinds().into_iter().map(StatementKind::as_str).collect()
+    }
+
+    /// Returns the kinds of the statements in the query. Statements that aren't of a known
+    /// [StatementKind] are skipped, as in [ParseResult::statement_types].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pg_query::StatementKind;
+    ///
+    /// let result = pg_query::parse("BEGIN; CREATE TABLE t (a int); INSERT INTO t VALUES (1)").unwrap();
+    /// let kinds = result.statement_kinds();
+    /// assert_eq!(kinds, [StatementKind::Transaction, StatementKind::Create, StatementKind::Insert]);
+    /// assert!(kinds[1].is_ddl() && kinds[2].is_dml() && kinds[0].is_utility());
+    /// assert_eq!(kinds[2].to_string(), "InsertStmt");
+    /// ```
+    pub fn statement_kinds(&self) -> Vec<StatementKind> {
+        self.protobuf.stmts.iter().filter_map(|s| s.stmt.as_ref().and_then(|s| s.node.as_ref()).and_then(StatementKind::of)).collect()
     }
 }
+
+/// Deserializes `json` without serde_json's recursion limit, growing the stack while nesting instead.
+fn from_json_str<'de, T: serde::Deserialize<'de>>(json: &'de str) -> Result<T> {
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    deserializer.disable_recursion_limit();
+    T::deserialize(serde_stacker::Deserializer::new(&mut deserializer))
+        .and_then(|value| deserializer.end().map(|()| value))
+        .map_err(|e| Error::InvalidJson(e.to_string()))
+}
@@ -1,5 +1,6 @@
 // This file is @generated by prost-build.
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ParseResult {
     #[prost(int32, tag = "1")]
@@ -7,7 +8,8 @@ pub struct ParseResult {
     #[prost(message, repeated, tag = "2")]
     pub stmts: ::prost::alloc::vec::Vec<RawStmt>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ScanResult {
     #[prost(int32, tag = "1")]
@@ -15,7 +17,8 @@ pub struct ScanResult {
     #[prost(message, repeated, tag = "2")]
     pub tokens: ::prost::alloc::vec::Vec<ScanToken>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Node {
     #[prost(
@@ -26,7 +29,7 @@ pub struct Node {
 }
 /// Nested message and enum types in `Node`.
 pub mod node {
-    #[derive(serde::Serialize)]
+    #[derive(serde::Serialize, serde::Deserialize)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Node {
         #[prost(message, tag = "1")]
@@ -575,59 +578,68 @@ pub mod node {
         AConst(super::AConst),
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct Integer {
     /// machine integer
     #[prost(int32, tag = "1")]
     pub ival: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Float {
     /// string
     #[prost(string, tag = "1")]
     pub fval: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct Boolean {
     #[prost(bool, tag = "1")]
     pub boolval: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct String {
     /// string
     #[prost(string, tag = "1")]
     pub sval: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BitString {
     /// string
     #[prost(string, tag = "1")]
     pub bsval: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct List {
     #[prost(message, repeated, tag = "1")]
     pub items: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OidList {
     #[prost(message, repeated, tag = "1")]
     pub items: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct IntList {
     #[prost(message, repeated, tag = "1")]
     pub items: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AConst {
     #[prost(bool, tag = "10")]
@@ -639,7 +651,7 @@ pub struct AConst {
 }
 /// Nested message and enum types in `A_Const`.
 pub mod a_const {
-    #[derive(serde::Serialize)]
+    #[derive(serde::Serialize, serde::Deserialize)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Val {
         #[prost(message, tag = "1")]
@@ -654,7 +666,8 @@ pub mod a_const {
         Bsval(super::BitString),
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Alias {
     #[prost(string, tag = "1")]
@@ -662,7 +675,8 @@ pub struct Alias {
     #[prost(message, repeated, tag = "2")]
     pub colnames: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RangeVar {
     #[prost(string, tag = "1")]
@@ -680,7 +694,8 @@ pub struct RangeVar {
     #[prost(int32, tag = "7")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TableFunc {
     #[prost(enumeration = "TableFuncType", tag = "1")]
@@ -718,7 +733,8 @@ pub struct TableFunc {
     #[prost(int32, tag = "17")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct IntoClause {
     #[prost(message, optional, tag = "1")]
@@ -738,7 +754,8 @@ pub struct IntoClause {
     #[prost(bool, tag = "8")]
     pub skip_data: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Var {
     #[prost(message, optional, boxed, tag = "1")]
@@ -760,7 +777,8 @@ pub struct Var {
     #[prost(int32, tag = "9")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Param {
     #[prost(message, optional, boxed, tag = "1")]
@@ -778,7 +796,8 @@ pub struct Param {
     #[prost(int32, tag = "7")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Aggref {
     #[prost(message, optional, boxed, tag = "1")]
@@ -820,7 +839,8 @@ pub struct Aggref {
     #[prost(int32, tag = "19")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GroupingFunc {
     #[prost(message, optional, boxed, tag = "1")]
@@ -834,7 +854,8 @@ pub struct GroupingFunc {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WindowFunc {
     #[prost(message, optional, boxed, tag = "1")]
@@ -862,7 +883,8 @@ pub struct WindowFunc {
     #[prost(int32, tag = "12")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WindowFuncRunCondition {
     #[prost(message, optional, boxed, tag = "1")]
@@ -876,7 +898,8 @@ pub struct WindowFuncRunCondition {
     #[prost(message, optional, boxed, tag = "5")]
     pub arg: ::core::option::Option<::prost::alloc::boxed::Box<Node>>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MergeSupportFunc {
     #[prost(message, optional, boxed, tag = "1")]
@@ -888,7 +911,8 @@ pub struct MergeSupportFunc {
     #[prost(int32, tag = "4")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SubscriptingRef {
     #[prost(message, optional, boxed, tag = "1")]
@@ -912,7 +936,8 @@ pub struct SubscriptingRef {
     #[prost(message, optional, boxed, tag = "10")]
     pub refassgnexpr: ::core::option::Option<::prost::alloc::boxed::Box<Node>>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FuncExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -936,7 +961,8 @@ pub struct FuncExpr {
     #[prost(int32, tag = "10")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NamedArgExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -950,7 +976,8 @@ pub struct NamedArgExpr {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OpExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -970,7 +997,8 @@ pub struct OpExpr {
     #[prost(int32, tag = "8")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DistinctExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -990,7 +1018,8 @@ pub struct DistinctExpr {
     #[prost(int32, tag = "8")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NullIfExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1010,7 +1039,8 @@ pub struct NullIfExpr {
     #[prost(int32, tag = "8")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ScalarArrayOpExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1026,7 +1056,8 @@ pub struct ScalarArrayOpExpr {
     #[prost(int32, tag = "6")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BoolExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1038,7 +1069,8 @@ pub struct BoolExpr {
     #[prost(int32, tag = "4")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SubLink {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1056,7 +1088,8 @@ pub struct SubLink {
     #[prost(int32, tag = "7")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SubPlan {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1094,7 +1127,8 @@ pub struct SubPlan {
     #[prost(double, tag = "17")]
     pub per_call_cost: f64,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlternativeSubPlan {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1102,7 +1136,8 @@ pub struct AlternativeSubPlan {
     #[prost(message, repeated, tag = "2")]
     pub subplans: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FieldSelect {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1118,7 +1153,8 @@ pub struct FieldSelect {
     #[prost(uint32, tag = "6")]
     pub resultcollid: u32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FieldStore {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1132,7 +1168,8 @@ pub struct FieldStore {
     #[prost(uint32, tag = "5")]
     pub resulttype: u32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RelabelType {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1150,7 +1187,8 @@ pub struct RelabelType {
     #[prost(int32, tag = "7")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CoerceViaIo {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1166,7 +1204,8 @@ pub struct CoerceViaIo {
     #[prost(int32, tag = "6")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ArrayCoerceExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1186,7 +1225,8 @@ pub struct ArrayCoerceExpr {
     #[prost(int32, tag = "8")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConvertRowtypeExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1200,7 +1240,8 @@ pub struct ConvertRowtypeExpr {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CollateExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1212,7 +1253,8 @@ pub struct CollateExpr {
     #[prost(int32, tag = "4")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CaseExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1230,7 +1272,8 @@ pub struct CaseExpr {
     #[prost(int32, tag = "7")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CaseWhen {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1242,7 +1285,8 @@ pub struct CaseWhen {
     #[prost(int32, tag = "4")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CaseTestExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1254,7 +1298,8 @@ pub struct CaseTestExpr {
     #[prost(uint32, tag = "4")]
     pub collation: u32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ArrayExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1272,7 +1317,8 @@ pub struct ArrayExpr {
     #[prost(int32, tag = "7")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RowExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1288,7 +1334,8 @@ pub struct RowExpr {
     #[prost(int32, tag = "6")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RowCompareExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1306,7 +1353,8 @@ pub struct RowCompareExpr {
     #[prost(message, repeated, tag = "7")]
     pub rargs: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CoalesceExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1320,7 +1368,8 @@ pub struct CoalesceExpr {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MinMaxExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1338,7 +1387,8 @@ pub struct MinMaxExpr {
     #[prost(int32, tag = "7")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SqlValueFunction {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1352,7 +1402,8 @@ pub struct SqlValueFunction {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct XmlExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1378,7 +1429,8 @@ pub struct XmlExpr {
     #[prost(int32, tag = "11")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct JsonFormat {
     #[prost(enumeration = "JsonFormatType", tag = "1")]
@@ -1388,7 +1440,8 @@ pub struct JsonFormat {
     #[prost(int32, tag = "3")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct JsonReturning {
     #[prost(message, optional, tag = "1")]
@@ -1398,7 +1451,8 @@ pub struct JsonReturning {
     #[prost(int32, tag = "3")]
     pub typmod: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonValueExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1408,7 +1462,8 @@ pub struct JsonValueExpr {
     #[prost(message, optional, tag = "3")]
     pub format: ::core::option::Option<JsonFormat>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonConstructorExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1430,7 +1485,8 @@ pub struct JsonConstructorExpr {
     #[prost(int32, tag = "9")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonIsPredicate {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1444,7 +1500,8 @@ pub struct JsonIsPredicate {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonBehavior {
     #[prost(enumeration = "JsonBehaviorType", tag = "1")]
@@ -1456,7 +1513,8 @@ pub struct JsonBehavior {
     #[prost(int32, tag = "4")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1494,13 +1552,15 @@ pub struct JsonExpr {
     #[prost(int32, tag = "17")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonTablePath {
     #[prost(string, tag = "1")]
     pub name: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonTablePathScan {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1516,7 +1576,8 @@ pub struct JsonTablePathScan {
     #[prost(int32, tag = "6")]
     pub col_max: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonTableSiblingJoin {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1526,7 +1587,8 @@ pub struct JsonTableSiblingJoin {
     #[prost(message, optional, boxed, tag = "3")]
     pub rplan: ::core::option::Option<::prost::alloc::boxed::Box<Node>>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NullTest {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1540,7 +1602,8 @@ pub struct NullTest {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BooleanTest {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1552,7 +1615,8 @@ pub struct BooleanTest {
     #[prost(int32, tag = "4")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MergeAction {
     #[prost(enumeration = "MergeMatchKind", tag = "1")]
@@ -1568,7 +1632,8 @@ pub struct MergeAction {
     #[prost(message, repeated, tag = "6")]
     pub update_colnos: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CoerceToDomain {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1586,7 +1651,8 @@ pub struct CoerceToDomain {
     #[prost(int32, tag = "7")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CoerceToDomainValue {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1600,7 +1666,8 @@ pub struct CoerceToDomainValue {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SetToDefault {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1614,7 +1681,8 @@ pub struct SetToDefault {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CurrentOfExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1626,7 +1694,8 @@ pub struct CurrentOfExpr {
     #[prost(int32, tag = "4")]
     pub cursor_param: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NextValueExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1636,7 +1705,8 @@ pub struct NextValueExpr {
     #[prost(uint32, tag = "3")]
     pub type_id: u32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InferenceElem {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1648,7 +1718,8 @@ pub struct InferenceElem {
     #[prost(uint32, tag = "4")]
     pub inferopclass: u32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TargetEntry {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1668,13 +1739,15 @@ pub struct TargetEntry {
     #[prost(bool, tag = "8")]
     pub resjunk: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct RangeTblRef {
     #[prost(int32, tag = "1")]
     pub rtindex: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JoinExpr {
     #[prost(enumeration = "JoinType", tag = "1")]
@@ -1696,7 +1769,8 @@ pub struct JoinExpr {
     #[prost(int32, tag = "9")]
     pub rtindex: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FromExpr {
     #[prost(message, repeated, tag = "1")]
@@ -1704,7 +1778,8 @@ pub struct FromExpr {
     #[prost(message, optional, boxed, tag = "2")]
     pub quals: ::core::option::Option<::prost::alloc::boxed::Box<Node>>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OnConflictExpr {
     #[prost(enumeration = "OnConflictAction", tag = "1")]
@@ -1724,7 +1799,8 @@ pub struct OnConflictExpr {
     #[prost(message, repeated, tag = "8")]
     pub excl_rel_tlist: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Query {
     #[prost(enumeration = "CmdType", tag = "1")]
@@ -1812,7 +1888,8 @@ pub struct Query {
     #[prost(int32, tag = "42")]
     pub stmt_len: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TypeName {
     #[prost(message, repeated, tag = "1")]
@@ -1832,7 +1909,8 @@ pub struct TypeName {
     #[prost(int32, tag = "8")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ColumnRef {
     #[prost(message, repeated, tag = "1")]
@@ -1840,7 +1918,8 @@ pub struct ColumnRef {
     #[prost(int32, tag = "2")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct ParamRef {
     #[prost(int32, tag = "1")]
@@ -1848,7 +1927,8 @@ pub struct ParamRef {
     #[prost(int32, tag = "2")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AExpr {
     #[prost(enumeration = "AExprKind", tag = "1")]
@@ -1862,7 +1942,8 @@ pub struct AExpr {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TypeCast {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1872,7 +1953,8 @@ pub struct TypeCast {
     #[prost(int32, tag = "3")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CollateClause {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1882,7 +1964,8 @@ pub struct CollateClause {
     #[prost(int32, tag = "3")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RoleSpec {
     #[prost(enumeration = "RoleSpecType", tag = "1")]
@@ -1892,7 +1975,8 @@ pub struct RoleSpec {
     #[prost(int32, tag = "3")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FuncCall {
     #[prost(message, repeated, tag = "1")]
@@ -1918,10 +2002,12 @@ pub struct FuncCall {
     #[prost(int32, tag = "11")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct AStar {}
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AIndices {
     #[prost(bool, tag = "1")]
@@ -1931,7 +2017,8 @@ pub struct AIndices {
     #[prost(message, optional, boxed, tag = "3")]
     pub uidx: ::core::option::Option<::prost::alloc::boxed::Box<Node>>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AIndirection {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1939,7 +2026,8 @@ pub struct AIndirection {
     #[prost(message, repeated, tag = "2")]
     pub indirection: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AArrayExpr {
     #[prost(message, repeated, tag = "1")]
@@ -1947,7 +2035,8 @@ pub struct AArrayExpr {
     #[prost(int32, tag = "2")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResTarget {
     #[prost(string, tag = "1")]
@@ -1959,7 +2048,8 @@ pub struct ResTarget {
     #[prost(int32, tag = "4")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MultiAssignRef {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1969,7 +2059,8 @@ pub struct MultiAssignRef {
     #[prost(int32, tag = "3")]
     pub ncolumns: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SortBy {
     #[prost(message, optional, boxed, tag = "1")]
@@ -1983,7 +2074,8 @@ pub struct SortBy {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WindowDef {
     #[prost(string, tag = "1")]
@@ -2003,7 +2095,8 @@ pub struct WindowDef {
     #[prost(int32, tag = "8")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RangeSubselect {
     #[prost(bool, tag = "1")]
@@ -2013,7 +2106,8 @@ pub struct RangeSubselect {
     #[prost(message, optional, tag = "3")]
     pub alias: ::core::option::Option<Alias>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RangeFunction {
     #[prost(bool, tag = "1")]
@@ -2029,7 +2123,8 @@ pub struct RangeFunction {
     #[prost(message, repeated, tag = "6")]
     pub coldeflist: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RangeTableFunc {
     #[prost(bool, tag = "1")]
@@ -2047,7 +2142,8 @@ pub struct RangeTableFunc {
     #[prost(int32, tag = "7")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RangeTableFuncCol {
     #[prost(string, tag = "1")]
@@ -2065,7 +2161,8 @@ pub struct RangeTableFuncCol {
     #[prost(int32, tag = "7")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RangeTableSample {
     #[prost(message, optional, boxed, tag = "1")]
@@ -2079,7 +2176,8 @@ pub struct RangeTableSample {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ColumnDef {
     #[prost(string, tag = "1")]
@@ -2121,7 +2219,8 @@ pub struct ColumnDef {
     #[prost(int32, tag = "19")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TableLikeClause {
     #[prost(message, optional, tag = "1")]
@@ -2131,7 +2230,8 @@ pub struct TableLikeClause {
     #[prost(uint32, tag = "3")]
     pub relation_oid: u32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct IndexElem {
     #[prost(string, tag = "1")]
@@ -2151,7 +2251,8 @@ pub struct IndexElem {
     #[prost(enumeration = "SortByNulls", tag = "8")]
     pub nulls_ordering: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DefElem {
     #[prost(string, tag = "1")]
@@ -2165,7 +2266,8 @@ pub struct DefElem {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LockingClause {
     #[prost(message, repeated, tag = "1")]
@@ -2175,7 +2277,8 @@ pub struct LockingClause {
     #[prost(enumeration = "LockWaitPolicy", tag = "3")]
     pub wait_policy: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct XmlSerialize {
     #[prost(enumeration = "XmlOptionType", tag = "1")]
@@ -2189,7 +2292,8 @@ pub struct XmlSerialize {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PartitionElem {
     #[prost(string, tag = "1")]
@@ -2203,7 +2307,8 @@ pub struct PartitionElem {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PartitionSpec {
     #[prost(enumeration = "PartitionStrategy", tag = "1")]
@@ -2213,7 +2318,8 @@ pub struct PartitionSpec {
     #[prost(int32, tag = "3")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PartitionBoundSpec {
     #[prost(string, tag = "1")]
@@ -2233,7 +2339,8 @@ pub struct PartitionBoundSpec {
     #[prost(int32, tag = "8")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PartitionRangeDatum {
     #[prost(enumeration = "PartitionRangeDatumKind", tag = "1")]
@@ -2243,10 +2350,12 @@ pub struct PartitionRangeDatum {
     #[prost(int32, tag = "3")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct SinglePartitionSpec {}
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PartitionCmd {
     #[prost(message, optional, tag = "1")]
@@ -2256,7 +2365,8 @@ pub struct PartitionCmd {
     #[prost(bool, tag = "3")]
     pub concurrent: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RangeTblEntry {
     #[prost(message, optional, tag = "1")]
@@ -2326,7 +2436,8 @@ pub struct RangeTblEntry {
     #[prost(message, repeated, tag = "32")]
     pub security_quals: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RtePermissionInfo {
     #[prost(uint32, tag = "1")]
@@ -2344,7 +2455,8 @@ pub struct RtePermissionInfo {
     #[prost(uint64, repeated, tag = "7")]
     pub updated_cols: ::prost::alloc::vec::Vec<u64>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RangeTblFunction {
     #[prost(message, optional, boxed, tag = "1")]
@@ -2362,7 +2474,8 @@ pub struct RangeTblFunction {
     #[prost(uint64, repeated, tag = "7")]
     pub funcparams: ::prost::alloc::vec::Vec<u64>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TableSampleClause {
     #[prost(uint32, tag = "1")]
@@ -2372,7 +2485,8 @@ pub struct TableSampleClause {
     #[prost(message, optional, boxed, tag = "3")]
     pub repeatable: ::core::option::Option<::prost::alloc::boxed::Box<Node>>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WithCheckOption {
     #[prost(enumeration = "WcoKind", tag = "1")]
@@ -2386,7 +2500,8 @@ pub struct WithCheckOption {
     #[prost(bool, tag = "5")]
     pub cascaded: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct SortGroupClause {
     #[prost(uint32, tag = "1")]
@@ -2400,7 +2515,8 @@ pub struct SortGroupClause {
     #[prost(bool, tag = "5")]
     pub hashable: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GroupingSet {
     #[prost(enumeration = "GroupingSetKind", tag = "1")]
@@ -2410,7 +2526,8 @@ pub struct GroupingSet {
     #[prost(int32, tag = "3")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WindowClause {
     #[prost(string, tag = "1")]
@@ -2442,7 +2559,8 @@ pub struct WindowClause {
     #[prost(bool, tag = "14")]
     pub copied_order: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct RowMarkClause {
     #[prost(uint32, tag = "1")]
@@ -2454,7 +2572,8 @@ pub struct RowMarkClause {
     #[prost(bool, tag = "4")]
     pub pushed_down: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WithClause {
     #[prost(message, repeated, tag = "1")]
@@ -2464,7 +2583,8 @@ pub struct WithClause {
     #[prost(int32, tag = "3")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InferClause {
     #[prost(message, repeated, tag = "1")]
@@ -2476,7 +2596,8 @@ pub struct InferClause {
     #[prost(int32, tag = "4")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OnConflictClause {
     #[prost(enumeration = "OnConflictAction", tag = "1")]
@@ -2490,7 +2611,8 @@ pub struct OnConflictClause {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CteSearchClause {
     #[prost(message, repeated, tag = "1")]
@@ -2502,7 +2624,8 @@ pub struct CteSearchClause {
     #[prost(int32, tag = "4")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CteCycleClause {
     #[prost(message, repeated, tag = "1")]
@@ -2526,7 +2649,8 @@ pub struct CteCycleClause {
     #[prost(uint32, tag = "10")]
     pub cycle_mark_neop: u32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CommonTableExpr {
     #[prost(string, tag = "1")]
@@ -2556,7 +2680,8 @@ pub struct CommonTableExpr {
     #[prost(message, repeated, tag = "13")]
     pub ctecolcollations: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MergeWhenClause {
     #[prost(enumeration = "MergeMatchKind", tag = "1")]
@@ -2572,7 +2697,8 @@ pub struct MergeWhenClause {
     #[prost(message, repeated, tag = "6")]
     pub values: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TriggerTransition {
     #[prost(string, tag = "1")]
@@ -2582,7 +2708,8 @@ pub struct TriggerTransition {
     #[prost(bool, tag = "3")]
     pub is_table: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonOutput {
     #[prost(message, optional, tag = "1")]
@@ -2590,7 +2717,8 @@ pub struct JsonOutput {
     #[prost(message, optional, tag = "2")]
     pub returning: ::core::option::Option<JsonReturning>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonArgument {
     #[prost(message, optional, boxed, tag = "1")]
@@ -2598,7 +2726,8 @@ pub struct JsonArgument {
     #[prost(string, tag = "2")]
     pub name: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonFuncExpr {
     #[prost(enumeration = "JsonExprOp", tag = "1")]
@@ -2624,7 +2753,8 @@ pub struct JsonFuncExpr {
     #[prost(int32, tag = "11")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonTablePathSpec {
     #[prost(message, optional, boxed, tag = "1")]
@@ -2636,7 +2766,8 @@ pub struct JsonTablePathSpec {
     #[prost(int32, tag = "4")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonTable {
     #[prost(message, optional, boxed, tag = "1")]
@@ -2656,7 +2787,8 @@ pub struct JsonTable {
     #[prost(int32, tag = "8")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonTableColumn {
     #[prost(enumeration = "JsonTableColumnType", tag = "1")]
@@ -2682,7 +2814,8 @@ pub struct JsonTableColumn {
     #[prost(int32, tag = "11")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonKeyValue {
     #[prost(message, optional, boxed, tag = "1")]
@@ -2690,7 +2823,8 @@ pub struct JsonKeyValue {
     #[prost(message, optional, boxed, tag = "2")]
     pub value: ::core::option::Option<::prost::alloc::boxed::Box<JsonValueExpr>>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonParseExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -2702,7 +2836,8 @@ pub struct JsonParseExpr {
     #[prost(int32, tag = "4")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonScalarExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -2712,7 +2847,8 @@ pub struct JsonScalarExpr {
     #[prost(int32, tag = "3")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonSerializeExpr {
     #[prost(message, optional, boxed, tag = "1")]
@@ -2722,7 +2858,8 @@ pub struct JsonSerializeExpr {
     #[prost(int32, tag = "3")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonObjectConstructor {
     #[prost(message, repeated, tag = "1")]
@@ -2736,7 +2873,8 @@ pub struct JsonObjectConstructor {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonArrayConstructor {
     #[prost(message, repeated, tag = "1")]
@@ -2748,7 +2886,8 @@ pub struct JsonArrayConstructor {
     #[prost(int32, tag = "4")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonArrayQueryConstructor {
     #[prost(message, optional, boxed, tag = "1")]
@@ -2762,7 +2901,8 @@ pub struct JsonArrayQueryConstructor {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonAggConstructor {
     #[prost(message, optional, tag = "1")]
@@ -2776,7 +2916,8 @@ pub struct JsonAggConstructor {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonObjectAgg {
     #[prost(message, optional, boxed, tag = "1")]
@@ -2790,7 +2931,8 @@ pub struct JsonObjectAgg {
     #[prost(bool, tag = "4")]
     pub unique: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct JsonArrayAgg {
     #[prost(message, optional, boxed, tag = "1")]
@@ -2802,7 +2944,8 @@ pub struct JsonArrayAgg {
     #[prost(bool, tag = "3")]
     pub absent_on_null: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RawStmt {
     #[prost(message, optional, boxed, tag = "1")]
@@ -2812,7 +2955,8 @@ pub struct RawStmt {
     #[prost(int32, tag = "3")]
     pub stmt_len: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InsertStmt {
     #[prost(message, optional, tag = "1")]
@@ -2832,7 +2976,8 @@ pub struct InsertStmt {
     #[prost(enumeration = "OverridingKind", tag = "7")]
     pub r#override: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeleteStmt {
     #[prost(message, optional, tag = "1")]
@@ -2846,7 +2991,8 @@ pub struct DeleteStmt {
     #[prost(message, optional, tag = "5")]
     pub with_clause: ::core::option::Option<WithClause>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateStmt {
     #[prost(message, optional, tag = "1")]
@@ -2862,7 +3008,8 @@ pub struct UpdateStmt {
     #[prost(message, optional, tag = "6")]
     pub with_clause: ::core::option::Option<WithClause>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MergeStmt {
     #[prost(message, optional, tag = "1")]
@@ -2878,7 +3025,8 @@ pub struct MergeStmt {
     #[prost(message, optional, tag = "6")]
     pub with_clause: ::core::option::Option<WithClause>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SelectStmt {
     #[prost(message, repeated, tag = "1")]
@@ -2922,7 +3070,8 @@ pub struct SelectStmt {
     #[prost(message, optional, boxed, tag = "20")]
     pub rarg: ::core::option::Option<::prost::alloc::boxed::Box<SelectStmt>>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SetOperationStmt {
     #[prost(enumeration = "SetOperation", tag = "1")]
@@ -2942,13 +3091,15 @@ pub struct SetOperationStmt {
     #[prost(message, repeated, tag = "8")]
     pub group_clauses: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ReturnStmt {
     #[prost(message, optional, boxed, tag = "1")]
     pub returnval: ::core::option::Option<::prost::alloc::boxed::Box<Node>>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PlAssignStmt {
     #[prost(string, tag = "1")]
@@ -2962,7 +3113,8 @@ pub struct PlAssignStmt {
     #[prost(int32, tag = "5")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateSchemaStmt {
     #[prost(string, tag = "1")]
@@ -2974,7 +3126,8 @@ pub struct CreateSchemaStmt {
     #[prost(bool, tag = "4")]
     pub if_not_exists: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterTableStmt {
     #[prost(message, optional, tag = "1")]
@@ -2986,7 +3139,8 @@ pub struct AlterTableStmt {
     #[prost(bool, tag = "4")]
     pub missing_ok: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ReplicaIdentityStmt {
     #[prost(string, tag = "1")]
@@ -2994,7 +3148,8 @@ pub struct ReplicaIdentityStmt {
     #[prost(string, tag = "2")]
     pub name: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterTableCmd {
     #[prost(enumeration = "AlterTableType", tag = "1")]
@@ -3014,13 +3169,15 @@ pub struct AlterTableCmd {
     #[prost(bool, tag = "8")]
     pub recurse: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterCollationStmt {
     #[prost(message, repeated, tag = "1")]
     pub collname: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterDomainStmt {
     #[prost(string, tag = "1")]
@@ -3036,7 +3193,8 @@ pub struct AlterDomainStmt {
     #[prost(bool, tag = "6")]
     pub missing_ok: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GrantStmt {
     #[prost(bool, tag = "1")]
@@ -3058,7 +3216,8 @@ pub struct GrantStmt {
     #[prost(enumeration = "DropBehavior", tag = "9")]
     pub behavior: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ObjectWithArgs {
     #[prost(message, repeated, tag = "1")]
@@ -3070,7 +3229,8 @@ pub struct ObjectWithArgs {
     #[prost(bool, tag = "4")]
     pub args_unspecified: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AccessPriv {
     #[prost(string, tag = "1")]
@@ -3078,7 +3238,8 @@ pub struct AccessPriv {
     #[prost(message, repeated, tag = "2")]
     pub cols: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GrantRoleStmt {
     #[prost(message, repeated, tag = "1")]
@@ -3094,7 +3255,8 @@ pub struct GrantRoleStmt {
     #[prost(enumeration = "DropBehavior", tag = "6")]
     pub behavior: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterDefaultPrivilegesStmt {
     #[prost(message, repeated, tag = "1")]
@@ -3102,7 +3264,8 @@ pub struct AlterDefaultPrivilegesStmt {
     #[prost(message, optional, tag = "2")]
     pub action: ::core::option::Option<GrantStmt>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CopyStmt {
     #[prost(message, optional, tag = "1")]
@@ -3122,7 +3285,8 @@ pub struct CopyStmt {
     #[prost(message, optional, boxed, tag = "8")]
     pub where_clause: ::core::option::Option<::prost::alloc::boxed::Box<Node>>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VariableSetStmt {
     #[prost(enumeration = "VariableSetKind", tag = "1")]
@@ -3134,13 +3298,15 @@ pub struct VariableSetStmt {
     #[prost(bool, tag = "4")]
     pub is_local: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VariableShowStmt {
     #[prost(string, tag = "1")]
     pub name: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateStmt {
     #[prost(message, optional, tag = "1")]
@@ -3168,7 +3334,8 @@ pub struct CreateStmt {
     #[prost(bool, tag = "12")]
     pub if_not_exists: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Constraint {
     #[prost(enumeration = "ConstrType", tag = "1")]
@@ -3234,7 +3401,8 @@ pub struct Constraint {
     #[prost(int32, tag = "31")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateTableSpaceStmt {
     #[prost(string, tag = "1")]
@@ -3246,7 +3414,8 @@ pub struct CreateTableSpaceStmt {
     #[prost(message, repeated, tag = "4")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DropTableSpaceStmt {
     #[prost(string, tag = "1")]
@@ -3254,7 +3423,8 @@ pub struct DropTableSpaceStmt {
     #[prost(bool, tag = "2")]
     pub missing_ok: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterTableSpaceOptionsStmt {
     #[prost(string, tag = "1")]
@@ -3264,7 +3434,8 @@ pub struct AlterTableSpaceOptionsStmt {
     #[prost(bool, tag = "3")]
     pub is_reset: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterTableMoveAllStmt {
     #[prost(string, tag = "1")]
@@ -3278,7 +3449,8 @@ pub struct AlterTableMoveAllStmt {
     #[prost(bool, tag = "5")]
     pub nowait: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateExtensionStmt {
     #[prost(string, tag = "1")]
@@ -3288,7 +3460,8 @@ pub struct CreateExtensionStmt {
     #[prost(message, repeated, tag = "3")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterExtensionStmt {
     #[prost(string, tag = "1")]
@@ -3296,7 +3469,8 @@ pub struct AlterExtensionStmt {
     #[prost(message, repeated, tag = "2")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterExtensionContentsStmt {
     #[prost(string, tag = "1")]
@@ -3308,7 +3482,8 @@ pub struct AlterExtensionContentsStmt {
     #[prost(message, optional, boxed, tag = "4")]
     pub object: ::core::option::Option<::prost::alloc::boxed::Box<Node>>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateFdwStmt {
     #[prost(string, tag = "1")]
@@ -3318,7 +3493,8 @@ pub struct CreateFdwStmt {
     #[prost(message, repeated, tag = "3")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterFdwStmt {
     #[prost(string, tag = "1")]
@@ -3328,7 +3504,8 @@ pub struct AlterFdwStmt {
     #[prost(message, repeated, tag = "3")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateForeignServerStmt {
     #[prost(string, tag = "1")]
@@ -3344,7 +3521,8 @@ pub struct CreateForeignServerStmt {
     #[prost(message, repeated, tag = "6")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterForeignServerStmt {
     #[prost(string, tag = "1")]
@@ -3356,7 +3534,8 @@ pub struct AlterForeignServerStmt {
     #[prost(bool, tag = "4")]
     pub has_version: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateForeignTableStmt {
     #[prost(message, optional, tag = "1")]
@@ -3366,7 +3545,8 @@ pub struct CreateForeignTableStmt {
     #[prost(message, repeated, tag = "3")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateUserMappingStmt {
     #[prost(message, optional, tag = "1")]
@@ -3378,7 +3558,8 @@ pub struct CreateUserMappingStmt {
     #[prost(message, repeated, tag = "4")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterUserMappingStmt {
     #[prost(message, optional, tag = "1")]
@@ -3388,7 +3569,8 @@ pub struct AlterUserMappingStmt {
     #[prost(message, repeated, tag = "3")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DropUserMappingStmt {
     #[prost(message, optional, tag = "1")]
@@ -3398,7 +3580,8 @@ pub struct DropUserMappingStmt {
     #[prost(bool, tag = "3")]
     pub missing_ok: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ImportForeignSchemaStmt {
     #[prost(string, tag = "1")]
@@ -3414,7 +3597,8 @@ pub struct ImportForeignSchemaStmt {
     #[prost(message, repeated, tag = "6")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreatePolicyStmt {
     #[prost(string, tag = "1")]
@@ -3432,7 +3616,8 @@ pub struct CreatePolicyStmt {
     #[prost(message, optional, boxed, tag = "7")]
     pub with_check: ::core::option::Option<::prost::alloc::boxed::Box<Node>>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterPolicyStmt {
     #[prost(string, tag = "1")]
@@ -3446,7 +3631,8 @@ pub struct AlterPolicyStmt {
     #[prost(message, optional, boxed, tag = "5")]
     pub with_check: ::core::option::Option<::prost::alloc::boxed::Box<Node>>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateAmStmt {
     #[prost(string, tag = "1")]
@@ -3456,7 +3642,8 @@ pub struct CreateAmStmt {
     #[prost(string, tag = "3")]
     pub amtype: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateTrigStmt {
     #[prost(bool, tag = "1")]
@@ -3490,7 +3677,8 @@ pub struct CreateTrigStmt {
     #[prost(message, optional, tag = "15")]
     pub constrrel: ::core::option::Option<RangeVar>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateEventTrigStmt {
     #[prost(string, tag = "1")]
@@ -3502,7 +3690,8 @@ pub struct CreateEventTrigStmt {
     #[prost(message, repeated, tag = "4")]
     pub funcname: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterEventTrigStmt {
     #[prost(string, tag = "1")]
@@ -3510,7 +3699,8 @@ pub struct AlterEventTrigStmt {
     #[prost(string, tag = "2")]
     pub tgenabled: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreatePLangStmt {
     #[prost(bool, tag = "1")]
@@ -3526,7 +3716,8 @@ pub struct CreatePLangStmt {
     #[prost(bool, tag = "6")]
     pub pltrusted: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateRoleStmt {
     #[prost(enumeration = "RoleStmtType", tag = "1")]
@@ -3536,7 +3727,8 @@ pub struct CreateRoleStmt {
     #[prost(message, repeated, tag = "3")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterRoleStmt {
     #[prost(message, optional, tag = "1")]
@@ -3546,7 +3738,8 @@ pub struct AlterRoleStmt {
     #[prost(int32, tag = "3")]
     pub action: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterRoleSetStmt {
     #[prost(message, optional, tag = "1")]
@@ -3556,7 +3749,8 @@ pub struct AlterRoleSetStmt {
     #[prost(message, optional, tag = "3")]
     pub setstmt: ::core::option::Option<VariableSetStmt>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DropRoleStmt {
     #[prost(message, repeated, tag = "1")]
@@ -3564,7 +3758,8 @@ pub struct DropRoleStmt {
     #[prost(bool, tag = "2")]
     pub missing_ok: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateSeqStmt {
     #[prost(message, optional, tag = "1")]
@@ -3578,7 +3773,8 @@ pub struct CreateSeqStmt {
     #[prost(bool, tag = "5")]
     pub if_not_exists: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterSeqStmt {
     #[prost(message, optional, tag = "1")]
@@ -3590,7 +3786,8 @@ pub struct AlterSeqStmt {
     #[prost(bool, tag = "4")]
     pub missing_ok: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DefineStmt {
     #[prost(enumeration = "ObjectType", tag = "1")]
@@ -3608,7 +3805,8 @@ pub struct DefineStmt {
     #[prost(bool, tag = "7")]
     pub replace: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateDomainStmt {
     #[prost(message, repeated, tag = "1")]
@@ -3620,7 +3818,8 @@ pub struct CreateDomainStmt {
     #[prost(message, repeated, tag = "4")]
     pub constraints: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateOpClassStmt {
     #[prost(message, repeated, tag = "1")]
@@ -3636,7 +3835,8 @@ pub struct CreateOpClassStmt {
     #[prost(bool, tag = "6")]
     pub is_default: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateOpClassItem {
     #[prost(int32, tag = "1")]
@@ -3652,7 +3852,8 @@ pub struct CreateOpClassItem {
     #[prost(message, optional, tag = "6")]
     pub storedtype: ::core::option::Option<TypeName>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateOpFamilyStmt {
     #[prost(message, repeated, tag = "1")]
@@ -3660,7 +3861,8 @@ pub struct CreateOpFamilyStmt {
     #[prost(string, tag = "2")]
     pub amname: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterOpFamilyStmt {
     #[prost(message, repeated, tag = "1")]
@@ -3672,7 +3874,8 @@ pub struct AlterOpFamilyStmt {
     #[prost(message, repeated, tag = "4")]
     pub items: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DropStmt {
     #[prost(message, repeated, tag = "1")]
@@ -3686,7 +3889,8 @@ pub struct DropStmt {
     #[prost(bool, tag = "5")]
     pub concurrent: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TruncateStmt {
     #[prost(message, repeated, tag = "1")]
@@ -3696,7 +3900,8 @@ pub struct TruncateStmt {
     #[prost(enumeration = "DropBehavior", tag = "3")]
     pub behavior: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CommentStmt {
     #[prost(enumeration = "ObjectType", tag = "1")]
@@ -3706,7 +3911,8 @@ pub struct CommentStmt {
     #[prost(string, tag = "3")]
     pub comment: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SecLabelStmt {
     #[prost(enumeration = "ObjectType", tag = "1")]
@@ -3718,7 +3924,8 @@ pub struct SecLabelStmt {
     #[prost(string, tag = "4")]
     pub label: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeclareCursorStmt {
     #[prost(string, tag = "1")]
@@ -3728,13 +3935,15 @@ pub struct DeclareCursorStmt {
     #[prost(message, optional, boxed, tag = "3")]
     pub query: ::core::option::Option<::prost::alloc::boxed::Box<Node>>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClosePortalStmt {
     #[prost(string, tag = "1")]
     pub portalname: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FetchStmt {
     #[prost(enumeration = "FetchDirection", tag = "1")]
@@ -3746,7 +3955,8 @@ pub struct FetchStmt {
     #[prost(bool, tag = "4")]
     pub ismove: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct IndexStmt {
     #[prost(string, tag = "1")]
@@ -3798,7 +4008,8 @@ pub struct IndexStmt {
     #[prost(bool, tag = "24")]
     pub reset_default_tblspc: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateStatsStmt {
     #[prost(message, repeated, tag = "1")]
@@ -3816,7 +4027,8 @@ pub struct CreateStatsStmt {
     #[prost(bool, tag = "7")]
     pub if_not_exists: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StatsElem {
     #[prost(string, tag = "1")]
@@ -3824,7 +4036,8 @@ pub struct StatsElem {
     #[prost(message, optional, boxed, tag = "2")]
     pub expr: ::core::option::Option<::prost::alloc::boxed::Box<Node>>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterStatsStmt {
     #[prost(message, repeated, tag = "1")]
@@ -3834,7 +4047,8 @@ pub struct AlterStatsStmt {
     #[prost(bool, tag = "3")]
     pub missing_ok: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateFunctionStmt {
     #[prost(bool, tag = "1")]
@@ -3852,7 +4066,8 @@ pub struct CreateFunctionStmt {
     #[prost(message, optional, boxed, tag = "7")]
     pub sql_body: ::core::option::Option<::prost::alloc::boxed::Box<Node>>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FunctionParameter {
     #[prost(string, tag = "1")]
@@ -3864,7 +4079,8 @@ pub struct FunctionParameter {
     #[prost(message, optional, boxed, tag = "4")]
     pub defexpr: ::core::option::Option<::prost::alloc::boxed::Box<Node>>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterFunctionStmt {
     #[prost(enumeration = "ObjectType", tag = "1")]
@@ -3874,13 +4090,15 @@ pub struct AlterFunctionStmt {
     #[prost(message, repeated, tag = "3")]
     pub actions: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DoStmt {
     #[prost(message, repeated, tag = "1")]
     pub args: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InlineCodeBlock {
     #[prost(string, tag = "1")]
@@ -3892,7 +4110,8 @@ pub struct InlineCodeBlock {
     #[prost(bool, tag = "4")]
     pub atomic: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CallStmt {
     #[prost(message, optional, boxed, tag = "1")]
@@ -3902,13 +4121,15 @@ pub struct CallStmt {
     #[prost(message, repeated, tag = "3")]
     pub outargs: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CallContext {
     #[prost(bool, tag = "1")]
     pub atomic: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RenameStmt {
     #[prost(enumeration = "ObjectType", tag = "1")]
@@ -3928,7 +4149,8 @@ pub struct RenameStmt {
     #[prost(bool, tag = "8")]
     pub missing_ok: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterObjectDependsStmt {
     #[prost(enumeration = "ObjectType", tag = "1")]
@@ -3942,7 +4164,8 @@ pub struct AlterObjectDependsStmt {
     #[prost(bool, tag = "5")]
     pub remove: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterObjectSchemaStmt {
     #[prost(enumeration = "ObjectType", tag = "1")]
@@ -3956,7 +4179,8 @@ pub struct AlterObjectSchemaStmt {
     #[prost(bool, tag = "5")]
     pub missing_ok: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterOwnerStmt {
     #[prost(enumeration = "ObjectType", tag = "1")]
@@ -3968,7 +4192,8 @@ pub struct AlterOwnerStmt {
     #[prost(message, optional, tag = "4")]
     pub newowner: ::core::option::Option<RoleSpec>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterOperatorStmt {
     #[prost(message, optional, tag = "1")]
@@ -3976,7 +4201,8 @@ pub struct AlterOperatorStmt {
     #[prost(message, repeated, tag = "2")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterTypeStmt {
     #[prost(message, repeated, tag = "1")]
@@ -3984,7 +4210,8 @@ pub struct AlterTypeStmt {
     #[prost(message, repeated, tag = "2")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RuleStmt {
     #[prost(message, optional, tag = "1")]
@@ -4002,7 +4229,8 @@ pub struct RuleStmt {
     #[prost(bool, tag = "7")]
     pub replace: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NotifyStmt {
     #[prost(string, tag = "1")]
@@ -4010,19 +4238,22 @@ pub struct NotifyStmt {
     #[prost(string, tag = "2")]
     pub payload: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListenStmt {
     #[prost(string, tag = "1")]
     pub conditionname: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UnlistenStmt {
     #[prost(string, tag = "1")]
     pub conditionname: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TransactionStmt {
     #[prost(enumeration = "TransactionStmtKind", tag = "1")]
@@ -4038,7 +4269,8 @@ pub struct TransactionStmt {
     #[prost(int32, tag = "6")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CompositeTypeStmt {
     #[prost(message, optional, tag = "1")]
@@ -4046,7 +4278,8 @@ pub struct CompositeTypeStmt {
     #[prost(message, repeated, tag = "2")]
     pub coldeflist: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateEnumStmt {
     #[prost(message, repeated, tag = "1")]
@@ -4054,7 +4287,8 @@ pub struct CreateEnumStmt {
     #[prost(message, repeated, tag = "2")]
     pub vals: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateRangeStmt {
     #[prost(message, repeated, tag = "1")]
@@ -4062,7 +4296,8 @@ pub struct CreateRangeStmt {
     #[prost(message, repeated, tag = "2")]
     pub params: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterEnumStmt {
     #[prost(message, repeated, tag = "1")]
@@ -4078,7 +4313,8 @@ pub struct AlterEnumStmt {
     #[prost(bool, tag = "6")]
     pub skip_if_new_val_exists: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ViewStmt {
     #[prost(message, optional, tag = "1")]
@@ -4094,13 +4330,15 @@ pub struct ViewStmt {
     #[prost(enumeration = "ViewCheckOption", tag = "6")]
     pub with_check_option: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LoadStmt {
     #[prost(string, tag = "1")]
     pub filename: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreatedbStmt {
     #[prost(string, tag = "1")]
@@ -4108,7 +4346,8 @@ pub struct CreatedbStmt {
     #[prost(message, repeated, tag = "2")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterDatabaseStmt {
     #[prost(string, tag = "1")]
@@ -4116,13 +4355,15 @@ pub struct AlterDatabaseStmt {
     #[prost(message, repeated, tag = "2")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterDatabaseRefreshCollStmt {
     #[prost(string, tag = "1")]
     pub dbname: ::prost::alloc::string::String,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterDatabaseSetStmt {
     #[prost(string, tag = "1")]
@@ -4130,7 +4371,8 @@ pub struct AlterDatabaseSetStmt {
     #[prost(message, optional, tag = "2")]
     pub setstmt: ::core::option::Option<VariableSetStmt>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DropdbStmt {
     #[prost(string, tag = "1")]
@@ -4140,13 +4382,15 @@ pub struct DropdbStmt {
     #[prost(message, repeated, tag = "3")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterSystemStmt {
     #[prost(message, optional, tag = "1")]
     pub setstmt: ::core::option::Option<VariableSetStmt>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClusterStmt {
     #[prost(message, optional, tag = "1")]
@@ -4156,7 +4400,8 @@ pub struct ClusterStmt {
     #[prost(message, repeated, tag = "3")]
     pub params: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VacuumStmt {
     #[prost(message, repeated, tag = "1")]
@@ -4166,7 +4411,8 @@ pub struct VacuumStmt {
     #[prost(bool, tag = "3")]
     pub is_vacuumcmd: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VacuumRelation {
     #[prost(message, optional, tag = "1")]
@@ -4176,7 +4422,8 @@ pub struct VacuumRelation {
     #[prost(message, repeated, tag = "3")]
     pub va_cols: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ExplainStmt {
     #[prost(message, optional, boxed, tag = "1")]
@@ -4184,7 +4431,8 @@ pub struct ExplainStmt {
     #[prost(message, repeated, tag = "2")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateTableAsStmt {
     #[prost(message, optional, boxed, tag = "1")]
@@ -4198,7 +4446,8 @@ pub struct CreateTableAsStmt {
     #[prost(bool, tag = "5")]
     pub if_not_exists: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RefreshMatViewStmt {
     #[prost(bool, tag = "1")]
@@ -4208,16 +4457,19 @@ pub struct RefreshMatViewStmt {
     #[prost(message, optional, tag = "3")]
     pub relation: ::core::option::Option<RangeVar>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CheckPointStmt {}
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct DiscardStmt {
     #[prost(enumeration = "DiscardMode", tag = "1")]
     pub target: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LockStmt {
     #[prost(message, repeated, tag = "1")]
@@ -4227,7 +4479,8 @@ pub struct LockStmt {
     #[prost(bool, tag = "3")]
     pub nowait: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConstraintsSetStmt {
     #[prost(message, repeated, tag = "1")]
@@ -4235,7 +4488,8 @@ pub struct ConstraintsSetStmt {
     #[prost(bool, tag = "2")]
     pub deferred: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ReindexStmt {
     #[prost(enumeration = "ReindexObjectType", tag = "1")]
@@ -4247,7 +4501,8 @@ pub struct ReindexStmt {
     #[prost(message, repeated, tag = "4")]
     pub params: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateConversionStmt {
     #[prost(message, repeated, tag = "1")]
@@ -4261,7 +4516,8 @@ pub struct CreateConversionStmt {
     #[prost(bool, tag = "5")]
     pub def: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateCastStmt {
     #[prost(message, optional, tag = "1")]
@@ -4275,7 +4531,8 @@ pub struct CreateCastStmt {
     #[prost(bool, tag = "5")]
     pub inout: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateTransformStmt {
     #[prost(bool, tag = "1")]
@@ -4289,7 +4546,8 @@ pub struct CreateTransformStmt {
     #[prost(message, optional, tag = "5")]
     pub tosql: ::core::option::Option<ObjectWithArgs>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PrepareStmt {
     #[prost(string, tag = "1")]
@@ -4299,7 +4557,8 @@ pub struct PrepareStmt {
     #[prost(message, optional, boxed, tag = "3")]
     pub query: ::core::option::Option<::prost::alloc::boxed::Box<Node>>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ExecuteStmt {
     #[prost(string, tag = "1")]
@@ -4307,7 +4566,8 @@ pub struct ExecuteStmt {
     #[prost(message, repeated, tag = "2")]
     pub params: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeallocateStmt {
     #[prost(string, tag = "1")]
@@ -4317,7 +4577,8 @@ pub struct DeallocateStmt {
     #[prost(int32, tag = "3")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DropOwnedStmt {
     #[prost(message, repeated, tag = "1")]
@@ -4325,7 +4586,8 @@ pub struct DropOwnedStmt {
     #[prost(enumeration = "DropBehavior", tag = "2")]
     pub behavior: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ReassignOwnedStmt {
     #[prost(message, repeated, tag = "1")]
@@ -4333,7 +4595,8 @@ pub struct ReassignOwnedStmt {
     #[prost(message, optional, tag = "2")]
     pub newrole: ::core::option::Option<RoleSpec>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterTsDictionaryStmt {
     #[prost(message, repeated, tag = "1")]
@@ -4341,7 +4604,8 @@ pub struct AlterTsDictionaryStmt {
     #[prost(message, repeated, tag = "2")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterTsConfigurationStmt {
     #[prost(enumeration = "AlterTsConfigType", tag = "1")]
@@ -4359,7 +4623,8 @@ pub struct AlterTsConfigurationStmt {
     #[prost(bool, tag = "7")]
     pub missing_ok: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PublicationTable {
     #[prost(message, optional, tag = "1")]
@@ -4369,7 +4634,8 @@ pub struct PublicationTable {
     #[prost(message, repeated, tag = "3")]
     pub columns: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PublicationObjSpec {
     #[prost(enumeration = "PublicationObjSpecType", tag = "1")]
@@ -4381,7 +4647,8 @@ pub struct PublicationObjSpec {
     #[prost(int32, tag = "4")]
     pub location: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreatePublicationStmt {
     #[prost(string, tag = "1")]
@@ -4393,7 +4660,8 @@ pub struct CreatePublicationStmt {
     #[prost(bool, tag = "4")]
     pub for_all_tables: bool,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterPublicationStmt {
     #[prost(string, tag = "1")]
@@ -4407,7 +4675,8 @@ pub struct AlterPublicationStmt {
     #[prost(enumeration = "AlterPublicationAction", tag = "5")]
     pub action: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateSubscriptionStmt {
     #[prost(string, tag = "1")]
@@ -4419,7 +4688,8 @@ pub struct CreateSubscriptionStmt {
     #[prost(message, repeated, tag = "4")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AlterSubscriptionStmt {
     #[prost(enumeration = "AlterSubscriptionType", tag = "1")]
@@ -4433,7 +4703,8 @@ pub struct AlterSubscriptionStmt {
     #[prost(message, repeated, tag = "5")]
     pub options: ::prost::alloc::vec::Vec<Node>,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DropSubscriptionStmt {
     #[prost(string, tag = "1")]
@@ -4443,7 +4714,8 @@ pub struct DropSubscriptionStmt {
     #[prost(enumeration = "DropBehavior", tag = "3")]
     pub behavior: i32,
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct ScanToken {
     #[prost(int32, tag = "1")]
@@ -4461,7 +4733,8 @@ pub struct ScanToken {
 /// These fields have `// optional` at the end of the line.
 ///
 /// Upstream issue: <https://github.com/protobuf-c/protobuf-c/issues/476>
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SummaryResult {
     #[prost(message, repeated, tag = "1")]
@@ -4486,7 +4759,8 @@ pub struct SummaryResult {
 }
 /// Nested message and enum types in `SummaryResult`.
 pub mod summary_result {
-    #[derive(serde::Serialize)]
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Table {
         #[prost(string, tag = "1")]
@@ -4498,7 +4772,8 @@ pub mod summary_result {
         #[prost(enumeration = "Context", tag = "4")]
         pub context: i32,
     }
-    #[derive(serde::Serialize)]
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Function {
         #[prost(string, tag = "1")]
@@ -4511,7 +4786,8 @@ pub mod summary_result {
         #[prost(enumeration = "Context", tag = "4")]
         pub context: i32,
     }
-    #[derive(serde::Serialize)]
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct FilterColumn {
         /// optional
@@ -4523,7 +4799,7 @@ pub mod summary_result {
         #[prost(string, tag = "3")]
         pub column: ::prost::alloc::string::String,
     }
-    #[derive(serde::Serialize)]
+    #[derive(serde::Serialize, serde::Deserialize)]
     #[derive(
         Clone,
         Copy,
@@ -4570,7 +4846,7 @@ pub mod summary_result {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum QuerySource {
@@ -4609,7 +4885,7 @@ impl QuerySource {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum SortByDir {
@@ -4645,7 +4921,7 @@ impl SortByDir {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum SortByNulls {
@@ -4678,7 +4954,7 @@ impl SortByNulls {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum SetQuantifier {
@@ -4711,7 +4987,7 @@ impl SetQuantifier {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum AExprKind {
@@ -4777,7 +5053,7 @@ impl AExprKind {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum RoleSpecType {
@@ -4816,7 +5092,7 @@ impl RoleSpecType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum TableLikeOption {
@@ -4870,7 +5146,7 @@ impl TableLikeOption {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum DefElemAction {
@@ -4906,7 +5182,7 @@ impl DefElemAction {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum PartitionStrategy {
@@ -4939,7 +5215,7 @@ impl PartitionStrategy {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum PartitionRangeDatumKind {
@@ -4972,7 +5248,7 @@ impl PartitionRangeDatumKind {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum RteKind {
@@ -5023,7 +5299,7 @@ impl RteKind {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum WcoKind {
@@ -5065,7 +5341,7 @@ impl WcoKind {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum GroupingSetKind {
@@ -5104,7 +5380,7 @@ impl GroupingSetKind {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum CteMaterialize {
@@ -5137,7 +5413,7 @@ impl CteMaterialize {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum JsonQuotes {
@@ -5170,7 +5446,7 @@ impl JsonQuotes {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum JsonTableColumnType {
@@ -5209,7 +5485,7 @@ impl JsonTableColumnType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum SetOperation {
@@ -5245,7 +5521,7 @@ impl SetOperation {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum ObjectType {
@@ -5425,7 +5701,7 @@ impl ObjectType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum DropBehavior {
@@ -5455,7 +5731,7 @@ impl DropBehavior {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum AlterTableType {
@@ -5680,7 +5956,7 @@ impl AlterTableType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum GrantTargetType {
@@ -5713,7 +5989,7 @@ impl GrantTargetType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum VariableSetKind {
@@ -5755,7 +6031,7 @@ impl VariableSetKind {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum ConstrType {
@@ -5821,7 +6097,7 @@ impl ConstrType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum ImportForeignSchemaType {
@@ -5854,7 +6130,7 @@ impl ImportForeignSchemaType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum RoleStmtType {
@@ -5887,7 +6163,7 @@ impl RoleStmtType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum FetchDirection {
@@ -5923,7 +6199,7 @@ impl FetchDirection {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum FunctionParameterMode {
@@ -5965,7 +6241,7 @@ impl FunctionParameterMode {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum TransactionStmtKind {
@@ -6019,7 +6295,7 @@ impl TransactionStmtKind {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum ViewCheckOption {
@@ -6052,7 +6328,7 @@ impl ViewCheckOption {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum DiscardMode {
@@ -6088,7 +6364,7 @@ impl DiscardMode {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum ReindexObjectType {
@@ -6127,7 +6403,7 @@ impl ReindexObjectType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum AlterTsConfigType {
@@ -6174,7 +6450,7 @@ impl AlterTsConfigType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum PublicationObjSpecType {
@@ -6214,7 +6490,7 @@ impl PublicationObjSpecType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum AlterPublicationAction {
@@ -6247,7 +6523,7 @@ impl AlterPublicationAction {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum AlterSubscriptionType {
@@ -6303,7 +6579,7 @@ impl AlterSubscriptionType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum OverridingKind {
@@ -6336,7 +6612,7 @@ impl OverridingKind {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum OnCommitAction {
@@ -6372,7 +6648,7 @@ impl OnCommitAction {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum TableFuncType {
@@ -6402,7 +6678,7 @@ impl TableFuncType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum ParamKind {
@@ -6438,7 +6714,7 @@ impl ParamKind {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum CoercionContext {
@@ -6474,7 +6750,7 @@ impl CoercionContext {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum CoercionForm {
@@ -6510,7 +6786,7 @@ impl CoercionForm {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum BoolExprType {
@@ -6543,7 +6819,7 @@ impl BoolExprType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum SubLinkType {
@@ -6591,7 +6867,7 @@ impl SubLinkType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum RowCompareType {
@@ -6633,7 +6909,7 @@ impl RowCompareType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum MinMaxOp {
@@ -6663,7 +6939,7 @@ impl MinMaxOp {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum SqlValueFunctionOp {
@@ -6732,7 +7008,7 @@ impl SqlValueFunctionOp {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum XmlExprOp {
@@ -6780,7 +7056,7 @@ impl XmlExprOp {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum XmlOptionType {
@@ -6810,7 +7086,7 @@ impl XmlOptionType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum JsonEncoding {
@@ -6846,7 +7122,7 @@ impl JsonEncoding {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum JsonFormatType {
@@ -6879,7 +7155,7 @@ impl JsonFormatType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum JsonConstructorType {
@@ -6924,7 +7200,7 @@ impl JsonConstructorType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum JsonValueType {
@@ -6960,7 +7236,7 @@ impl JsonValueType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum JsonWrapper {
@@ -6996,7 +7272,7 @@ impl JsonWrapper {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum JsonBehaviorType {
@@ -7047,7 +7323,7 @@ impl JsonBehaviorType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum JsonExprOp {
@@ -7083,7 +7359,7 @@ impl JsonExprOp {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum NullTestType {
@@ -7113,7 +7389,7 @@ impl NullTestType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum BoolTestType {
@@ -7155,7 +7431,7 @@ impl BoolTestType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum MergeMatchKind {
@@ -7188,7 +7464,7 @@ impl MergeMatchKind {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum CmdType {
@@ -7236,7 +7512,7 @@ impl CmdType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum JoinType {
@@ -7287,7 +7563,7 @@ impl JoinType {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum AggStrategy {
@@ -7323,7 +7599,7 @@ impl AggStrategy {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum AggSplit {
@@ -7356,7 +7632,7 @@ impl AggSplit {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum SetOpCmd {
@@ -7392,7 +7668,7 @@ impl SetOpCmd {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum SetOpStrategy {
@@ -7422,7 +7698,7 @@ impl SetOpStrategy {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum OnConflictAction {
@@ -7455,7 +7731,7 @@ impl OnConflictAction {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum LimitOption {
@@ -7488,7 +7764,7 @@ impl LimitOption {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum LockClauseStrength {
@@ -7527,7 +7803,7 @@ impl LockClauseStrength {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum LockWaitPolicy {
@@ -7560,7 +7836,7 @@ impl LockWaitPolicy {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum LockTupleMode {
@@ -7596,7 +7872,7 @@ impl LockTupleMode {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum KeywordKind {
@@ -7632,7 +7908,7 @@ impl KeywordKind {
         }
     }
 }
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum Token {
@@ -5,8 +5,10 @@ use prost::Message;
 
 use crate::bindings::*;
 use crate::error::*;
+use crate::limits::ParseLimits;
 use crate::parse_result::ParseResult;
 use crate::protobuf;
+use crate::{Node, NodeEnum};
 
 /// Represents the resulting fingerprint containing both the raw integer form as well as the
 /// corresponding 16 character hex value.
@@ -15,6 +17,63 @@ pub struct Fingerprint {
     pub hex: String,
 }
 
+/// The grammar entry point the parser should start from.
+///
+/// From libpg_query: pg_query.h (`PgQueryParseMode`), which mirrors `RawParseMode` in the Postgres source.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum ParseMode {
+    /// A list of SQL statements
+    #[default]
+    Default = 0,
+    /// A single type name, e.g. `varchar(20)[]`
+    TypeName = 1,
+    /// A PL/pgSQL expression, e.g. `a + 1 > $1`
+    PlpgsqlExpr = 2,
+    /// A PL/pgSQL assignment statement with a single-part target name (`a := 1`)
+    PlpgsqlAssign1 = 3,
+    /// A PL/pgSQL assignment statement with a two-part target name (`a.b := 1`)
+    PlpgsqlAssign2 = 4,
+    /// A PL/pgSQL assignment statement with a three-part target name (`a.b.c := 1`)
+    PlpgsqlAssign3 = 5,
+}
+
+/// Options passed to the parser, see [parse_with_options].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    pub mode: ParseMode,
+    /// Equivalent to `backslash_quote = off`
+    pub disable_backslash_quote: bool,
+    /// Equivalent to `standard_conforming_strings = off`
+    pub disable_standard_conforming_strings: bool,
+    /// Equivalent to `escape_string_warning = off`
+    pub disable_escape_string_warning: bool,
+    /// Limits on the statement and its parse tree, unlimited by default
+    pub limits: ParseLimits,
+}
+
+impl ParseOptions {
+    /// Returns options that only change the parse mode.
+    pub fn with_mode(mode: ParseMode) -> Self {
+        Self { mode, ..Self::default() }
+    }
+
+    /// Encodes the options into the `parser_options` bitmask expected by libpg_query.
+    pub(crate) fn bits(&self) -> i32 {
+        let mut bits = self.mode as i32;
+        if self.disable_backslash_quote {
+            bits |= PG_QUERY_DISABLE_BACKSLASH_QUOTE as i32;
+        }
+        if self.disable_standard_conforming_strings {
+            bits |= PG_QUERY_DISABLE_STANDARD_CONFORMING_STRINGS as i32;
+        }
+        if self.disable_escape_string_warning {
+            bits |= PG_QUERY_DISABLE_ESCAPE_STRING_WARNING as i32;
+        }
+        bits
+    }
+}
+
 /// Parses the given SQL statement into the given abstract syntax tree.
 ///
 /// # Example
@@ -29,20 +88,112 @@ pub struct Fingerprint {
 /// assert!(matches!(result.protobuf.nodes()[0].0, NodeRef::SelectStmt(_)));
 /// ```
 pub fn parse(statement: &str) -> Result<ParseResult> {
+    parse_with_options(statement, ParseOptions::default())
+}
+
+/// Parses the given SQL fragment using the given parser options.
+///
+/// Outside of [ParseMode::Default], the result contains a single statement
+/// wrapping the node produced by the grammar entry point: a `TypeName` for
+/// [ParseMode::TypeName], a `SelectStmt` for [ParseMode::PlpgsqlExpr], and
+/// a `PlassignStmt` for the `PlpgsqlAssign*` modes.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::{NodeEnum, ParseMode, ParseOptions};
+///
+/// let result = pg_query::parse_with_options("varchar(20)[]", ParseOptions::with_mode(ParseMode::TypeName)).unwrap();
+/// let node = result.protobuf.stmts[0].stmt.as_ref().and_then(|s| s.node.as_ref());
+/// assert!(matches!(node, Some(NodeEnum::TypeName(_))));
+/// ```
+pub fn parse_with_options(statement: &str, options: ParseOptions) -> Result<ParseResult> {
     let input = CString::new(statement)?;
-    let result = unsafe { pg_query_parse_protobuf(input.as_ptr()) };
+    options.limits.check_input(statement)?;
+    let result = unsafe { pg_query_parse_protobuf_opts(input.as_ptr(), options.bits()) };
     let parse_result = if !result.error.is_null() {
-        let message = unsafe { CStr::from_ptr((*result.error).message) }.to_string_lossy().to_string();
-        Err(Error::Parse(message))
+        Err(Error::Syntax(Box::new(unsafe { ParseError::from_c(statement, result.error) })))
     } else {
         let data = unsafe { std::slice::from_raw_parts(result.parse_tree.data as *const u8, result.parse_tree.len as usize) };
         let stderr = unsafe { CStr::from_ptr(result.stderr_buffer) }.to_string_lossy().to_string();
-        protobuf::ParseResult::decode(data).map_err(Error::Decode).map(|result| ParseResult::new(result, stderr))
+        options
+            .limits
+            .check_encoded(data)
+            .and_then(|()| protobuf::ParseResult::decode(data).map_err(Error::Decode))
+            .map(|result| ParseResult::new(result, stderr).with_statement(statement))
     };
     unsafe { pg_query_free_protobuf_parse_result(result) };
     parse_result
 }
 
+/// Parses the given SQL statement into the JSON format of libpg_query, which the pg_query bindings for other
+/// languages share. It can be read back with [ParseResult::from_libpg_query_json].
+///
+/// Unlike [ParseResult::to_json], fields and node types are named after the Postgres structs, enums are
+/// encoded by name, and fields holding default values are left out.
+///
+/// # Example
+///
+/// ```rust
+/// let json = pg_query::parse_json("SELECT 1").unwrap();
+/// assert!(json.contains(r#""targetList":[{"ResTarget":"#));
+/// let result = pg_query::ParseResult::from_libpg_query_json(&json).unwrap();
+/// assert_eq!(result.protobuf, pg_query::parse("SELECT 1").unwrap().protobuf);
+/// ```
+pub fn parse_json(statement: &str) -> Result<String> {
+    let input = CString::new(statement)?;
+    let result = unsafe { pg_query_parse(input.as_ptr()) };
+    let parse_result = if !result.error.is_null() {
+        Err(Error::Syntax(Box::new(unsafe { ParseError::from_c(statement, result.error) })))
+    } else {
+        Ok(unsafe { CStr::from_ptr(result.parse_tree) }.to_string_lossy().to_string())
+    };
+    unsafe { pg_query_free_parse_result(result) };
+    parse_result
+}
+
+/// Parses a bare type name, such as `varchar(20)[]` or `public.my_type`.
+///
+/// # Example
+///
+/// ```rust
+/// let type_name = pg_query::parse_type_name("varchar(20)[]").unwrap();
+/// assert_eq!(type_name.array_bounds.len(), 1);
+/// ```
+pub fn parse_type_name(type_name: &str) -> Result<protobuf::TypeName> {
+    let result = parse_with_options(type_name, ParseOptions::with_mode(ParseMode::TypeName))?;
+    match result.protobuf.stmts.into_iter().next().and_then(|s| s.stmt).and_then(|s| s.node) {
+        Some(NodeEnum::TypeName(t)) => Ok(t),
+        _ => Err(Error::UnexpectedInput(format!("expected a type name: {type_name}"))),
+    }
+}
+
+/// Parses a standalone expression, such as `a + 1 > $1`, without wrapping it in a `SELECT`.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::NodeEnum;
+///
+/// let expr = pg_query::parse_expression("a + 1 > $1").unwrap();
+/// assert!(matches!(expr.node, Some(NodeEnum::AExpr(_))));
+/// ```
+pub fn parse_expression(expression: &str) -> Result<Node> {
+    let result = parse_with_options(expression, ParseOptions::with_mode(ParseMode::PlpgsqlExpr))?;
+    let select = match result.protobuf.stmts.into_iter().next().and_then(|s| s.stmt).and_then(|s| s.node) {
+        Some(NodeEnum::SelectStmt(s)) => s,
+        _ => return Err(Error::UnexpectedInput(format!("expected an expression: {expression}"))),
+    };
+    // PL/pgSQL expressions may contain FROM, WHERE, etc. Only a single bare target is a standalone expression.
+    if select.target_list.len() != 1 || !select.from_clause.is_empty() || select.where_clause.is_some() {
+        return Err(Error::UnexpectedInput(format!("expected a single expression: {expression}")));
+    }
+    match select.target_list.into_iter().next().and_then(|t| t.node) {
+        Some(NodeEnum::ResTarget(t)) => t.val.map(|v| *v).ok_or_else(|| Error::UnexpectedInput(format!("expected an expression: {expression}"))),
+        _ => Err(Error::UnexpectedInput(format!("expected an expression: {expression}"))),
+    }
+}
+
 /// Converts a parsed tree back into a string.
 ///
 /// # Example
@@ -74,7 +225,7 @@ pub fn deparse(protobuf: &protobuf::ParseResult) -> Result<String> {
 
     let deparse_result = if !result.error.is_null() {
         let message = unsafe { CStr::from_ptr((*result.error).message) }.to_string_lossy().to_string();
-        Err(Error::Parse(message))
+        Err(Error::Deparse(message))
     } else {
         let query = unsafe { CStr::from_ptr(result.query) }.to_string_lossy().to_string();
         Ok(query)
@@ -98,8 +249,7 @@ pub fn normalize(statement: &str) -> Result<String> {
     let input = CString::new(statement).unwrap();
     let result = unsafe { pg_query_normalize(input.as_ptr()) };
     let normalized_query = if !result.error.is_null() {
-        let message = unsafe { CStr::from_ptr((*result.error).message) }.to_string_lossy().to_string();
-        Err(Error::Parse(message))
+        Err(Error::Syntax(Box::new(unsafe { ParseError::from_c(statement, result.error) })))
     } else {
         let n = unsafe { CStr::from_ptr(result.normalized_query) };
         Ok(n.to_string_lossy().to_string())
@@ -123,8 +273,7 @@ pub fn fingerprint(statement: &str) -> Result<Fingerprint> {
     let input = CString::new(statement)?;
     let result = unsafe { pg_query_fingerprint(input.as_ptr()) };
     let fingerprint = if !result.error.is_null() {
-        let message = unsafe { CStr::from_ptr((*result.error).message) }.to_string_lossy().to_string();
-        Err(Error::Parse(message))
+        Err(Error::Syntax(Box::new(unsafe { ParseError::from_c(statement, result.error) })))
     } else {
         let hex = unsafe { CStr::from_ptr(result.fingerprint_str) };
         Ok(Fingerprint { value: result.fingerprint, hex: hex.to_string_lossy().to_string() })
@@ -155,8 +304,7 @@ pub fn parse_plpgsql(stmt: &str) -> Result<serde_json::Value> {
     let input = CString::new(stmt)?;
     let result = unsafe { pg_query_parse_plpgsql(input.as_ptr()) };
     let structure = if !result.error.is_null() {
-        let message = unsafe { CStr::from_ptr((*result.error).message) }.to_string_lossy().to_string();
-        Err(Error::Parse(message))
+        Err(Error::Syntax(Box::new(unsafe { ParseError::from_c(stmt, result.error) })))
     } else {
         let raw = unsafe { CStr::from_ptr(result.plpgsql_funcs) };
         serde_json::from_str(&raw.to_string_lossy()).map_err(|e| Error::InvalidJson(e.to_string()))
@@ -179,16 +327,18 @@ pub fn parse_plpgsql(stmt: &str) -> Result<serde_json::Value> {
 ///
 /// ```rust
 /// let query = "select 1; this statement is not sql; select 2;";
-/// let result = pg_query::split_with_parser(query);
-/// let err = r#"syntax error at or near "this""#;
-/// assert_eq!(result, Err(pg_query::Error::Split(err.to_string())));
+/// let error = match pg_query::split_with_parser(query) {
+///     Err(pg_query::Error::Syntax(error)) => error,
+///     _ => unreachable!(),
+/// };
+/// assert_eq!(error.message, r#"syntax error at or near "this""#);
+/// assert_eq!(error.cursor, Some(10));
 /// ```
 pub fn split_with_parser(query: &str) -> Result<Vec<&str>> {
     let input = CString::new(query)?;
     let result = unsafe { pg_query_split_with_parser(input.as_ptr()) };
     let split_result = if !result.error.is_null() {
-        let message = unsafe { CStr::from_ptr((*result.error).message) }.to_string_lossy().to_string();
-        Err(Error::Split(message))
+        Err(Error::Syntax(Box::new(unsafe { ParseError::from_c(query, result.error) })))
     } else {
         let n_stmts = result.n_stmts as usize;
         let mut statements = Vec::with_capacity(n_stmts);
@@ -43,7 +43,7 @@ pub fn deparse_raw(protobuf: &protobuf::ParseResult) -> Result<String> {
         if !result.error.is_null() {
             let message = CStr::from_ptr((*result.error).message).to_string_lossy().to_string();
             bindings_raw::pg_query_free_deparse_result(result);
-            return Err(Error::Parse(message));
+            return Err(Error::Deparse(message));
         }
 
         let query = CStr::from_ptr(result.query).to_string_lossy().to_string();
@@ -4,9 +4,10 @@
 //! parsing directly into PostgreSQL's internal structures and fingerprinting them
 //! without going through protobuf serialization.
 
+use crate::bindings;
 use crate::bindings_raw;
 use crate::query::Fingerprint;
-use crate::{Error, Result};
+use crate::{Error, ParseError, Result};
 use std::ffi::{CStr, CString};
 
 /// Fingerprints a SQL statement without going through protobuf serialization.
@@ -34,8 +35,7 @@ pub fn fingerprint_raw(statement: &str) -> Result<Fingerprint> {
 
     // Convert the fingerprint result to Rust types
     let result = if !fingerprint_result.error.is_null() {
-        let message = unsafe { CStr::from_ptr((*fingerprint_result.error).message) }.to_string_lossy().to_string();
-        Err(Error::Parse(message))
+        Err(Error::Syntax(Box::new(unsafe { ParseError::from_c(statement, fingerprint_result.error as *const bindings::PgQueryError) })))
     } else {
         let hex = unsafe { CStr::from_ptr(fingerprint_result.fingerprint_str) };
         Ok(Fingerprint { value: fingerprint_result.fingerprint, hex: hex.to_string_lossy().to_string() })
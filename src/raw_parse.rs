@@ -9,9 +9,12 @@
 
 use crate::bindings;
 use crate::bindings_raw;
+use crate::limits::{LimitKind, ParseLimits};
 use crate::parse_result::ParseResult;
 use crate::protobuf;
-use crate::{Error, Result};
+use crate::query::ParseOptions;
+use crate::{Error, ParseError, Result};
+use std::cell::RefCell;
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 
@@ -27,26 +30,135 @@ use std::os::raw::c_char;
 /// assert_eq!(result.tables(), vec!["users"]);
 /// ```
 pub fn parse_raw(statement: &str) -> Result<ParseResult> {
+    parse_raw_with_options(statement, ParseOptions::default())
+}
+
+/// Same as [parse_raw], but parses the statement with the given parser options.
+///
+/// See [crate::parse_with_options] for the shape of the result in each [crate::ParseMode].
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::{NodeEnum, ParseMode, ParseOptions};
+///
+/// let result = pg_query::parse_raw_with_options("a + 1 > $1", ParseOptions::with_mode(ParseMode::PlpgsqlExpr)).unwrap();
+/// let node = result.protobuf.stmts[0].stmt.as_ref().and_then(|s| s.node.as_ref());
+/// assert!(matches!(node, Some(NodeEnum::SelectStmt(_))));
+/// ```
+pub fn parse_raw_with_options(statement: &str, options: ParseOptions) -> Result<ParseResult> {
+    let input = CString::new(statement)?;
+    parse_raw_checked(&input, statement, options).map(|(result, _)| result)
+}
+
+/// Same as [parse_raw], but fails with [Error::UnhandledNode] instead of leaving out nodes it can't convert.
+///
+/// Every node the raw parser produces is converted, so this only fails if that stops being the case
+/// (e.g. after upgrading PostgreSQL). Use it where a silently incomplete tree would be worse than an error,
+/// such as before deparsing a modified tree back to SQL.
+///
+/// # Example
+///
+/// ```rust
+/// let result = pg_query::parse_raw_strict("CREATE FUNCTION one() RETURNS int RETURN 1").unwrap();
+/// assert_eq!(result.protobuf, pg_query::parse("CREATE FUNCTION one() RETURNS int RETURN 1").unwrap().protobuf);
+/// ```
+pub fn parse_raw_strict(statement: &str) -> Result<ParseResult> {
+    parse_raw_strict_with_options(statement, ParseOptions::default())
+}
+
+/// Same as [parse_raw_strict], but parses the statement with the given parser options.
+pub fn parse_raw_strict_with_options(statement: &str, options: ParseOptions) -> Result<ParseResult> {
     let input = CString::new(statement)?;
-    let result = unsafe { bindings_raw::pg_query_parse_raw(input.as_ptr()) };
+    match parse_raw_checked(&input, statement, options)? {
+        (_, Some(node_tag)) => Err(Error::UnhandledNode(node_tag_name(node_tag).map_or_else(|| format!("NodeTag {node_tag}"), str::to_string))),
+        (result, None) => Ok(result),
+    }
+}
+
+/// Parses `input`, which must hold the same text as `statement`, also returning the tag of the first node
+/// that couldn't be converted.
+fn parse_raw_checked(input: &CStr, statement: &str, options: ParseOptions) -> Result<(ParseResult, Option<bindings_raw::NodeTag>)> {
+    options.limits.check_input(statement)?;
+    let result = unsafe { bindings_raw::pg_query_parse_raw_opts(input.as_ptr(), options.bits()) };
 
     let parse_result = if !result.error.is_null() {
-        let message = unsafe { CStr::from_ptr((*result.error).message) }.to_string_lossy().to_string();
-        Err(Error::Parse(message))
+        Err(Error::Syntax(Box::new(unsafe { ParseError::from_c(statement, result.error as *const bindings::PgQueryError) })))
     } else {
         // Convert the C parse tree to protobuf types
         let tree = result.tree;
-        let stmts = unsafe { convert_list_to_raw_stmts(tree) };
-        let protobuf = protobuf::ParseResult { version: bindings::PG_VERSION_NUM as i32, stmts };
-        Ok(ParseResult::new(protobuf, String::new()))
+        let statements = if tree.is_null() { 0 } else { unsafe { (*tree).length as usize } };
+        options.limits.check(LimitKind::Statements, statements).and_then(|()| {
+            let (stmts, unhandled) = convert_with_limits(options.limits, || unsafe { convert_list_to_raw_stmts(tree) })?;
+            let protobuf = protobuf::ParseResult { version: bindings::PG_VERSION_NUM as i32, stmts };
+            Ok((ParseResult::new(protobuf, String::new()).with_statement(statement), unhandled))
+        })
     };
 
     unsafe { bindings_raw::pg_query_free_raw_parse_result(result) };
     parse_result
 }
 
+/// Counts the nodes converted on this thread, to enforce [ParseLimits] and find unhandled nodes.
+#[derive(Default)]
+struct Conversion {
+    limits: ParseLimits,
+    depth: usize,
+    nodes: usize,
+    /// The first limit that was exceeded, after which no more nodes are converted
+    exceeded: Option<Error>,
+    /// Tag of the first node that [convert_node] couldn't convert
+    unhandled: Option<bindings_raw::NodeTag>,
+}
+
+impl Conversion {
+    /// Counts a node about to be converted, returning false if it shouldn't be.
+    fn enter(&mut self) -> bool {
+        if self.exceeded.is_some() {
+            return false;
+        }
+        let checked = self.limits.check(LimitKind::Depth, self.depth + 1).and_then(|()| self.limits.check(LimitKind::Nodes, self.nodes + 1));
+        match checked {
+            Ok(()) => {
+                self.depth += 1;
+                self.nodes += 1;
+                true
+            }
+            Err(error) => {
+                self.exceeded = Some(error);
+                false
+            }
+        }
+    }
+
+    fn leave(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+thread_local! {
+    static CONVERSION: RefCell<Conversion> = RefCell::new(Conversion::default());
+}
+
+/// Runs `convert` with the given limits, failing if it exceeded any of them. Otherwise returns its output and
+/// the tag of the first node that couldn't be converted.
+///
+/// Conversions outside of this are unlimited, as the state is left at its default.
+fn convert_with_limits<T>(limits: ParseLimits, convert: impl FnOnce() -> T) -> Result<(T, Option<bindings_raw::NodeTag>)> {
+    CONVERSION.set(Conversion { limits, ..Conversion::default() });
+    let output = convert();
+    let conversion = CONVERSION.take();
+    match conversion.exceeded {
+        Some(error) => Err(error),
+        None => Ok((output, conversion.unhandled)),
+    }
+}
+
 /// Converts a PostgreSQL List of RawStmt nodes to protobuf RawStmt vector.
-unsafe fn convert_list_to_raw_stmts(list: *mut bindings_raw::List) -> Vec<protobuf::RawStmt> {
+///
+/// Non-default parse modes (e.g. `ParseMode::TypeName`) return bare nodes instead of RawStmts.
+/// These are wrapped in a RawStmt, matching the output of the protobuf-based parser.
+pub(crate) unsafe fn convert_list_to_raw_stmts(list: *mut bindings_raw::List) -> Vec<protobuf::RawStmt> {
     if list.is_null() {
         return Vec::new();
     }
@@ -64,6 +176,8 @@ unsafe fn convert_list_to_raw_stmts(list: *mut bindings_raw::List) -> Vec<protob
             if node_tag == bindings_raw::NodeTag_T_RawStmt {
                 let raw_stmt = node_ptr as *mut bindings_raw::RawStmt;
                 stmts.push(convert_raw_stmt(&*raw_stmt));
+            } else {
+                stmts.push(protobuf::RawStmt { stmt: convert_node_boxed(node_ptr), stmt_location: 0, stmt_len: 0 });
             }
         }
     }
@@ -82,8 +196,8 @@ unsafe fn convert_node_boxed(node_ptr: *mut bindings_raw::Node) -> Option<Box<pr
 }
 
 /// Converts a C Node pointer to a protobuf Node.
-unsafe fn convert_node(node_ptr: *mut bindings_raw::Node) -> Option<protobuf::Node> {
-    if node_ptr.is_null() {
+pub(crate) unsafe fn convert_node(node_ptr: *mut bindings_raw::Node) -> Option<protobuf::Node> {
+    if node_ptr.is_null() || !CONVERSION.with_borrow_mut(Conversion::enter) {
         return None;
     }
 
@@ -964,12 +1078,32 @@ unsafe fn convert_node(node_ptr: *mut bindings_raw::Node) -> Option<protobuf::No
             let ce = node_ptr as *mut bindings_raw::CurrentOfExpr;
             Some(protobuf::node::Node::CurrentOfExpr(Box::new(convert_current_of_expr(&*ce))))
         }
+        bindings_raw::NodeTag_T_PLAssignStmt => {
+            let pas = node_ptr as *mut bindings_raw::PLAssignStmt;
+            Some(protobuf::node::Node::PlassignStmt(Box::new(convert_pl_assign_stmt(&*pas))))
+        }
+        bindings_raw::NodeTag_T_ReturnStmt => {
+            let rs = node_ptr as *mut bindings_raw::ReturnStmt;
+            Some(protobuf::node::Node::ReturnStmt(Box::new(convert_return_stmt(&*rs))))
+        }
+        bindings_raw::NodeTag_T_IntList => {
+            let list = node_ptr as *mut bindings_raw::List;
+            Some(protobuf::node::Node::IntList(protobuf::IntList { items: convert_int_list(&*list, |cell| cell.int_value as i32) }))
+        }
+        bindings_raw::NodeTag_T_OidList => {
+            let list = node_ptr as *mut bindings_raw::List;
+            Some(protobuf::node::Node::OidList(protobuf::OidList { items: convert_int_list(&*list, |cell| cell.oid_value as i32) }))
+        }
         _ => {
-            // For unhandled node types, return None
-            // In the future, we could add more node types here
+            // Only planner and executor nodes are left, which the raw parser doesn't produce.
+            // Remember the first one for parse_raw_strict.
+            CONVERSION.with_borrow_mut(|conversion| {
+                conversion.unhandled.get_or_insert(node_tag);
+            });
             None
         }
     };
+    CONVERSION.with_borrow_mut(Conversion::leave);
 
     node.map(|n| protobuf::Node { node: Some(n) })
 }
@@ -1030,11 +1164,22 @@ unsafe fn convert_select_stmt(stmt: &bindings_raw::SelectStmt) -> protobuf::Sele
         with_clause: convert_with_clause_opt(stmt.withClause),
         op: stmt.op as i32 + 1, // Protobuf SetOperation has UNDEFINED=0, so C values need +1
         all: stmt.all,
-        larg: if stmt.larg.is_null() { None } else { Some(Box::new(convert_select_stmt(&*stmt.larg))) },
-        rarg: if stmt.rarg.is_null() { None } else { Some(Box::new(convert_select_stmt(&*stmt.rarg))) },
+        larg: convert_set_operand(stmt.larg),
+        rarg: convert_set_operand(stmt.rarg),
     }
 }
 
+/// Converts an operand of a set operation, which is a SelectStmt rather than a Node, but counts as a node
+/// towards the limits.
+unsafe fn convert_set_operand(stmt: *mut bindings_raw::SelectStmt) -> Option<Box<protobuf::SelectStmt>> {
+    if stmt.is_null() || !CONVERSION.with_borrow_mut(Conversion::enter) {
+        return None;
+    }
+    let operand = convert_select_stmt(&*stmt);
+    CONVERSION.with_borrow_mut(Conversion::leave);
+    Some(Box::new(operand))
+}
+
 unsafe fn convert_insert_stmt(stmt: &bindings_raw::InsertStmt) -> protobuf::InsertStmt {
     protobuf::InsertStmt {
         relation: if stmt.relation.is_null() { None } else { Some(convert_range_var(&*stmt.relation)) },
@@ -1522,6 +1667,27 @@ unsafe fn convert_current_of_expr(ce: &bindings_raw::CurrentOfExpr) -> protobuf:
     protobuf::CurrentOfExpr { xpr: None, cvarno: ce.cvarno, cursor_name: convert_c_string(ce.cursor_name), cursor_param: ce.cursor_param }
 }
 
+unsafe fn convert_pl_assign_stmt(pas: &bindings_raw::PLAssignStmt) -> protobuf::PlAssignStmt {
+    protobuf::PlAssignStmt {
+        name: convert_c_string(pas.name),
+        indirection: convert_list_to_nodes(pas.indirection),
+        nnames: pas.nnames,
+        val: if pas.val.is_null() { None } else { Some(Box::new(convert_select_stmt(&*pas.val))) },
+        location: pas.location,
+    }
+}
+
+unsafe fn convert_return_stmt(rs: &bindings_raw::ReturnStmt) -> protobuf::ReturnStmt {
+    protobuf::ReturnStmt { returnval: convert_node_boxed(rs.returnval) }
+}
+
+/// Converts an IntList or OidList, whose cells hold values instead of node pointers, to Integer nodes.
+unsafe fn convert_int_list(list: &bindings_raw::List, value: impl Fn(&bindings_raw::ListCell) -> i32) -> Vec<protobuf::Node> {
+    (0..list.length as usize)
+        .map(|i| protobuf::Node { node: Some(protobuf::node::Node::Integer(protobuf::Integer { ival: value(&*list.elements.add(i)) })) })
+        .collect()
+}
+
 unsafe fn convert_locking_clause(lc: &bindings_raw::LockingClause) -> protobuf::LockingClause {
     protobuf::LockingClause {
         locked_rels: convert_list_to_nodes(lc.lockedRels),
@@ -2164,6 +2330,9 @@ unsafe fn convert_access_priv(ap: &bindings_raw::AccessPriv) -> protobuf::Access
 // Utility Functions
 // ============================================================================
 
+// The node tag names, generated from nodetags.h by build.rs
+include!(concat!(env!("OUT_DIR"), "/node_tags.rs"));
+
 /// Converts a C string pointer to a Rust String.
 unsafe fn convert_c_string(ptr: *const c_char) -> std::string::String {
     if ptr.is_null() {
@@ -0,0 +1,646 @@
+//! Borrowed, zero-copy views over PostgreSQL's internal parse tree.
+//!
+//! [crate::parse_raw] converts the whole C parse tree into protobuf types up front. Callers that only
+//! look at a few fields of each statement (e.g. a proxy routing queries by statement type and table)
+//! can instead keep the C tree alive in a [RawParseTree], and read it in place through the `Raw*` views,
+//! converting only the parts they need with [RawParseTree::to_owned] or [RawNode::to_owned].
+
+// Some casts are necessary for cross-platform compatibility (e.g., `long` is i32 on Windows but i64 on Linux)
+#![allow(clippy::unnecessary_cast)]
+
+use std::ffi::{CStr, CString};
+use std::marker::PhantomData;
+use std::os::raw::c_char;
+
+use crate::bindings;
+use crate::bindings_raw;
+use crate::limits::LimitKind;
+use crate::parse_result::ParseResult;
+use crate::protobuf;
+use crate::query::ParseOptions;
+use crate::raw_parse::{convert_list_to_raw_stmts, convert_node};
+use crate::{Error, ParseError, Result};
+
+/// Parses a SQL statement, and keeps PostgreSQL's parse tree around to be read in place.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::{RawNodeRef, parse_raw_tree};
+///
+/// let tree = parse_raw_tree("SELECT * FROM public.users WHERE id = $1").unwrap();
+/// let stmt = tree.stmts().next().unwrap();
+/// let Some(RawNodeRef::SelectStmt(select)) = stmt.stmt().map(|node| node.to_ref()) else { panic!() };
+/// let Some(RawNodeRef::RangeVar(table)) = select.from_clause().iter().next().map(|node| node.to_ref()) else { panic!() };
+/// assert_eq!((table.schemaname(), table.relname()), (Some("public"), "users"));
+/// ```
+pub fn parse_raw_tree(statement: &str) -> Result<RawParseTree<'_>> {
+    parse_raw_tree_with_options(statement, ParseOptions::default())
+}
+
+/// Same as [parse_raw_tree], but parses the statement with the given parser options.
+///
+/// Nothing is converted up front, so only the input length and statement count limits of
+/// [ParseOptions::limits] apply.
+pub fn parse_raw_tree_with_options(statement: &str, options: ParseOptions) -> Result<RawParseTree<'_>> {
+    options.limits.check_input(statement)?;
+    let input = CString::new(statement)?;
+    let result = unsafe { bindings_raw::pg_query_parse_raw_opts(input.as_ptr(), options.bits()) };
+    if !result.error.is_null() {
+        let error = unsafe { ParseError::from_c(statement, result.error as *const bindings::PgQueryError) };
+        unsafe { bindings_raw::pg_query_free_raw_parse_result(result) };
+        return Err(Error::Syntax(Box::new(error)));
+    }
+    let tree = RawParseTree { statement, result, _thread_local: PhantomData };
+    options.limits.check(LimitKind::Statements, tree.stmts().len())?;
+    Ok(tree)
+}
+
+/// PostgreSQL's parse tree for a statement, which is freed when this is dropped.
+///
+/// The tree lives in one of libpg_query's thread-local memory contexts, so a `RawParseTree` is neither
+/// `Send` nor `Sync`.
+pub struct RawParseTree<'a> {
+    statement: &'a str,
+    result: bindings_raw::PgQueryRawParseResult,
+    _thread_local: PhantomData<*mut ()>,
+}
+
+impl<'a> RawParseTree<'a> {
+    /// The SQL text this tree was parsed from.
+    pub fn statement(&self) -> &'a str {
+        self.statement
+    }
+
+    /// Iterates over the top-level statements.
+    pub fn stmts(&self) -> impl ExactSizeIterator<Item = RawStmt<'a, '_>> {
+        let statement = self.statement;
+        unsafe { RawList::new(self.result.tree) }.cells().map(move |node| match node {
+            Some(node) if node.node.type_ == bindings_raw::NodeTag_T_RawStmt => {
+                let raw_stmt = unsafe { &*(node.node as *const bindings_raw::Node as *const bindings_raw::RawStmt) };
+                RawStmt { statement, stmt: unsafe { RawNode::new(raw_stmt.stmt) }, location: raw_stmt.stmt_location, len: raw_stmt.stmt_len }
+            }
+            // Non-default parse modes return bare nodes, which cover the whole statement
+            _ => RawStmt { statement, stmt: node, location: 0, len: 0 },
+        })
+    }
+
+    /// Converts the whole tree to protobuf types, giving the same result as [crate::parse_raw_with_options].
+    pub fn to_owned(&self) -> ParseResult {
+        let stmts = unsafe { convert_list_to_raw_stmts(self.result.tree) };
+        ParseResult::new(protobuf::ParseResult { version: bindings::PG_VERSION_NUM as i32, stmts }, String::new()).with_statement(self.statement)
+    }
+}
+
+impl Drop for RawParseTree<'_> {
+    fn drop(&mut self) {
+        unsafe { bindings_raw::pg_query_free_raw_parse_result(self.result) };
+    }
+}
+
+/// A top-level statement of a [RawParseTree].
+#[derive(Clone, Copy)]
+pub struct RawStmt<'a, 't> {
+    statement: &'a str,
+    stmt: Option<RawNode<'t>>,
+    location: i32,
+    len: i32,
+}
+
+impl<'a, 't> RawStmt<'a, 't> {
+    pub fn stmt(&self) -> Option<RawNode<'t>> {
+        self.stmt
+    }
+
+    /// Byte offset of the statement in the parsed text.
+    pub fn stmt_location(&self) -> i32 {
+        self.location
+    }
+
+    /// Length of the statement in bytes, or 0 if it extends to the end of the parsed text.
+    pub fn stmt_len(&self) -> i32 {
+        self.len
+    }
+
+    /// The text of this statement, without the separating semicolon.
+    pub fn sql(&self) -> &'a str {
+        let start = self.location as usize;
+        let end = if self.len == 0 { self.statement.len() } else { start + self.len as usize };
+        &self.statement[start..end]
+    }
+}
+
+/// A node of a [RawParseTree].
+#[derive(Clone, Copy)]
+pub struct RawNode<'t> {
+    node: &'t bindings_raw::Node,
+}
+
+impl<'t> RawNode<'t> {
+    /// # Safety
+    ///
+    /// `ptr` must be null, or point to a node that lives for `'t`.
+    unsafe fn new(ptr: *mut bindings_raw::Node) -> Option<Self> {
+        ptr.as_ref().map(|node| Self { node })
+    }
+
+    /// Reinterprets this node as its concrete struct, which must match its tag.
+    unsafe fn cast<T>(self) -> &'t T {
+        &*(self.node as *const bindings_raw::Node as *const T)
+    }
+
+    /// Returns a typed view of this node, or [RawNodeRef::Other] for nodes without one.
+    pub fn to_ref(self) -> RawNodeRef<'t> {
+        unsafe {
+            match self.node.type_ {
+                bindings_raw::NodeTag_T_SelectStmt => RawNodeRef::SelectStmt(RawSelectStmt(self.cast())),
+                bindings_raw::NodeTag_T_InsertStmt => RawNodeRef::InsertStmt(RawInsertStmt(self.cast())),
+                bindings_raw::NodeTag_T_UpdateStmt => RawNodeRef::UpdateStmt(RawUpdateStmt(self.cast())),
+                bindings_raw::NodeTag_T_DeleteStmt => RawNodeRef::DeleteStmt(RawDeleteStmt(self.cast())),
+                bindings_raw::NodeTag_T_TransactionStmt => RawNodeRef::TransactionStmt(RawTransactionStmt(self.cast())),
+                bindings_raw::NodeTag_T_VariableSetStmt => RawNodeRef::VariableSetStmt(RawVariableSetStmt(self.cast())),
+                bindings_raw::NodeTag_T_RangeVar => RawNodeRef::RangeVar(RawRangeVar(self.cast())),
+                bindings_raw::NodeTag_T_RangeSubselect => RawNodeRef::RangeSubselect(RawRangeSubselect(self.cast())),
+                bindings_raw::NodeTag_T_JoinExpr => RawNodeRef::JoinExpr(RawJoinExpr(self.cast())),
+                bindings_raw::NodeTag_T_ResTarget => RawNodeRef::ResTarget(RawResTarget(self.cast())),
+                bindings_raw::NodeTag_T_ColumnRef => RawNodeRef::ColumnRef(RawColumnRef(self.cast())),
+                bindings_raw::NodeTag_T_A_Const => RawNodeRef::AConst(RawAConst(self.cast())),
+                bindings_raw::NodeTag_T_ParamRef => RawNodeRef::ParamRef(RawParamRef(self.cast())),
+                bindings_raw::NodeTag_T_A_Expr => RawNodeRef::AExpr(RawAExpr(self.cast())),
+                bindings_raw::NodeTag_T_BoolExpr => RawNodeRef::BoolExpr(RawBoolExpr(self.cast())),
+                bindings_raw::NodeTag_T_FuncCall => RawNodeRef::FuncCall(RawFuncCall(self.cast())),
+                bindings_raw::NodeTag_T_String => RawNodeRef::String(c_str(self.cast::<bindings_raw::String>().sval).unwrap_or_default()),
+                bindings_raw::NodeTag_T_A_Star => RawNodeRef::AStar,
+                _ => RawNodeRef::Other(self),
+            }
+        }
+    }
+
+    /// Converts this node and its children to protobuf types.
+    ///
+    /// Nodes that the conversion doesn't handle become empty nodes, as they do in [crate::parse_raw].
+    pub fn to_owned(self) -> protobuf::Node {
+        unsafe { convert_node(self.node as *const bindings_raw::Node as *mut bindings_raw::Node) }.unwrap_or(protobuf::Node { node: None })
+    }
+}
+
+/// Typed views of the nodes a [RawParseTree] can hold.
+///
+/// Only the nodes most often inspected without a full conversion have a view; the others are returned as
+/// [RawNodeRef::Other], and can still be converted with [RawNode::to_owned].
+#[derive(Clone, Copy)]
+pub enum RawNodeRef<'t> {
+    SelectStmt(RawSelectStmt<'t>),
+    InsertStmt(RawInsertStmt<'t>),
+    UpdateStmt(RawUpdateStmt<'t>),
+    DeleteStmt(RawDeleteStmt<'t>),
+    TransactionStmt(RawTransactionStmt<'t>),
+    VariableSetStmt(RawVariableSetStmt<'t>),
+    RangeVar(RawRangeVar<'t>),
+    RangeSubselect(RawRangeSubselect<'t>),
+    JoinExpr(RawJoinExpr<'t>),
+    ResTarget(RawResTarget<'t>),
+    ColumnRef(RawColumnRef<'t>),
+    AConst(RawAConst<'t>),
+    ParamRef(RawParamRef<'t>),
+    AExpr(RawAExpr<'t>),
+    BoolExpr(RawBoolExpr<'t>),
+    FuncCall(RawFuncCall<'t>),
+    String(&'t str),
+    AStar,
+    Other(RawNode<'t>),
+}
+
+/// A list of nodes in a [RawParseTree].
+#[derive(Clone, Copy)]
+pub struct RawList<'t> {
+    list: Option<&'t bindings_raw::List>,
+}
+
+impl<'t> RawList<'t> {
+    /// # Safety
+    ///
+    /// `ptr` must be null (the empty list), or point to a list that lives for `'t`.
+    unsafe fn new(ptr: *mut bindings_raw::List) -> Self {
+        Self { list: ptr.as_ref() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.map_or(0, |list| list.length as usize)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterates over the nodes of the list, skipping empty cells (e.g. the placeholder of a plain `DISTINCT`).
+    pub fn iter(&self) -> impl Iterator<Item = RawNode<'t>> {
+        self.cells().flatten()
+    }
+
+    fn cells(self) -> impl ExactSizeIterator<Item = Option<RawNode<'t>>> {
+        (0..self.len()).map(move |i| unsafe {
+            let list = self.list.unwrap_unchecked();
+            RawNode::new((*list.elements.add(i)).ptr_value as *mut bindings_raw::Node)
+        })
+    }
+}
+
+/// Returns the string at `ptr`, or `None` if it is null or not valid UTF-8.
+unsafe fn c_str<'t>(ptr: *const c_char) -> Option<&'t str> {
+    if ptr.is_null() {
+        None
+    } else {
+        CStr::from_ptr(ptr).to_str().ok()
+    }
+}
+
+/// Converts a C enum value to its protobuf enum, which has an extra UNDEFINED = 0 variant.
+fn protobuf_enum<T: TryFrom<i32> + Default>(value: i32) -> T {
+    T::try_from(value + 1).unwrap_or_default()
+}
+
+#[derive(Clone, Copy)]
+pub struct RawSelectStmt<'t>(&'t bindings_raw::SelectStmt);
+
+impl<'t> RawSelectStmt<'t> {
+    pub fn target_list(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.targetList) }
+    }
+
+    pub fn from_clause(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.fromClause) }
+    }
+
+    pub fn where_clause(&self) -> Option<RawNode<'t>> {
+        unsafe { RawNode::new(self.0.whereClause) }
+    }
+
+    pub fn group_clause(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.groupClause) }
+    }
+
+    pub fn having_clause(&self) -> Option<RawNode<'t>> {
+        unsafe { RawNode::new(self.0.havingClause) }
+    }
+
+    pub fn values_lists(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.valuesLists) }
+    }
+
+    pub fn sort_clause(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.sortClause) }
+    }
+
+    pub fn limit_offset(&self) -> Option<RawNode<'t>> {
+        unsafe { RawNode::new(self.0.limitOffset) }
+    }
+
+    pub fn limit_count(&self) -> Option<RawNode<'t>> {
+        unsafe { RawNode::new(self.0.limitCount) }
+    }
+
+    pub fn locking_clause(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.lockingClause) }
+    }
+
+    pub fn op(&self) -> protobuf::SetOperation {
+        protobuf_enum(self.0.op as i32)
+    }
+
+    pub fn larg(&self) -> Option<RawSelectStmt<'t>> {
+        unsafe { self.0.larg.as_ref() }.map(RawSelectStmt)
+    }
+
+    pub fn rarg(&self) -> Option<RawSelectStmt<'t>> {
+        unsafe { self.0.rarg.as_ref() }.map(RawSelectStmt)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct RawInsertStmt<'t>(&'t bindings_raw::InsertStmt);
+
+impl<'t> RawInsertStmt<'t> {
+    pub fn relation(&self) -> Option<RawRangeVar<'t>> {
+        unsafe { self.0.relation.as_ref() }.map(RawRangeVar)
+    }
+
+    pub fn cols(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.cols) }
+    }
+
+    pub fn select_stmt(&self) -> Option<RawNode<'t>> {
+        unsafe { RawNode::new(self.0.selectStmt) }
+    }
+
+    pub fn returning_list(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.returningList) }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct RawUpdateStmt<'t>(&'t bindings_raw::UpdateStmt);
+
+impl<'t> RawUpdateStmt<'t> {
+    pub fn relation(&self) -> Option<RawRangeVar<'t>> {
+        unsafe { self.0.relation.as_ref() }.map(RawRangeVar)
+    }
+
+    pub fn target_list(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.targetList) }
+    }
+
+    pub fn where_clause(&self) -> Option<RawNode<'t>> {
+        unsafe { RawNode::new(self.0.whereClause) }
+    }
+
+    pub fn from_clause(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.fromClause) }
+    }
+
+    pub fn returning_list(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.returningList) }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct RawDeleteStmt<'t>(&'t bindings_raw::DeleteStmt);
+
+impl<'t> RawDeleteStmt<'t> {
+    pub fn relation(&self) -> Option<RawRangeVar<'t>> {
+        unsafe { self.0.relation.as_ref() }.map(RawRangeVar)
+    }
+
+    pub fn using_clause(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.usingClause) }
+    }
+
+    pub fn where_clause(&self) -> Option<RawNode<'t>> {
+        unsafe { RawNode::new(self.0.whereClause) }
+    }
+
+    pub fn returning_list(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.returningList) }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct RawTransactionStmt<'t>(&'t bindings_raw::TransactionStmt);
+
+impl<'t> RawTransactionStmt<'t> {
+    pub fn kind(&self) -> protobuf::TransactionStmtKind {
+        protobuf_enum(self.0.kind as i32)
+    }
+
+    pub fn options(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.options) }
+    }
+
+    pub fn savepoint_name(&self) -> Option<&'t str> {
+        unsafe { c_str(self.0.savepoint_name) }
+    }
+
+    pub fn gid(&self) -> Option<&'t str> {
+        unsafe { c_str(self.0.gid) }
+    }
+
+    pub fn chain(&self) -> bool {
+        self.0.chain
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct RawVariableSetStmt<'t>(&'t bindings_raw::VariableSetStmt);
+
+impl<'t> RawVariableSetStmt<'t> {
+    pub fn kind(&self) -> protobuf::VariableSetKind {
+        protobuf_enum(self.0.kind as i32)
+    }
+
+    pub fn name(&self) -> Option<&'t str> {
+        unsafe { c_str(self.0.name) }
+    }
+
+    pub fn args(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.args) }
+    }
+
+    pub fn is_local(&self) -> bool {
+        self.0.is_local
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct RawRangeVar<'t>(&'t bindings_raw::RangeVar);
+
+impl<'t> RawRangeVar<'t> {
+    pub fn catalogname(&self) -> Option<&'t str> {
+        unsafe { c_str(self.0.catalogname) }
+    }
+
+    pub fn schemaname(&self) -> Option<&'t str> {
+        unsafe { c_str(self.0.schemaname) }
+    }
+
+    pub fn relname(&self) -> &'t str {
+        unsafe { c_str(self.0.relname) }.unwrap_or_default()
+    }
+
+    pub fn inh(&self) -> bool {
+        self.0.inh
+    }
+
+    pub fn aliasname(&self) -> Option<&'t str> {
+        unsafe { self.0.alias.as_ref().and_then(|alias| c_str(alias.aliasname)) }
+    }
+
+    pub fn location(&self) -> i32 {
+        self.0.location
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct RawRangeSubselect<'t>(&'t bindings_raw::RangeSubselect);
+
+impl<'t> RawRangeSubselect<'t> {
+    pub fn lateral(&self) -> bool {
+        self.0.lateral
+    }
+
+    pub fn subquery(&self) -> Option<RawNode<'t>> {
+        unsafe { RawNode::new(self.0.subquery) }
+    }
+
+    pub fn aliasname(&self) -> Option<&'t str> {
+        unsafe { self.0.alias.as_ref().and_then(|alias| c_str(alias.aliasname)) }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct RawJoinExpr<'t>(&'t bindings_raw::JoinExpr);
+
+impl<'t> RawJoinExpr<'t> {
+    pub fn jointype(&self) -> protobuf::JoinType {
+        protobuf_enum(self.0.jointype as i32)
+    }
+
+    pub fn larg(&self) -> Option<RawNode<'t>> {
+        unsafe { RawNode::new(self.0.larg) }
+    }
+
+    pub fn rarg(&self) -> Option<RawNode<'t>> {
+        unsafe { RawNode::new(self.0.rarg) }
+    }
+
+    pub fn quals(&self) -> Option<RawNode<'t>> {
+        unsafe { RawNode::new(self.0.quals) }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct RawResTarget<'t>(&'t bindings_raw::ResTarget);
+
+impl<'t> RawResTarget<'t> {
+    pub fn name(&self) -> Option<&'t str> {
+        unsafe { c_str(self.0.name) }
+    }
+
+    pub fn indirection(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.indirection) }
+    }
+
+    pub fn val(&self) -> Option<RawNode<'t>> {
+        unsafe { RawNode::new(self.0.val) }
+    }
+
+    pub fn location(&self) -> i32 {
+        self.0.location
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct RawColumnRef<'t>(&'t bindings_raw::ColumnRef);
+
+impl<'t> RawColumnRef<'t> {
+    /// The `String` (or, for `t.*`, `A_Star`) nodes naming the column.
+    pub fn fields(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.fields) }
+    }
+
+    pub fn location(&self) -> i32 {
+        self.0.location
+    }
+}
+
+/// The value of an [RawAConst].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RawConstValue<'t> {
+    Null,
+    Integer(i32),
+    /// Floats are kept as written, as they may not fit in a `f64`.
+    Float(&'t str),
+    Boolean(bool),
+    String(&'t str),
+    BitString(&'t str),
+}
+
+#[derive(Clone, Copy)]
+pub struct RawAConst<'t>(&'t bindings_raw::A_Const);
+
+impl<'t> RawAConst<'t> {
+    pub fn value(&self) -> RawConstValue<'t> {
+        if self.0.isnull {
+            return RawConstValue::Null;
+        }
+        unsafe {
+            let val = &self.0.val;
+            match val.node.type_ {
+                bindings_raw::NodeTag_T_Integer => RawConstValue::Integer(val.ival.ival),
+                bindings_raw::NodeTag_T_Float => RawConstValue::Float(c_str(val.fval.fval).unwrap_or_default()),
+                bindings_raw::NodeTag_T_Boolean => RawConstValue::Boolean(val.boolval.boolval),
+                bindings_raw::NodeTag_T_String => RawConstValue::String(c_str(val.sval.sval).unwrap_or_default()),
+                bindings_raw::NodeTag_T_BitString => RawConstValue::BitString(c_str(val.bsval.bsval).unwrap_or_default()),
+                _ => RawConstValue::Null,
+            }
+        }
+    }
+
+    pub fn location(&self) -> i32 {
+        self.0.location
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct RawParamRef<'t>(&'t bindings_raw::ParamRef);
+
+impl RawParamRef<'_> {
+    /// The number of the parameter, e.g. 1 for `$1`.
+    pub fn number(&self) -> i32 {
+        self.0.number
+    }
+
+    pub fn location(&self) -> i32 {
+        self.0.location
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct RawAExpr<'t>(&'t bindings_raw::A_Expr);
+
+impl<'t> RawAExpr<'t> {
+    pub fn kind(&self) -> protobuf::AExprKind {
+        protobuf_enum(self.0.kind as i32)
+    }
+
+    /// The `String` nodes naming the operator, e.g. `=` or `pg_catalog.+`.
+    pub fn name(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.name) }
+    }
+
+    pub fn lexpr(&self) -> Option<RawNode<'t>> {
+        unsafe { RawNode::new(self.0.lexpr) }
+    }
+
+    pub fn rexpr(&self) -> Option<RawNode<'t>> {
+        unsafe { RawNode::new(self.0.rexpr) }
+    }
+
+    pub fn location(&self) -> i32 {
+        self.0.location
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct RawBoolExpr<'t>(&'t bindings_raw::BoolExpr);
+
+impl<'t> RawBoolExpr<'t> {
+    pub fn boolop(&self) -> protobuf::BoolExprType {
+        protobuf_enum(self.0.boolop as i32)
+    }
+
+    pub fn args(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.args) }
+    }
+
+    pub fn location(&self) -> i32 {
+        self.0.location
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct RawFuncCall<'t>(&'t bindings_raw::FuncCall);
+
+impl<'t> RawFuncCall<'t> {
+    /// The `String` nodes naming the function, e.g. `pg_catalog.now`.
+    pub fn funcname(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.funcname) }
+    }
+
+    pub fn args(&self) -> RawList<'t> {
+        unsafe { RawList::new(self.0.args) }
+    }
+
+    pub fn location(&self) -> i32 {
+        self.0.location
+    }
+}
@@ -0,0 +1,595 @@
+//! Scope-aware name resolution, binding table and column references to the range entries they refer to.
+//!
+//! There is no catalog, so the columns of tables aren't known. Unqualified column names are bound to the
+//! range entry that is known to have them (a subquery, CTE or alias with column names, or a `USING`
+//! join), or else to the only entry in scope that could have them, and reported as ambiguous otherwise.
+
+use std::collections::HashMap;
+
+use crate::protobuf::{self, ColumnRef, RangeVar};
+use crate::*;
+
+/// A scope of names: the `FROM` items of a query, or the CTEs of a `WITH` clause.
+#[derive(Clone, Debug)]
+pub struct Scope {
+    /// The enclosing scope, which is searched when a name isn't found in this one
+    pub parent: Option<usize>,
+    /// The path of the statement or `WITH` clause introducing the scope
+    pub path: NodePath,
+    /// The range entries of the scope, in [Resolution::entries]
+    pub entries: Vec<usize>,
+}
+
+/// The kind of a [RangeEntry], with the node it was created from.
+#[derive(Clone, Copy, Debug)]
+pub enum RangeEntryKind<'a> {
+    /// A table or view in `FROM`, or the target of an `INSERT`, `UPDATE`, `DELETE` or `MERGE`, together
+    /// with the CTE it refers to, if it names one
+    Relation(&'a RangeVar, Option<usize>),
+    /// A CTE defined in a `WITH` clause, which isn't visible to column references itself
+    Cte(&'a protobuf::CommonTableExpr),
+    /// A subquery in `FROM`
+    Subquery(&'a protobuf::RangeSubselect),
+    /// A function call in `FROM`
+    Function(&'a protobuf::RangeFunction),
+    /// An `XMLTABLE` or `JSON_TABLE` in `FROM`
+    TableFunction(NodeRef<'a>),
+    /// A join with an alias, or with a `USING` clause, whose merged columns it holds
+    Join(&'a protobuf::JoinExpr),
+    /// The `excluded` row of `INSERT ... ON CONFLICT`
+    Excluded,
+}
+
+/// An item that columns can be taken from, such as a table in `FROM` or a CTE.
+#[derive(Clone, Debug)]
+pub struct RangeEntry<'a> {
+    pub kind: RangeEntryKind<'a>,
+    /// The name the entry is referred to by: its alias, or else its table, CTE or function name
+    pub name: Option<String>,
+    /// The names of the columns, if they are known without a catalog
+    pub columns: Option<Vec<String>>,
+    /// The scope the entry belongs to, in [Resolution::scopes]
+    pub scope: usize,
+    pub path: NodePath,
+    /// The aliased join hiding the entry, whose columns must be referred to through the join
+    pub hidden_by: Option<usize>,
+}
+
+/// What a [ColumnReference] refers to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ColumnBinding {
+    /// A column of the range entry, or the entry itself for `t.*` or a whole-row reference like `u` in
+    /// `SELECT u FROM users u`
+    Entry(usize),
+    /// A column of one of the range entries, which can't be told apart without a catalog
+    Ambiguous(Vec<usize>),
+    /// `*`, standing for the columns of the entries
+    Star(Vec<usize>),
+    /// An output column of the query, as its index in the target list, e.g. `x` in
+    /// `SELECT a AS x FROM t ORDER BY x`. For a set operation, it's the target list of the left-most
+    /// query, e.g. `a` in `SELECT a FROM t UNION SELECT b FROM u ORDER BY a`
+    Output(usize),
+    /// No entry in scope has the name
+    Unresolved,
+}
+
+/// A table name, i.e. a `RangeVar`, and what it refers to.
+#[derive(Clone, Debug)]
+pub struct TableReference<'a> {
+    pub node: &'a RangeVar,
+    pub path: NodePath,
+    /// The scope the name is looked up in, in [Resolution::scopes]
+    pub scope: usize,
+    /// The range entry created for it, if it's in `FROM` or the target of a DML statement
+    pub entry: Option<usize>,
+    /// The CTE it refers to, in [Resolution::entries]
+    pub cte: Option<usize>,
+}
+
+/// A column name, i.e. a `ColumnRef`, and what it refers to.
+#[derive(Clone, Debug)]
+pub struct ColumnReference<'a> {
+    pub node: &'a ColumnRef,
+    pub path: NodePath,
+    /// The scope the name is looked up in, in [Resolution::scopes]
+    pub scope: usize,
+    pub binding: ColumnBinding,
+}
+
+/// The scopes of a parse tree, and what every table and column reference in it refers to. See
+/// [ParseResult::resolve].
+#[derive(Clone, Debug, Default)]
+pub struct Resolution<'a> {
+    scopes: Vec<Scope>,
+    entries: Vec<RangeEntry<'a>>,
+    tables: Vec<TableReference<'a>>,
+    columns: Vec<ColumnReference<'a>>,
+    /// The indices of table and column references, by the address of their node
+    by_address: HashMap<usize, usize>,
+}
+
+impl<'a> Resolution<'a> {
+    /// Returns the scopes, outer scopes first.
+    pub fn scopes(&self) -> &[Scope] {
+        &self.scopes
+    }
+
+    /// Returns the range entries of all scopes.
+    pub fn entries(&self) -> &[RangeEntry<'a>] {
+        &self.entries
+    }
+
+    /// Returns every table reference, in the order they were resolved.
+    pub fn tables(&self) -> &[TableReference<'a>] {
+        &self.tables
+    }
+
+    /// Returns every column reference, in the order they were resolved.
+    pub fn columns(&self) -> &[ColumnReference<'a>] {
+        &self.columns
+    }
+
+    /// Returns what `node` refers to, if it's part of the resolved tree.
+    pub fn table(&self, node: &RangeVar) -> Option<&TableReference<'a>> {
+        let index = *self.by_address.get(&(node as *const RangeVar as usize))?;
+        self.tables.get(index).filter(|table| std::ptr::eq(table.node, node))
+    }
+
+    /// Returns what `node` refers to, if it's part of the resolved tree.
+    pub fn column(&self, node: &ColumnRef) -> Option<&ColumnReference<'a>> {
+        let index = *self.by_address.get(&(node as *const ColumnRef as usize))?;
+        self.columns.get(index).filter(|column| std::ptr::eq(column.node, node))
+    }
+
+    fn scope(&mut self, parent: Option<usize>, path: &NodePath) -> usize {
+        self.scopes.push(Scope { parent, path: path.clone(), entries: Vec::new() });
+        self.scopes.len() - 1
+    }
+
+    fn entry(&mut self, scope: usize, path: &NodePath, kind: RangeEntryKind<'a>, name: Option<String>, columns: Option<Vec<String>>) -> usize {
+        let entry = self.entries.len();
+        self.entries.push(RangeEntry { kind, name, columns, scope, path: path.clone(), hidden_by: None });
+        self.scopes[scope].entries.push(entry);
+        entry
+    }
+
+    /// Returns the entries of `scope` that columns can be taken from.
+    fn visible(&self, scope: usize) -> impl Iterator<Item = usize> + '_ {
+        self.scopes[scope]
+            .entries
+            .iter()
+            .copied()
+            .filter(|&entry| self.entries[entry].hidden_by.is_none() && !matches!(self.entries[entry].kind, RangeEntryKind::Cte(_)))
+    }
+
+    /// Returns `scope` and its enclosing scopes, innermost first.
+    fn chain(&self, scope: usize) -> impl Iterator<Item = usize> + '_ {
+        std::iter::successors(Some(scope), |&scope| self.scopes[scope].parent)
+    }
+
+    fn walk(&mut self, path: &NodePath, node: NodeRef<'a>, scope: usize) {
+        match node {
+            NodeRef::SelectStmt(select) => self.select(path, select, scope),
+            NodeRef::InsertStmt(insert) => {
+                let scope = self.with_clause(path, insert.with_clause.as_ref(), scope);
+                if let Some(select) = insert.select_stmt.as_ref().and_then(|select| select.node.as_ref()) {
+                    self.walk(&path.join("select_stmt", None), select.to_ref(), scope);
+                }
+                let target = self.scope(Some(scope), path);
+                if let Some(relation) = &insert.relation {
+                    self.relation(&path.join("relation", None), relation, target, true);
+                }
+                if insert.on_conflict_clause.is_some() {
+                    self.entry(target, &path.join("on_conflict_clause", None), RangeEntryKind::Excluded, Some("excluded".into()), None);
+                }
+                self.walk_children(path, node, target, &["with_clause", "select_stmt", "relation"]);
+            }
+            NodeRef::UpdateStmt(update) => {
+                let scope = self.with_clause(path, update.with_clause.as_ref(), scope);
+                let target = self.scope(Some(scope), path);
+                if let Some(relation) = &update.relation {
+                    self.relation(&path.join("relation", None), relation, target, true);
+                }
+                self.from_items(path, "from_clause", &update.from_clause, target, scope);
+                self.walk_children(path, node, target, &["with_clause", "relation", "from_clause"]);
+            }
+            NodeRef::DeleteStmt(delete) => {
+                let scope = self.with_clause(path, delete.with_clause.as_ref(), scope);
+                let target = self.scope(Some(scope), path);
+                if let Some(relation) = &delete.relation {
+                    self.relation(&path.join("relation", None), relation, target, true);
+                }
+                self.from_items(path, "using_clause", &delete.using_clause, target, scope);
+                self.walk_children(path, node, target, &["with_clause", "relation", "using_clause"]);
+            }
+            NodeRef::MergeStmt(merge) => {
+                let scope = self.with_clause(path, merge.with_clause.as_ref(), scope);
+                let target = self.scope(Some(scope), path);
+                if let Some(relation) = &merge.relation {
+                    self.relation(&path.join("relation", None), relation, target, true);
+                }
+                if let Some(source) = merge.source_relation.as_ref().and_then(|source| source.node.as_ref()) {
+                    self.from_item(&path.join("source_relation", None), source.to_ref(), target, scope);
+                }
+                self.walk_children(path, node, target, &["with_clause", "relation", "source_relation"]);
+            }
+            NodeRef::RangeVar(relation) => self.relation(path, relation, scope, false),
+            NodeRef::ColumnRef(column) => self.column_ref(path, column, scope),
+            _ => self.walk_children(path, node, scope, &[]),
+        }
+    }
+
+    /// Walks the children of `node` in `scope`, apart from those in `skip`.
+    fn walk_children(&mut self, path: &NodePath, node: NodeRef<'a>, scope: usize, skip: &[&str]) {
+        let mut nodes = Vec::new();
+        children(node, &mut |field, index, child| {
+            if !skip.contains(&field) {
+                nodes.push((path.join(field, index), child));
+            }
+        });
+        for (path, child) in nodes {
+            self.walk(&path, child, scope);
+        }
+    }
+
+    /// Adds the CTEs of `with` to a new scope within `scope`, and returns it.
+    fn with_clause(&mut self, path: &NodePath, with: Option<&'a protobuf::WithClause>, scope: usize) -> usize {
+        let Some(with) = with else {
+            return scope;
+        };
+        let path = path.join("with_clause", None);
+        let scope = self.scope(Some(scope), &path);
+        for (i, cte) in with.ctes.iter().enumerate() {
+            let Some(NodeEnum::CommonTableExpr(cte)) = &cte.node else {
+                continue;
+            };
+            let path = path.join("ctes", Some(i));
+            let columns = if cte.aliascolnames.is_empty() { output_columns(cte.ctequery.as_deref()) } else { Some(strings(&cte.aliascolnames)) };
+            // A CTE is only visible to the CTEs after it, unless the clause is recursive
+            let add = |resolution: &mut Self| resolution.entry(scope, &path, RangeEntryKind::Cte(cte), Some(cte.ctename.clone()), columns.clone());
+            if with.recursive {
+                add(self);
+            }
+            self.walk_children(&path, NodeRef::CommonTableExpr(cte), scope, &[]);
+            if !with.recursive {
+                add(self);
+            }
+        }
+        scope
+    }
+
+    fn select(&mut self, path: &NodePath, select: &'a protobuf::SelectStmt, scope: usize) {
+        let scope = self.with_clause(path, select.with_clause.as_ref(), scope);
+        if select.larg.is_some() || select.rarg.is_some() {
+            // Each side of a set operation is a query of its own, and ORDER BY can only name the output
+            // columns, so it's resolved in a scope of its own
+            let names = output_names(select);
+            let output = self.scope(None, path);
+            self.sort_clause(path, select, output, |column| output_column(&names, column));
+            self.walk_children(path, NodeRef::SelectStmt(select), scope, &["with_clause", "sort_clause"]);
+            return;
+        }
+        let from = self.scope(Some(scope), path);
+        self.from_items(path, "from_clause", &select.from_clause, from, scope);
+        let aliases: Vec<_> = select
+            .target_list
+            .iter()
+            .map(|target| match &target.node {
+                Some(NodeEnum::ResTarget(target)) if !target.name.is_empty() => Some(target.name.clone()),
+                _ => None,
+            })
+            .collect();
+        self.sort_clause(path, select, from, |column| output_column(&aliases, column));
+        self.walk_children(path, NodeRef::SelectStmt(select), from, &["with_clause", "from_clause", "sort_clause"]);
+    }
+
+    /// Resolves the `ORDER BY` of `select` in `scope`, binding the names `output_of` finds to output columns.
+    fn sort_clause(&mut self, path: &NodePath, select: &'a protobuf::SelectStmt, scope: usize, output_of: impl Fn(&ColumnRef) -> Option<usize>) {
+        for (i, sort) in select.sort_clause.iter().enumerate() {
+            let path = path.join("sort_clause", Some(i));
+            let output = match &sort.node {
+                Some(NodeEnum::SortBy(sort)) => match sort.node.as_ref().and_then(|node| node.node.as_ref()) {
+                    Some(NodeEnum::ColumnRef(column)) => output_of(column).map(|output| (column, output)),
+                    _ => None,
+                },
+                _ => None,
+            };
+            match output {
+                Some((column, output)) => self.add_column(path.join("node", None), column, scope, ColumnBinding::Output(output)),
+                None => {
+                    if let Some(sort) = &sort.node {
+                        self.walk(&path, sort.to_ref(), scope);
+                    }
+                }
+            }
+        }
+    }
+
+    fn from_items(&mut self, path: &NodePath, field: &'static str, items: &'a [protobuf::Node], from: usize, outer: usize) {
+        for (i, item) in items.iter().enumerate() {
+            if let Some(item) = &item.node {
+                self.from_item(&path.join(field, Some(i)), item.to_ref(), from, outer);
+            }
+        }
+    }
+
+    /// Adds the entries of a `FROM` item to `from`. Subqueries that aren't `LATERAL` are resolved in
+    /// `outer`, as they can't see the other items.
+    fn from_item(&mut self, path: &NodePath, node: NodeRef<'a>, from: usize, outer: usize) {
+        match node {
+            NodeRef::RangeVar(relation) => self.relation(path, relation, from, true),
+            NodeRef::RangeSubselect(subselect) => {
+                if let Some(subquery) = subselect.subquery.as_ref().and_then(|subquery| subquery.node.as_ref()) {
+                    self.walk(&path.join("subquery", None), subquery.to_ref(), if subselect.lateral { from } else { outer });
+                }
+                let columns = aliased_columns(subselect.alias.as_ref()).or_else(|| output_columns(subselect.subquery.as_deref()));
+                self.entry(from, path, RangeEntryKind::Subquery(subselect), subselect.alias.as_ref().map(|alias| alias.aliasname.clone()), columns);
+            }
+            NodeRef::RangeFunction(function) => {
+                // Functions can always refer to the items before them
+                self.walk_children(path, node, from, &["alias"]);
+                let name = function.alias.as_ref().map(|alias| alias.aliasname.clone()).or_else(|| function_name(function));
+                let columns = aliased_columns(function.alias.as_ref()).or_else(|| {
+                    let columns: Vec<_> = function
+                        .coldeflist
+                        .iter()
+                        .filter_map(|column| match &column.node {
+                            Some(NodeEnum::ColumnDef(column)) => Some(column.colname.clone()),
+                            _ => None,
+                        })
+                        .collect();
+                    (!columns.is_empty()).then_some(columns)
+                });
+                self.entry(from, path, RangeEntryKind::Function(function), name, columns);
+            }
+            NodeRef::RangeTableFunc(function) => {
+                self.walk_children(path, node, from, &["alias"]);
+                self.entry(from, path, RangeEntryKind::TableFunction(node), function.alias.as_ref().map(|alias| alias.aliasname.clone()), None);
+            }
+            NodeRef::JsonTable(function) => {
+                self.walk_children(path, node, from, &["alias"]);
+                self.entry(from, path, RangeEntryKind::TableFunction(node), function.alias.as_ref().map(|alias| alias.aliasname.clone()), None);
+            }
+            NodeRef::RangeTableSample(sample) => {
+                if let Some(relation) = sample.relation.as_ref().and_then(|relation| relation.node.as_ref()) {
+                    self.from_item(&path.join("relation", None), relation.to_ref(), from, outer);
+                }
+                self.walk_children(path, node, from, &["relation"]);
+            }
+            NodeRef::JoinExpr(join) => {
+                let first = self.entries.len();
+                for (field, side) in [("larg", &join.larg), ("rarg", &join.rarg)] {
+                    if let Some(side) = side.as_ref().and_then(|side| side.node.as_ref()) {
+                        self.from_item(&path.join(field, None), side.to_ref(), from, outer);
+                    }
+                }
+                self.walk_children(path, node, from, &["larg", "rarg", "alias", "join_using_alias"]);
+                if join.alias.is_none() && join.using_clause.is_empty() {
+                    return;
+                }
+                let name = join.alias.as_ref().or(join.join_using_alias.as_ref()).map(|alias| alias.aliasname.clone());
+                let columns = match &join.alias {
+                    Some(alias) => aliased_columns(Some(alias)),
+                    None => Some(strings(&join.using_clause)),
+                };
+                let entry = self.entry(from, path, RangeEntryKind::Join(join), name, columns);
+                if join.alias.is_some() {
+                    for hidden in &mut self.entries[first..entry] {
+                        if hidden.scope == from && hidden.hidden_by.is_none() {
+                            hidden.hidden_by = Some(entry);
+                        }
+                    }
+                }
+            }
+            _ => self.walk(path, node, from),
+        }
+    }
+
+    /// Resolves a table name, adding an entry for it to `scope` if it's a range entry.
+    fn relation(&mut self, path: &NodePath, relation: &'a RangeVar, scope: usize, range_entry: bool) {
+        let cte = if relation.schemaname.is_empty() && relation.catalogname.is_empty() {
+            self.chain(scope).find_map(|scope| {
+                self.scopes[scope].entries.iter().copied().find(|&entry| {
+                    matches!(self.entries[entry].kind, RangeEntryKind::Cte(_)) && self.entries[entry].name.as_deref() == Some(relation.relname.as_str())
+                })
+            })
+        } else {
+            None
+        };
+        let entry = range_entry.then(|| {
+            let name = relation.alias.as_ref().map_or(&relation.relname, |alias| &alias.aliasname).clone();
+            let columns = aliased_columns(relation.alias.as_ref()).or_else(|| cte.and_then(|cte| self.entries[cte].columns.clone()));
+            self.entry(scope, path, RangeEntryKind::Relation(relation, cte), Some(name), columns)
+        });
+        self.by_address.insert(relation as *const RangeVar as usize, self.tables.len());
+        self.tables.push(TableReference { node: relation, path: path.clone(), scope, entry, cte });
+    }
+
+    fn column_ref(&mut self, path: &NodePath, column: &'a ColumnRef, scope: usize) {
+        let names = strings(&column.fields);
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        let star = matches!(column.fields.last().and_then(|field| field.node.as_ref()), Some(NodeEnum::AStar(_)));
+        let binding = match (&names[..], star) {
+            ([], true) => ColumnBinding::Star(self.visible(scope).collect()),
+            ([name], false) => self.lookup_column(scope, name),
+            ([table], true) | ([table, _], false) => self.lookup_entry(scope, None, table).map_or(ColumnBinding::Unresolved, ColumnBinding::Entry),
+            ([schema, table], true) | ([schema, table, _], false) => {
+                self.lookup_entry(scope, Some(*schema), table).map_or(ColumnBinding::Unresolved, ColumnBinding::Entry)
+            }
+            _ => ColumnBinding::Unresolved,
+        };
+        self.add_column(path.clone(), column, scope, binding);
+    }
+
+    fn add_column(&mut self, path: NodePath, column: &'a ColumnRef, scope: usize, binding: ColumnBinding) {
+        self.by_address.insert(column as *const ColumnRef as usize, self.columns.len());
+        self.columns.push(ColumnReference { node: column, path, scope, binding });
+    }
+
+    /// Finds the entry named `table`, in `schema` if given, in `scope` or its enclosing scopes.
+    fn lookup_entry(&self, scope: usize, schema: Option<&str>, table: &str) -> Option<usize> {
+        self.chain(scope).find_map(|scope| {
+            self.visible(scope).find(|&entry| match schema {
+                Some(schema) => matches!(self.entries[entry].kind, RangeEntryKind::Relation(relation, _)
+                    if relation.alias.is_none() && relation.schemaname == schema && relation.relname == table),
+                None => self.entries[entry].name.as_deref() == Some(table),
+            })
+        })
+    }
+
+    /// Binds an unqualified column name to the entries of the innermost scope that has or may have it.
+    fn lookup_column(&self, scope: usize, name: &str) -> ColumnBinding {
+        for scope in self.chain(scope) {
+            // The columns of `excluded` can only be referred to through its name
+            let entries: Vec<_> = self.visible(scope).filter(|&entry| !matches!(self.entries[entry].kind, RangeEntryKind::Excluded)).collect();
+            let has_column = |entry: usize| self.entries[entry].columns.as_ref().is_some_and(|columns| columns.iter().any(|column| column == name));
+            let known: Vec<_> = entries.iter().copied().filter(|&entry| has_column(entry)).collect();
+            // A name that isn't a known column but names an entry refers to its whole row, like `u` in
+            // `SELECT row_to_json(u) FROM users u`
+            let named = entries.iter().copied().find(|&entry| self.entries[entry].name.as_deref() == Some(name));
+            if let Some(entry) = named.filter(|_| known.is_empty()) {
+                return ColumnBinding::Entry(entry);
+            }
+            let candidates = if known.is_empty() { entries.into_iter().filter(|&entry| self.entries[entry].columns.is_none()).collect() } else { known };
+            match candidates.len() {
+                0 => continue,
+                1 => return ColumnBinding::Entry(candidates[0]),
+                _ => return ColumnBinding::Ambiguous(candidates),
+            }
+        }
+        ColumnBinding::Unresolved
+    }
+}
+
+impl protobuf::ParseResult {
+    /// Builds the scopes of the statements and resolves every table and column reference in them. See
+    /// [ParseResult::resolve].
+    pub fn resolve(&self) -> Resolution<'_> {
+        let mut resolution = Resolution::default();
+        for (i, stmt) in self.stmts.iter().enumerate() {
+            if let Some(node) = stmt.stmt.as_ref().and_then(|stmt| stmt.node.as_ref()) {
+                let path = NodePath::default().join("stmts", Some(i));
+                let scope = resolution.scope(None, &path);
+                resolution.walk(&path, node.to_ref(), scope);
+            }
+        }
+        resolution
+    }
+}
+
+impl ParseResult {
+    /// Builds the scopes of the statements (`FROM` items, subqueries, CTEs, `LATERAL` and joins) and
+    /// resolves every table and column reference in them to the range entry it refers to.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pg_query::{ColumnBinding, RangeEntryKind};
+    ///
+    /// let result = pg_query::parse("WITH f AS (SELECT * FROM f LIMIT 1) SELECT f.id, name FROM f JOIN users u ON u.id = f.id").unwrap();
+    /// let resolution = result.resolve();
+    ///
+    /// // The `f` in the CTE is a table, and the one in the query is the CTE
+    /// let ctes: Vec<_> = resolution.tables().iter().map(|table| (table.node.relname.as_str(), table.cte.is_some())).collect();
+    /// assert_eq!(ctes, [("f", false), ("f", true), ("users", false)]);
+    ///
+    /// // `f.id` in the target list refers to the CTE, while `name` may be a column of either table
+    /// let columns = resolution.columns();
+    /// let ColumnBinding::Entry(entry) = &columns[3].binding else { panic!() };
+    /// assert!(matches!(resolution.entries()[*entry].kind, RangeEntryKind::Relation(_, Some(_))));
+    /// assert!(matches!(&columns[4].binding, ColumnBinding::Ambiguous(entries) if entries.len() == 2));
+    /// ```
+    pub fn resolve(&self) -> Resolution<'_> {
+        self.protobuf.resolve()
+    }
+}
+
+/// Returns the `String` values of `nodes`.
+pub(crate) fn strings(nodes: &[protobuf::Node]) -> Vec<String> {
+    nodes
+        .iter()
+        .filter_map(|node| match &node.node {
+            Some(NodeEnum::String(string)) => Some(string.sval.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn aliased_columns(alias: Option<&protobuf::Alias>) -> Option<Vec<String>> {
+    alias.filter(|alias| !alias.colnames.is_empty()).map(|alias| strings(&alias.colnames))
+}
+
+/// Returns the index of the output column named like `column`, if it's an unqualified name in `names`.
+fn output_column(names: &[Option<String>], column: &ColumnRef) -> Option<usize> {
+    let fields = strings(&column.fields);
+    let [name] = &fields[..] else {
+        return None;
+    };
+    if column.fields.len() != 1 {
+        return None;
+    }
+    names.iter().position(|output| output.as_ref() == Some(name))
+}
+
+/// Returns the names of the output columns of a query, or `None` if they depend on a catalog (e.g. with
+/// `SELECT *`).
+fn output_columns(query: Option<&protobuf::Node>) -> Option<Vec<String>> {
+    let names = match query?.node.as_ref()? {
+        NodeEnum::SelectStmt(select) => output_names(select),
+        NodeEnum::InsertStmt(insert) => target_names(&insert.returning_list),
+        NodeEnum::UpdateStmt(update) => target_names(&update.returning_list),
+        NodeEnum::DeleteStmt(delete) => target_names(&delete.returning_list),
+        NodeEnum::MergeStmt(merge) => target_names(&merge.returning_list),
+        _ => return None,
+    };
+    names.into_iter().collect()
+}
+
+/// Returns the names of the output columns of a `SELECT`, which are those of its left-most query for a
+/// set operation.
+fn output_names(select: &protobuf::SelectStmt) -> Vec<Option<String>> {
+    let mut select = select;
+    while let Some(larg) = &select.larg {
+        select = &**larg;
+    }
+    if let Some(NodeEnum::List(row)) = select.values_lists.first().and_then(|row| row.node.as_ref()) {
+        return (1..=row.items.len()).map(|i| Some(format!("column{i}"))).collect();
+    }
+    target_names(&select.target_list)
+}
+
+/// Returns the names of the columns of a target list, `None` for `*`.
+fn target_names(targets: &[protobuf::Node]) -> Vec<Option<String>> {
+    targets
+        .iter()
+        .map(|target| match &target.node {
+            Some(NodeEnum::ResTarget(target)) if !target.name.is_empty() => Some(target.name.clone()),
+            Some(NodeEnum::ResTarget(target)) => target.val.as_ref().and_then(|val| val.node.as_ref()).and_then(column_name),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the name Postgres gives to an output column without an alias, or `None` for `*`.
+pub(crate) fn column_name(node: &NodeEnum) -> Option<String> {
+    let name = match node {
+        NodeEnum::ColumnRef(column) => match column.fields.last()?.node.as_ref()? {
+            NodeEnum::String(name) => name.sval.clone(),
+            _ => return None,
+        },
+        NodeEnum::FuncCall(call) => strings(&call.funcname).pop()?,
+        NodeEnum::TypeCast(cast) => return cast.arg.as_ref().and_then(|arg| arg.node.as_ref()).map_or(Some("?column?".into()), column_name),
+        _ => "?column?".into(),
+    };
+    Some(name)
+}
+
+fn function_name(function: &protobuf::RangeFunction) -> Option<String> {
+    let Some(NodeEnum::List(list)) = function.functions.first()?.node.as_ref() else {
+        return None;
+    };
+    match list.items.first()?.node.as_ref()? {
+        NodeEnum::FuncCall(call) => strings(&call.funcname).pop(),
+        _ => None,
+    }
+}
@@ -0,0 +1,304 @@
+//! The roles table and column references play in a statement, finer grained than [Context].
+
+use crate::protobuf::{self, ColumnRef, RangeVar};
+use crate::*;
+
+/// What a statement does with a table it references.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TableRole {
+    /// Read by a `SELECT` that isn't part of a data-modifying statement
+    Select,
+    /// Read by an `INSERT`, `UPDATE`, `DELETE` or `MERGE`, e.g. in `INSERT ... SELECT`, `UPDATE ... FROM`,
+    /// `DELETE ... USING` or a subquery in their `WHERE` clause
+    ReadInDml,
+    /// The table an `INSERT` writes to
+    InsertTarget,
+    /// The table an `UPDATE` writes to
+    UpdateTarget,
+    /// The table a `DELETE` deletes from
+    DeleteTarget,
+    /// The table a `MERGE` writes to
+    MergeTarget,
+    /// A table read by the `USING` clause of a `MERGE`
+    MergeSource,
+    /// Locked by `SELECT ... FOR UPDATE` (or `FOR SHARE`, ...) or `LOCK TABLE`
+    Lock,
+    /// Emptied by `TRUNCATE`
+    Truncate,
+    /// Referenced by any other statement, such as `CREATE`, `ALTER`, `COPY` or `SELECT ... INTO`
+    Ddl,
+}
+
+/// Where a column reference appears in a statement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ColumnRole {
+    /// The target list of a `SELECT`
+    Output,
+    /// A `WHERE` or `HAVING` clause, or the condition of a `MERGE ... WHEN` or `ON CONFLICT ... DO UPDATE`
+    Filter,
+    /// The `ON` condition of a join or a `MERGE`
+    JoinCondition,
+    /// `GROUP BY`
+    GroupBy,
+    /// `ORDER BY`
+    OrderBy,
+    /// `RETURNING`
+    Returning,
+    /// A value assigned by `UPDATE ... SET`, `ON CONFLICT ... DO UPDATE SET` or `MERGE ... THEN`
+    Assignment,
+    /// Anywhere else
+    Other,
+}
+
+/// A table reference with its role. A table has several roles if it's referenced several times, or
+/// locked by `FOR UPDATE` as well as read.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableUse {
+    /// The name of the table, with its schema if given
+    pub name: String,
+    pub role: TableRole,
+    /// The path of the `RangeVar`
+    pub path: NodePath,
+}
+
+/// A column reference with its role.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnUse {
+    /// The table, alias or other range entry the column is qualified with, as written
+    pub qualifier: Option<String>,
+    /// The table the column belongs to, if [ParseResult::resolve] binds it to one
+    pub table: Option<String>,
+    pub column: String,
+    pub role: ColumnRole,
+    /// The path of the `ColumnRef`
+    pub path: NodePath,
+}
+
+impl protobuf::ParseResult {
+    /// Returns the tables referenced by the statements, with their roles. See [ParseResult::table_uses].
+    pub fn table_uses(&self) -> Vec<TableUse> {
+        self.uses().0
+    }
+
+    /// Returns the columns referenced by the statements, with their roles. See [ParseResult::column_uses].
+    pub fn column_uses(&self) -> Vec<ColumnUse> {
+        self.uses().1
+    }
+
+    fn uses(&self) -> (Vec<TableUse>, Vec<ColumnUse>) {
+        let resolution = self.resolve();
+        let mut walker = Walker { resolution: &resolution, tables: Vec::new(), columns: Vec::new() };
+        for (i, stmt) in self.stmts.iter().enumerate() {
+            if let Some(node) = stmt.stmt.as_ref().and_then(|stmt| stmt.node.as_ref()) {
+                let at = Position { tables: TableRole::Ddl, columns: ColumnRole::Other };
+                walker.walk(node.to_ref(), &NodePath::default().join("stmts", Some(i)), at);
+            }
+        }
+        (walker.tables, walker.columns)
+    }
+}
+
+impl ParseResult {
+    /// Returns the tables referenced by the statements, in the order they appear, with the role each
+    /// reference plays. References to CTEs aren't included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pg_query::TableRole;
+    ///
+    /// let result = pg_query::parse("UPDATE users u SET name = o.name FROM orders o WHERE u.id = o.user_id").unwrap();
+    /// let roles: Vec<_> = result.table_uses().into_iter().map(|table| (table.name, table.role)).collect();
+    /// assert_eq!(roles, [("users".to_string(), TableRole::UpdateTarget), ("orders".to_string(), TableRole::ReadInDml)]);
+    /// ```
+    pub fn table_uses(&self) -> Vec<TableUse> {
+        self.protobuf.table_uses()
+    }
+
+    /// Returns the columns referenced by the statements, in the order they appear, with the role each
+    /// reference plays. `*` isn't included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pg_query::ColumnRole;
+    ///
+    /// let result = pg_query::parse("DELETE FROM users u WHERE u.id = 1 RETURNING name").unwrap();
+    /// let roles: Vec<_> = result.column_uses().into_iter().map(|column| (column.table, column.column, column.role)).collect();
+    /// assert_eq!(
+    ///     roles,
+    ///     [(Some("users".to_string()), "id".to_string(), ColumnRole::Filter), (Some("users".to_string()), "name".to_string(), ColumnRole::Returning)]
+    /// );
+    /// ```
+    pub fn column_uses(&self) -> Vec<ColumnUse> {
+        self.protobuf.column_uses()
+    }
+}
+
+/// The roles given to the references within a node.
+#[derive(Clone, Copy)]
+struct Position {
+    tables: TableRole,
+    columns: ColumnRole,
+}
+
+struct Walker<'a, 'r> {
+    resolution: &'r Resolution<'a>,
+    tables: Vec<TableUse>,
+    columns: Vec<ColumnUse>,
+}
+
+impl<'a> Walker<'a, '_> {
+    fn walk(&mut self, node: NodeRef<'a>, path: &NodePath, at: Position) {
+        match node {
+            NodeRef::RangeVar(relation) => self.table(relation, path, at.tables),
+            NodeRef::ColumnRef(column) => self.column(column, path, at.columns),
+            _ => {}
+        }
+        let mut nodes = Vec::new();
+        children(node, &mut |field, index, child| nodes.push((field, index, child)));
+        for (field, index, child) in nodes {
+            if let Some(position) = position(node, field, at) {
+                self.walk(child, &path.join(field, index), position);
+            }
+        }
+        if let NodeRef::SelectStmt(select) = node {
+            self.locks(select, path);
+        }
+    }
+
+    fn table(&mut self, relation: &RangeVar, path: &NodePath, role: TableRole) {
+        if self.resolution.table(relation).is_some_and(|table| table.cte.is_some()) {
+            return;
+        }
+        self.tables.push(TableUse { name: relation_name(relation), role, path: path.clone() });
+    }
+
+    fn column(&mut self, column: &ColumnRef, path: &NodePath, role: ColumnRole) {
+        let mut names: Vec<_> = column
+            .fields
+            .iter()
+            .map(|field| match &field.node {
+                Some(NodeEnum::String(string)) => Some(string.sval.clone()),
+                _ => None,
+            })
+            .collect();
+        let Some(Some(name)) = names.pop() else {
+            return;
+        };
+        let table = match self.resolution.column(column).map(|column| &column.binding) {
+            Some(ColumnBinding::Entry(entry)) => match self.resolution.entries()[*entry].kind {
+                RangeEntryKind::Relation(relation, None) => Some(relation_name(relation)),
+                _ => None,
+            },
+            _ => None,
+        };
+        self.columns.push(ColumnUse { qualifier: names.pop().flatten(), table, column: name, role, path: path.clone() });
+    }
+
+    /// Adds the tables in the `FROM` clause of `select` that its `FOR UPDATE`, `FOR SHARE`, ... clauses lock.
+    fn locks(&mut self, select: &protobuf::SelectStmt, path: &NodePath) {
+        if select.locking_clause.is_empty() {
+            return;
+        }
+        let mut relations = Vec::new();
+        for (i, item) in select.from_clause.iter().enumerate() {
+            if let Some(node) = &item.node {
+                from_relations(node.to_ref(), path.join("from_clause", Some(i)), &mut relations);
+            }
+        }
+        let mut locked: Vec<(&RangeVar, NodePath)> = Vec::new();
+        for clause in &select.locking_clause {
+            let Some(NodeEnum::LockingClause(clause)) = &clause.node else {
+                continue;
+            };
+            let names: Vec<&str> = clause
+                .locked_rels
+                .iter()
+                .filter_map(|node| match &node.node {
+                    Some(NodeEnum::RangeVar(relation)) => Some(relation.relname.as_str()),
+                    _ => None,
+                })
+                .collect();
+            for (relation, path) in &relations {
+                let name = relation.alias.as_ref().map_or(relation.relname.as_str(), |alias| alias.aliasname.as_str());
+                if (names.is_empty() || names.contains(&name)) && !locked.iter().any(|(other, _)| std::ptr::eq(*other, *relation)) {
+                    locked.push((*relation, path.clone()));
+                }
+            }
+        }
+        for (relation, path) in locked {
+            self.table(relation, &path, TableRole::Lock);
+        }
+    }
+}
+
+/// Returns the roles of the references within `field` of `node`, or `None` if they aren't walked.
+fn position(node: NodeRef, field: &str, at: Position) -> Option<Position> {
+    use ColumnRole::*;
+    let read = |columns| Position { tables: TableRole::ReadInDml, columns };
+    let target = |tables| Position { tables, columns: Other };
+    Some(match (node, field) {
+        // The locked tables are names of FROM items, which are walked already
+        (NodeRef::SelectStmt(_), "locking_clause") => return None,
+        (NodeRef::SelectStmt(_), "into_clause") => target(TableRole::Ddl),
+        (NodeRef::SelectStmt(_), _) => {
+            let tables = match at.tables {
+                TableRole::ReadInDml | TableRole::MergeSource => at.tables,
+                _ => TableRole::Select,
+            };
+            // The output of a subquery in a clause is used by that clause
+            let columns = match field {
+                "target_list" if at.columns == Other => Output,
+                "where_clause" | "having_clause" => Filter,
+                "group_clause" => GroupBy,
+                "sort_clause" => OrderBy,
+                "from_clause" | "with_clause" => Other,
+                _ => at.columns,
+            };
+            Position { tables, columns }
+        }
+        (NodeRef::InsertStmt(_), "relation") => target(TableRole::InsertTarget),
+        (NodeRef::UpdateStmt(_), "relation") => target(TableRole::UpdateTarget),
+        (NodeRef::DeleteStmt(_), "relation") => target(TableRole::DeleteTarget),
+        (NodeRef::MergeStmt(_), "relation") => target(TableRole::MergeTarget),
+        (NodeRef::MergeStmt(_), "source_relation") => target(TableRole::MergeSource),
+        (NodeRef::InsertStmt(_) | NodeRef::UpdateStmt(_) | NodeRef::DeleteStmt(_) | NodeRef::MergeStmt(_), _) => read(match field {
+            "where_clause" => Filter,
+            "target_list" => Assignment,
+            "join_condition" => JoinCondition,
+            "returning_list" => Returning,
+            _ => Other,
+        }),
+        (NodeRef::OnConflictClause(_), "where_clause") | (NodeRef::MergeWhenClause(_), "condition") => Position { columns: Filter, ..at },
+        (NodeRef::OnConflictClause(_), "target_list") | (NodeRef::MergeWhenClause(_), _) => Position { columns: Assignment, ..at },
+        (NodeRef::JoinExpr(_), "quals") => Position { columns: JoinCondition, ..at },
+        (NodeRef::TruncateStmt(_), "relations") => target(TableRole::Truncate),
+        (NodeRef::LockStmt(_), "relations") => target(TableRole::Lock),
+        _ => at,
+    })
+}
+
+/// Adds the tables in the `FROM` item `node`, including those in joins but not in subqueries.
+fn from_relations<'a>(node: NodeRef<'a>, path: NodePath, relations: &mut Vec<(&'a RangeVar, NodePath)>) {
+    match node {
+        NodeRef::RangeVar(relation) => relations.push((relation, path)),
+        NodeRef::JoinExpr(join) => {
+            for (field, arg) in [("larg", &join.larg), ("rarg", &join.rarg)] {
+                if let Some(node) = arg.as_ref().and_then(|arg| arg.node.as_ref()) {
+                    from_relations(node.to_ref(), path.join(field, None), relations);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns the name of the table, with its schema if given.
+pub(crate) fn relation_name(relation: &RangeVar) -> String {
+    if relation.schemaname.is_empty() {
+        relation.relname.clone()
+    } else {
+        format!("{}.{}", relation.schemaname, relation.relname)
+    }
+}
@@ -0,0 +1,181 @@
+//! Fault-tolerant parsing of scripts containing many statements.
+//!
+//! [parse] fails as soon as any statement in its input is invalid. [parse_script] instead splits the
+//! script into statements using the scanner, and parses each one on its own, so that every broken
+//! statement of a migration file can be reported at once.
+
+use std::ffi::CString;
+use std::ops::Range;
+
+use prost::Message;
+
+use crate::bindings::*;
+use crate::error::line_column;
+use crate::protobuf::{self, Token};
+use crate::{parse, Error, ParseError, ParseResult, Result};
+
+/// The outcome of parsing a single statement of a script, returned by [parse_script].
+#[derive(Debug)]
+pub struct StatementResult<'a> {
+    /// The text of the statement, without its terminating semicolon
+    pub sql: &'a str,
+    /// Byte range of the statement within the script
+    pub span: Range<usize>,
+    /// The parsed statement, or the reason it is invalid.
+    ///
+    /// Locations inside the parse tree are relative to `sql`, whereas the cursor, line and column of a
+    /// [ParseError] are relative to the whole script.
+    pub result: Result<ParseResult>,
+}
+
+impl StatementResult<'_> {
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    /// Returns the parsed statement, or `None` if it is invalid.
+    pub fn stmt(&self) -> Option<&protobuf::RawStmt> {
+        self.result.as_ref().ok().and_then(|result| result.protobuf.stmts.first())
+    }
+
+    /// Returns the syntax error of an invalid statement.
+    pub fn error(&self) -> Option<&ParseError> {
+        match &self.result {
+            Err(Error::Syntax(error)) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Parses every statement of a script independently, so that one invalid statement does not prevent
+/// the others from being parsed.
+///
+/// Statements are split on the semicolons found by the scanner, taking `BEGIN ATOMIC ... END` bodies
+/// of `CREATE FUNCTION` and `CREATE PROCEDURE` into account. Text the scanner rejects is kept in the
+/// statement it appears in, and scanning resumes after it.
+///
+/// # Example
+///
+/// ```rust
+/// let script = "SELECT * FROM contacts; garbage; SELECT count(*) FROM orders";
+/// let statements = pg_query::parse_script(script);
+/// assert_eq!(statements.len(), 3);
+///
+/// assert_eq!(statements[0].result.as_ref().unwrap().tables(), vec!["contacts"]);
+///
+/// let error = statements[1].error().unwrap();
+/// assert_eq!(statements[1].sql, "garbage");
+/// assert_eq!(error.message, "syntax error at or near \"garbage\"");
+/// assert_eq!(error.cursor, Some(24));
+///
+/// assert_eq!(statements[2].span, 33..60);
+/// assert_eq!(statements[2].result.as_ref().unwrap().functions(), vec!["count"]);
+/// ```
+pub fn parse_script(sql: &str) -> Vec<StatementResult<'_>> {
+    statement_spans(&tokens(sql))
+        .into_iter()
+        .map(|span| {
+            let statement = &sql[span.clone()];
+            let result = parse(statement).map_err(|error| match error {
+                Error::Syntax(error) => Error::Syntax(Box::new(offset_error(*error, sql, span.start))),
+                error => error,
+            });
+            StatementResult { sql: statement, span, result }
+        })
+        .collect()
+}
+
+/// Makes the position of an error found in the statement starting at `offset` relative to `script`.
+fn offset_error(mut error: ParseError, script: &str, offset: usize) -> ParseError {
+    if let Some(cursor) = error.cursor {
+        let (line, column) = line_column(script, offset + cursor);
+        error.cursor = Some(offset + cursor);
+        error.line = Some(line);
+        error.column = Some(column);
+    }
+    error
+}
+
+/// Scans the whole script. Regions the scanner rejects are returned as `Token::Nul`, so that they
+/// still end up inside a statement.
+fn tokens(sql: &str) -> Vec<protobuf::ScanToken> {
+    let mut tokens = Vec::new();
+    let mut offset = 0;
+    while offset < sql.len() {
+        let rest = &sql[offset..];
+        let shift = |token: protobuf::ScanToken| protobuf::ScanToken { start: token.start + offset as i32, end: token.end + offset as i32, ..token };
+        match scan(rest) {
+            Ok(scanned) => {
+                tokens.extend(scanned.into_iter().map(shift));
+                break;
+            }
+            Err(error) => {
+                let Some(cursor) = error.as_ref().and_then(|error| error.cursor).filter(|&cursor| cursor < rest.len()) else {
+                    tokens.push(protobuf::ScanToken { start: offset as i32, end: sql.len() as i32, token: Token::Nul as i32, keyword_kind: 0 });
+                    break;
+                };
+                tokens.extend(scan(&rest[..cursor]).unwrap_or_default().into_iter().map(shift));
+                // Unterminated literals and comments swallow the rest of the script, other invalid tokens
+                // are skipped one character at a time
+                let end = if error.is_some_and(|error| error.message.starts_with("unterminated")) {
+                    sql.len()
+                } else {
+                    offset + cursor + rest[cursor..].chars().next().map_or(1, char::len_utf8)
+                };
+                tokens.push(protobuf::ScanToken { start: (offset + cursor) as i32, end: end as i32, token: Token::Nul as i32, keyword_kind: 0 });
+                offset = end;
+            }
+        }
+    }
+    tokens
+}
+
+fn scan(sql: &str) -> std::result::Result<Vec<protobuf::ScanToken>, Option<Box<ParseError>>> {
+    let input = CString::new(sql).map_err(|_| None)?;
+    let result = unsafe { pg_query_scan(input.as_ptr()) };
+    let scan_result = if !result.error.is_null() {
+        Err(Some(Box::new(unsafe { ParseError::from_c(sql, result.error) })))
+    } else {
+        let data = unsafe { std::slice::from_raw_parts(result.pbuf.data as *const u8, result.pbuf.len as usize) };
+        protobuf::ScanResult::decode(data).map(|result| result.tokens).map_err(|_| None)
+    };
+    unsafe { pg_query_free_scan_result(result) };
+    scan_result
+}
+
+/// Groups tokens into statements, each spanning from its first to its last token. Comments before
+/// the first token and after the last token of a statement are left out.
+fn statement_spans(tokens: &[protobuf::ScanToken]) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut current: Option<Range<usize>> = None;
+    let mut first = Token::Nul;
+    // Nesting of BEGIN/CASE ... END inside a SQL-standard routine body, whose semicolons don't end the statement
+    let mut depth = 0;
+    let mut routine = false;
+    for token in tokens {
+        let kind = Token::try_from(token.token).unwrap_or(Token::Nul);
+        match kind {
+            Token::SqlComment | Token::CComment => continue,
+            Token::Ascii59 if depth == 0 => {
+                spans.extend(current.take());
+                routine = false;
+                continue;
+            }
+            Token::Function | Token::Procedure if first == Token::Create => routine = true,
+            Token::BeginP if routine => depth += 1,
+            Token::Case if depth > 0 => depth += 1,
+            Token::EndP if depth > 0 => depth -= 1,
+            _ => {}
+        }
+        let (start, end) = (token.start as usize, token.end as usize);
+        current = match current {
+            Some(span) => Some(span.start..end),
+            None => {
+                first = kind;
+                Some(start..end)
+            }
+        };
+    }
+    spans.extend(current);
+    spans
+}
@@ -0,0 +1,343 @@
+//! Selectors, a small CSS-like language for finding nodes in parse trees.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::*;
+
+include!(concat!(env!("OUT_DIR"), "/selector.rs"));
+
+/// A compiled selector, which finds the nodes of a tree matching a pattern, e.g.
+/// `UpdateStmt > whereClause ColumnRef[fields="tenant_id"]`.
+///
+/// A selector is a list of steps, each matching a node:
+///
+/// - A node type, as in [NodeRef] (e.g. `ColumnRef`, or `A_Const` as in libpg_query's JSON), matches
+///   nodes of that type. `*` matches any node.
+/// - A field name (e.g. `whereClause` or `where_clause`) matches the node held by that field of its parent.
+/// - Either may be followed by attribute tests on the node's fields: `[name]` tests that the field is
+///   set, `[name="value"]` that it has the value, and `[name!="value"]` that it doesn't. Enums are
+///   compared by name (e.g. `[boolop="OR_EXPR"]`). Fields holding names, such as the `fields` of a
+///   `ColumnRef` or the `funcname` of a `FuncCall`, match a value when their trailing names equal it,
+///   so `[fields="tenant_id"]` matches both `tenant_id` and `t.tenant_id`.
+///
+/// Steps separated by whitespace match descendants of the previous step, and steps separated by `>`
+/// its direct children. Selectors separated by commas match the nodes that any of them match.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::{NodeRef, Selector};
+///
+/// let selector = Selector::new(r#"UpdateStmt > whereClause ColumnRef[fields="tenant_id"]"#).unwrap();
+/// let result = pg_query::parse("UPDATE accounts SET tenant_id = 2 WHERE accounts.tenant_id = 1 AND id = 5").unwrap();
+/// let matches = selector.find(&result.protobuf);
+/// assert_eq!(matches.len(), 1);
+/// assert_eq!(matches[0].0.to_string(), "stmts[0].where_clause.args[0].lexpr");
+/// assert!(matches!(matches[0].1, NodeRef::ColumnRef(_)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Selector {
+    source: String,
+    alternatives: Vec<Vec<Step>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Step {
+    /// How the node relates to the one matched by the previous step
+    combinator: Combinator,
+    node_type: Option<&'static str>,
+    field: Option<String>,
+    attributes: Vec<Attribute>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Attribute {
+    field: String,
+    /// The expected value, and whether it should be equal to it
+    value: Option<(String, bool)>,
+}
+
+impl Selector {
+    /// Compiles a selector, failing with [Error::InvalidSelector] if it isn't valid or names a node type or
+    /// field that doesn't exist, as those would never match.
+    pub fn new(selector: &str) -> Result<Self> {
+        let mut parser = SelectorParser { source: selector, position: 0 };
+        let mut alternatives = vec![parser.steps()?];
+        while parser.eat(',') {
+            alternatives.push(parser.steps()?);
+        }
+        if parser.position < selector.len() {
+            return Err(parser.error("unexpected character"));
+        }
+        Ok(Selector { source: selector.to_string(), alternatives })
+    }
+
+    /// Returns the nodes of `tree` that the selector matches, with their paths, in pre-order.
+    pub fn find<'a>(&self, tree: &'a protobuf::ParseResult) -> Vec<(NodePath, NodeRef<'a>)> {
+        let mut ancestors: Vec<(NodePath, NodeRef<'a>)> = Vec::new();
+        let mut matches = Vec::new();
+        for (path, node) in tree.iter_nodes() {
+            ancestors.truncate(path.len() - 1);
+            ancestors.push((path, node));
+            if self.alternatives.iter().any(|steps| matches_steps(steps, &ancestors)) {
+                matches.push(ancestors.last().unwrap().clone());
+            }
+        }
+        matches
+    }
+
+    /// Returns whether the selector matches any node of `tree`.
+    pub fn is_match(&self, tree: &protobuf::ParseResult) -> bool {
+        let mut ancestors = Vec::new();
+        tree.iter_nodes().any(|(path, node)| {
+            ancestors.truncate(path.len() - 1);
+            ancestors.push((path, node));
+            self.alternatives.iter().any(|steps| matches_steps(steps, &ancestors))
+        })
+    }
+}
+
+impl FromStr for Selector {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Selector::new(s)
+    }
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+/// Returns whether `steps` match the last node of `nodes`, given the nodes before it are its ancestors.
+fn matches_steps(steps: &[Step], nodes: &[(NodePath, NodeRef)]) -> bool {
+    let (Some((step, steps)), Some(((path, node), ancestors))) = (steps.split_last(), nodes.split_last()) else { return false };
+    if !step.matches(path, *node) {
+        return false;
+    }
+    if steps.is_empty() {
+        return true;
+    }
+    match step.combinator {
+        Combinator::Child => matches_steps(steps, ancestors),
+        Combinator::Descendant => (1..=ancestors.len()).rev().any(|len| matches_steps(steps, &ancestors[..len])),
+    }
+}
+
+impl Step {
+    fn matches(&self, path: &NodePath, node: NodeRef) -> bool {
+        self.node_type.is_none_or(|node_type| node_type == self::node_type(node))
+            && self.field.as_ref().is_none_or(|field| path.last().is_some_and(|segment| segment.field == field.as_str()))
+            && self.attributes.iter().all(|attribute| attribute.matches(node))
+    }
+}
+
+impl Attribute {
+    fn matches(&self, node: NodeRef) -> bool {
+        if let Some((text, set)) = scalar_field(node, &self.field) {
+            return match &self.value {
+                None => set,
+                Some((value, equal)) => (text == *value) == *equal,
+            };
+        }
+        let mut names = Vec::new();
+        let mut set = false;
+        children(node, &mut |field, _, child| {
+            if field == self.field {
+                set = true;
+                names.extend(value(child));
+            }
+        });
+        match &self.value {
+            None => set,
+            Some((value, equal)) => (!names.is_empty() && names.ends_with(&value.split('.').map(str::to_string).collect::<Vec<_>>())) == *equal,
+        }
+    }
+}
+
+/// Returns the value of a node holding a name or a constant.
+fn value(node: NodeRef) -> Option<String> {
+    match node {
+        NodeRef::String(node) => Some(node.sval.clone()),
+        NodeRef::Integer(node) => Some(node.ival.to_string()),
+        NodeRef::Float(node) => Some(node.fval.clone()),
+        NodeRef::Boolean(node) => Some(node.boolval.to_string()),
+        NodeRef::BitString(node) => Some(node.bsval.clone()),
+        _ => None,
+    }
+}
+
+struct SelectorParser<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> SelectorParser<'a> {
+    fn error(&self, message: &str) -> Error {
+        Error::InvalidSelector(format!("{message} at position {} of {:?}", self.position, self.source))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.position..].chars().next()
+    }
+
+    /// Skips whitespace, returning whether there was any.
+    fn whitespace(&mut self) -> bool {
+        let start = self.position;
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.position += c.len_utf8();
+        }
+        self.position > start
+    }
+
+    /// Consumes `c`, and the whitespace around it, if it's next.
+    fn eat(&mut self, c: char) -> bool {
+        let start = self.position;
+        self.whitespace();
+        if self.peek() == Some(c) {
+            self.position += 1;
+            self.whitespace();
+            true
+        } else {
+            self.position = start;
+            false
+        }
+    }
+
+    fn identifier(&mut self) -> Option<&'a str> {
+        let start = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_alphanumeric() || c == '_') {
+            self.position += 1;
+        }
+        let source = self.source;
+        (self.position > start).then(|| &source[start..self.position])
+    }
+
+    fn steps(&mut self) -> Result<Vec<Step>> {
+        self.whitespace();
+        let mut steps = vec![self.step(Combinator::Descendant)?];
+        loop {
+            if self.eat('>') {
+                steps.push(self.step(Combinator::Child)?);
+            } else if self.whitespace() && !matches!(self.peek(), None | Some(',')) {
+                steps.push(self.step(Combinator::Descendant)?);
+            } else {
+                return Ok(steps);
+            }
+        }
+    }
+
+    fn step(&mut self, combinator: Combinator) -> Result<Step> {
+        let mut step = Step { combinator, node_type: None, field: None, attributes: Vec::new() };
+        let start = self.position;
+        if self.peek() == Some('*') {
+            self.position += 1;
+        } else if let Some(name) = self.identifier() {
+            if name.starts_with(|c: char| c.is_ascii_uppercase()) {
+                let normalized = name.replace('_', "");
+                let Some(node_type) = NODE_TYPES.iter().find(|node_type| node_type.eq_ignore_ascii_case(&normalized)) else {
+                    self.position = start;
+                    return Err(self.error(&format!("unknown node type {name}")));
+                };
+                step.node_type = Some(node_type);
+            } else {
+                let field = snake_case(name);
+                if FIELD_NAMES.binary_search(&field.as_str()).is_err() {
+                    self.position = start;
+                    return Err(self.error(&format!("unknown field {name}")));
+                }
+                step.field = Some(field);
+            }
+        }
+        while self.peek() == Some('[') {
+            self.position += 1;
+            step.attributes.push(self.attribute(step.node_type)?);
+        }
+        if self.position == start {
+            return Err(self.error("expected a node type, field or attribute"));
+        }
+        Ok(step)
+    }
+
+    /// Parses an attribute test on a field of `node_type`, or of any node type if it's `None`.
+    fn attribute(&mut self, node_type: Option<&str>) -> Result<Attribute> {
+        self.whitespace();
+        let start = self.position;
+        let name = self.identifier().ok_or_else(|| self.error("expected a field name"))?;
+        let field = snake_case(name);
+        let known = match node_type {
+            Some(node_type) => node_fields(node_type).contains(&field.as_str()),
+            None => FIELD_NAMES.binary_search(&field.as_str()).is_ok(),
+        };
+        if !known {
+            self.position = start;
+            return Err(self.error(&format!("unknown field {name}{}", node_type.map(|node_type| format!(" of {node_type}")).unwrap_or_default())));
+        }
+        let value = if self.eat('=') {
+            Some((self.value()?, true))
+        } else if self.eat('!') {
+            if !self.eat('=') {
+                return Err(self.error("expected ="));
+            }
+            Some((self.value()?, false))
+        } else {
+            None
+        };
+        if !self.eat(']') {
+            return Err(self.error("expected ]"));
+        }
+        Ok(Attribute { field, value })
+    }
+
+    fn value(&mut self) -> Result<String> {
+        match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.position += 1;
+                let mut value = String::new();
+                let mut chars = self.source[self.position..].char_indices();
+                while let Some((i, c)) = chars.next() {
+                    match c {
+                        '\\' => value.extend(chars.next().map(|(_, c)| c)),
+                        c if c == quote => {
+                            self.position += i + 1;
+                            return Ok(value);
+                        }
+                        c => value.push(c),
+                    }
+                }
+                Err(self.error("unterminated string"))
+            }
+            _ => {
+                let start = self.position;
+                while self.peek().is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-')) {
+                    self.position += 1;
+                }
+                match &self.source[start..self.position] {
+                    "" => Err(self.error("expected a value")),
+                    value => Ok(value.to_string()),
+                }
+            }
+        }
+    }
+}
+
+/// Converts a field name from the camelCase of libpg_query's JSON to the snake_case of [crate::protobuf].
+fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
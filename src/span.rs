@@ -0,0 +1,182 @@
+//! Source positions of parse tree nodes.
+//!
+//! Postgres only records where a node starts (its `location`), and only for some node types. The extent
+//! of a node is recovered from the locations in its subtree and the tokens around them: it starts at the
+//! earliest location and ends at the last token before the next location outside of it, keeping only the
+//! tokens that can close the node, such as parentheses, `END` and `NULL`.
+
+use std::ops::Range;
+
+use crate::error::line_column;
+use crate::protobuf::{self, Token};
+use crate::*;
+
+impl ParseResult {
+    /// Returns the byte range of `node` within [ParseResult::statement].
+    ///
+    /// Top-level statements span their whole text. Other nodes span the tokens of their subtree,
+    /// together with enclosing parentheses and closing keywords. Trailing clauses without a location
+    /// of their own, such as an alias without `AS` or `FOR UPDATE` in a subquery, aren't included.
+    ///
+    /// Returns `None` if neither the node nor its children have a location, or the statement
+    /// isn't known (e.g. for trees loaded from JSON, see [ParseResult::with_statement]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pg_query::NodeRef;
+    ///
+    /// let query = "SELECT * FROM users WHERE id IN (SELECT user_id FROM orders) AND deleted_at IS NULL";
+    /// let result = pg_query::parse(query).unwrap();
+    /// let text: Vec<_> = result
+    ///     .protobuf
+    ///     .nodes()
+    ///     .into_iter()
+    ///     .filter(|(node, ..)| matches!(node, NodeRef::NullTest(_) | NodeRef::SelectStmt(_)))
+    ///     .map(|(node, ..)| result.source_text(&node).unwrap())
+    ///     .collect();
+    /// assert_eq!(text, [query, "deleted_at IS NULL", "SELECT user_id FROM orders"]);
+    /// ```
+    pub fn span(&self, node: &NodeRef) -> Option<Range<usize>> {
+        let statement = self.statement.as_deref()?;
+        let tokens = self.tokens()?;
+        let node = *node;
+        let inner = locations(node);
+
+        // Statements span their RawStmt, apart from surrounding whitespace and comments
+        let is_stmt = |stmt: &NodeEnum| locations(stmt.to_ref()) == inner && stmt.to_ref().structurally_eq(&node);
+        if let Some(stmt) = self.protobuf.stmts.iter().find(|stmt| stmt.stmt.as_ref().and_then(|stmt| stmt.node.as_ref()).is_some_and(is_stmt)) {
+            let range = statement_range(stmt, statement.len());
+            let first = tokens.iter().find(|token| range.contains(&(token.start as usize)))?;
+            let last = tokens.iter().rev().find(|token| range.contains(&(token.start as usize)))?;
+            return Some(first.start as usize..last.end as usize);
+        }
+
+        let (min, max) = (*inner.iter().min()?, *inner.iter().max()?);
+        let stmt = self.protobuf.stmts.iter().find(|stmt| statement_range(stmt, statement.len()).contains(&min))?;
+        let range = statement_range(stmt, statement.len());
+        let outer = stmt.stmt.as_ref().and_then(|stmt| stmt.node.as_ref()).map(|stmt| locations(stmt.to_ref())).unwrap_or_default();
+
+        // The node can't reach into the tokens of the nodes before and after it
+        let lower = match outer.iter().filter(|&&location| location < min).max() {
+            Some(&before) => tokens.partition_point(|token| token.start as usize <= before),
+            None => tokens.partition_point(|token| (token.start as usize) < range.start),
+        };
+        let upper = match outer.iter().filter(|&&location| location > max).min() {
+            Some(&after) => tokens.partition_point(|token| (token.start as usize) < after),
+            None => tokens.partition_point(|token| (token.start as usize) < range.end),
+        };
+        let mut first = tokens.partition_point(|token| token.end as usize <= min);
+        let mut last = tokens.partition_point(|token| token.end as usize <= max);
+        if first >= upper || last >= upper {
+            return None;
+        }
+
+        // Nested statements have no location, so start them at their first keyword
+        if matches!(node, NodeRef::SelectStmt(_) | NodeRef::InsertStmt(_) | NodeRef::UpdateStmt(_) | NodeRef::DeleteStmt(_) | NodeRef::MergeStmt(_)) {
+            let keyword = (lower..first).rev().find(|&i| {
+                matches!(
+                    token(&tokens[i]),
+                    Token::Select | Token::Values | Token::With | Token::Insert | Token::Update | Token::DeleteP | Token::Merge
+                )
+            });
+            first = keyword.unwrap_or(first);
+        }
+
+        // Include the opening parentheses of closing ones within the span, and vice versa
+        let mut depth: i32 = tokens[first..=last].iter().map(nesting).sum();
+        while depth < 0 && first > lower {
+            first -= 1;
+            depth += nesting(&tokens[first]);
+        }
+        while last + 1 < upper {
+            let next = &tokens[last + 1];
+            match token(next) {
+                Token::Ascii41 | Token::Ascii93 if depth > 0 => depth -= 1,
+                Token::EndP | Token::NullP | Token::TrueP | Token::FalseP | Token::Unknown | Token::Asc | Token::Desc => {}
+                Token::Not | Token::NullsP | Token::FirstP | Token::LastP => {}
+                // A parenthesized list without locations, e.g. the arguments of now() or count(*)
+                Token::Ascii40 | Token::Ascii91 => match closing(&tokens[last + 1..upper]) {
+                    Some(len) => last += len,
+                    None => break,
+                },
+                _ => break,
+            }
+            last += 1;
+        }
+        Some(tokens[first].start as usize..tokens[last].end as usize)
+    }
+
+    /// Returns the text of `node` within [ParseResult::statement], see [ParseResult::span].
+    pub fn source_text(&self, node: &NodeRef) -> Option<&str> {
+        let span = self.span(node)?;
+        self.statement.as_deref()?.get(span)
+    }
+
+    /// Returns the 1-based line and character column of a byte offset into [ParseResult::statement],
+    /// such as a node's `location`. Offsets within a character count as that character, and offsets
+    /// past the end as the end. Returns `None` if the statement isn't known.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let result = pg_query::parse("SELECT *\nFROM users").unwrap();
+    /// assert_eq!(result.line_column(14), Some((2, 6)));
+    /// ```
+    pub fn line_column(&self, offset: usize) -> Option<(usize, usize)> {
+        self.statement.as_deref().map(|statement| line_column(statement, offset))
+    }
+
+    /// Returns the tokens of the statement without comments, scanning it on the first call.
+    fn tokens(&self) -> Option<&[protobuf::ScanToken]> {
+        self.tokens
+            .get_or_init(|| {
+                let tokens = crate::scan(self.statement.as_deref()?).ok()?.tokens;
+                Some(tokens.into_iter().filter(|token| !matches!(Token::try_from(token.token), Ok(Token::SqlComment | Token::CComment))).collect())
+            })
+            .as_deref()
+    }
+}
+
+/// Byte range of a statement's text, which runs to the end of the input if its length is unknown.
+fn statement_range(stmt: &protobuf::RawStmt, len: usize) -> Range<usize> {
+    let start = stmt.stmt_location as usize;
+    match stmt.stmt_len {
+        0 => start..len,
+        stmt_len => start..start + stmt_len as usize,
+    }
+}
+
+/// Collects the locations of a node and its children, leaving out unknown (negative) ones.
+fn locations(node: NodeRef) -> Vec<usize> {
+    let mut locations = Vec::new();
+    let mut nodes = vec![node];
+    while let Some(node) = nodes.pop() {
+        if let Some(location) = scalar_field(node, "location").and_then(|(location, _)| location.parse().ok()) {
+            locations.push(location);
+        }
+        children(node, &mut |_, _, child| nodes.push(child));
+    }
+    locations
+}
+
+fn token(token: &protobuf::ScanToken) -> Token {
+    Token::try_from(token.token).unwrap_or(Token::Nul)
+}
+
+fn nesting(scan_token: &protobuf::ScanToken) -> i32 {
+    match token(scan_token) {
+        Token::Ascii40 | Token::Ascii91 => 1,
+        Token::Ascii41 | Token::Ascii93 => -1,
+        _ => 0,
+    }
+}
+
+/// Returns the offset of the token closing the bracket that `tokens` starts with.
+fn closing(tokens: &[protobuf::ScanToken]) -> Option<usize> {
+    let mut depth = 0;
+    tokens.iter().position(|token| {
+        depth += nesting(token);
+        depth == 0
+    })
+}
@@ -0,0 +1,284 @@
+//! The kinds of statements, as returned by [ParseResult::statement_kinds].
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::*;
+
+macro_rules! statement_kinds {
+    ($($(#[$attr:meta])* $kind:ident => $node:ident, $name:literal;)*) => {
+        /// The kind of a statement, named after its node type. See [ParseResult::statement_kinds].
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum StatementKind {
+            $($(#[$attr])* $kind,)*
+        }
+
+        impl StatementKind {
+            /// Every kind of statement.
+            pub const ALL: &'static [StatementKind] = &[$(StatementKind::$kind,)*];
+
+            /// Returns the kind of the statement `node`, or `None` if it isn't a statement.
+            pub fn of(node: &NodeEnum) -> Option<Self> {
+                match node {
+                    $(NodeEnum::$node(..) => Some(StatementKind::$kind),)*
+                    _ => None,
+                }
+            }
+
+            /// Returns the name of the kind, as in [ParseResult::statement_types], e.g. `InsertStmt`.
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $(StatementKind::$kind => $name,)*
+                }
+            }
+        }
+    };
+}
+
+statement_kinds! {
+    Insert => InsertStmt, "InsertStmt";
+    Delete => DeleteStmt, "DeleteStmt";
+    Update => UpdateStmt, "UpdateStmt";
+    Select => SelectStmt, "SelectStmt";
+    Merge => MergeStmt, "MergeStmt";
+    AlterTable => AlterTableStmt, "AlterTableStmt";
+    AlterTableCmd => AlterTableCmd, "AlterTableCmd";
+    AlterDomain => AlterDomainStmt, "AlterDomainStmt";
+    SetOperation => SetOperationStmt, "SetOperationStmt";
+    Grant => GrantStmt, "GrantStmt";
+    GrantRole => GrantRoleStmt, "GrantRoleStmt";
+    AlterDefaultPrivileges => AlterDefaultPrivilegesStmt, "AlterDefaultPrivilegesStmt";
+    ClosePortal => ClosePortalStmt, "ClosePortalStmt";
+    Cluster => ClusterStmt, "ClusterStmt";
+    Copy => CopyStmt, "CopyStmt";
+    Create => CreateStmt, "CreateStmt";
+    Define => DefineStmt, "DefineStmt";
+    Drop => DropStmt, "DropStmt";
+    Truncate => TruncateStmt, "TruncateStmt";
+    Comment => CommentStmt, "CommentStmt";
+    Fetch => FetchStmt, "FetchStmt";
+    Index => IndexStmt, "IndexStmt";
+    CreateFunction => CreateFunctionStmt, "CreateFunctionStmt";
+    AlterFunction => AlterFunctionStmt, "AlterFunctionStmt";
+    Do => DoStmt, "DoStmt";
+    Rename => RenameStmt, "RenameStmt";
+    Rule => RuleStmt, "RuleStmt";
+    Notify => NotifyStmt, "NotifyStmt";
+    Listen => ListenStmt, "ListenStmt";
+    Unlisten => UnlistenStmt, "UnlistenStmt";
+    Transaction => TransactionStmt, "TransactionStmt";
+    View => ViewStmt, "ViewStmt";
+    Load => LoadStmt, "LoadStmt";
+    CreateDomain => CreateDomainStmt, "CreateDomainStmt";
+    Createdb => CreatedbStmt, "CreatedbStmt";
+    Dropdb => DropdbStmt, "DropdbStmt";
+    Vacuum => VacuumStmt, "VacuumStmt";
+    Explain => ExplainStmt, "ExplainStmt";
+    CreateTableAs => CreateTableAsStmt, "CreateTableAsStmt";
+    CreateSeq => CreateSeqStmt, "CreateSeqStmt";
+    AlterSeq => AlterSeqStmt, "AlterSeqStmt";
+    VariableSet => VariableSetStmt, "VariableSetStmt";
+    VariableShow => VariableShowStmt, "VariableShowStmt";
+    Discard => DiscardStmt, "DiscardStmt";
+    CreateTrig => CreateTrigStmt, "CreateTrigStmt";
+    /// Spelled `CreatePLangStmt` to match the C implementation
+    CreatePlang => CreatePlangStmt, "CreatePLangStmt";
+    CreateRole => CreateRoleStmt, "CreateRoleStmt";
+    AlterRole => AlterRoleStmt, "AlterRoleStmt";
+    DropRole => DropRoleStmt, "DropRoleStmt";
+    Lock => LockStmt, "LockStmt";
+    ConstraintsSet => ConstraintsSetStmt, "ConstraintsSetStmt";
+    Reindex => ReindexStmt, "ReindexStmt";
+    CheckPoint => CheckPointStmt, "CheckPointStmt";
+    CreateSchema => CreateSchemaStmt, "CreateSchemaStmt";
+    AlterDatabase => AlterDatabaseStmt, "AlterDatabaseStmt";
+    AlterDatabaseSet => AlterDatabaseSetStmt, "AlterDatabaseSetStmt";
+    AlterRoleSet => AlterRoleSetStmt, "AlterRoleSetStmt";
+    CreateConversion => CreateConversionStmt, "CreateConversionStmt";
+    CreateCast => CreateCastStmt, "CreateCastStmt";
+    CreateOpClass => CreateOpClassStmt, "CreateOpClassStmt";
+    CreateOpFamily => CreateOpFamilyStmt, "CreateOpFamilyStmt";
+    AlterOpFamily => AlterOpFamilyStmt, "AlterOpFamilyStmt";
+    Prepare => PrepareStmt, "PrepareStmt";
+    Execute => ExecuteStmt, "ExecuteStmt";
+    Deallocate => DeallocateStmt, "DeallocateStmt";
+    DeclareCursor => DeclareCursorStmt, "DeclareCursorStmt";
+    CreateTableSpace => CreateTableSpaceStmt, "CreateTableSpaceStmt";
+    DropTableSpace => DropTableSpaceStmt, "DropTableSpaceStmt";
+    AlterObjectDepends => AlterObjectDependsStmt, "AlterObjectDependsStmt";
+    AlterObjectSchema => AlterObjectSchemaStmt, "AlterObjectSchemaStmt";
+    AlterOwner => AlterOwnerStmt, "AlterOwnerStmt";
+    AlterOperator => AlterOperatorStmt, "AlterOperatorStmt";
+    AlterType => AlterTypeStmt, "AlterTypeStmt";
+    DropOwned => DropOwnedStmt, "DropOwnedStmt";
+    ReassignOwned => ReassignOwnedStmt, "ReassignOwnedStmt";
+    CompositeType => CompositeTypeStmt, "CompositeTypeStmt";
+    CreateEnum => CreateEnumStmt, "CreateEnumStmt";
+    CreateRange => CreateRangeStmt, "CreateRangeStmt";
+    AlterEnum => AlterEnumStmt, "AlterEnumStmt";
+    /// Spelled `AlterTSDictionaryStmt` to match the C implementation
+    AlterTsdictionary => AlterTsdictionaryStmt, "AlterTSDictionaryStmt";
+    /// Spelled `AlterTSConfigurationStmt` to match the C implementation
+    AlterTsconfiguration => AlterTsconfigurationStmt, "AlterTSConfigurationStmt";
+    CreateFdw => CreateFdwStmt, "CreateFdwStmt";
+    AlterFdw => AlterFdwStmt, "AlterFdwStmt";
+    CreateForeignServer => CreateForeignServerStmt, "CreateForeignServerStmt";
+    AlterForeignServer => AlterForeignServerStmt, "AlterForeignServerStmt";
+    CreateUserMapping => CreateUserMappingStmt, "CreateUserMappingStmt";
+    AlterUserMapping => AlterUserMappingStmt, "AlterUserMappingStmt";
+    DropUserMapping => DropUserMappingStmt, "DropUserMappingStmt";
+    AlterTableSpaceOptions => AlterTableSpaceOptionsStmt, "AlterTableSpaceOptionsStmt";
+    AlterTableMoveAll => AlterTableMoveAllStmt, "AlterTableMoveAllStmt";
+    SecLabel => SecLabelStmt, "SecLabelStmt";
+    CreateForeignTable => CreateForeignTableStmt, "CreateForeignTableStmt";
+    ImportForeignSchema => ImportForeignSchemaStmt, "ImportForeignSchemaStmt";
+    CreateExtension => CreateExtensionStmt, "CreateExtensionStmt";
+    AlterExtension => AlterExtensionStmt, "AlterExtensionStmt";
+    AlterExtensionContents => AlterExtensionContentsStmt, "AlterExtensionContentsStmt";
+    CreateEventTrig => CreateEventTrigStmt, "CreateEventTrigStmt";
+    AlterEventTrig => AlterEventTrigStmt, "AlterEventTrigStmt";
+    RefreshMatView => RefreshMatViewStmt, "RefreshMatViewStmt";
+    ReplicaIdentity => ReplicaIdentityStmt, "ReplicaIdentityStmt";
+    AlterSystem => AlterSystemStmt, "AlterSystemStmt";
+    CreatePolicy => CreatePolicyStmt, "CreatePolicyStmt";
+    AlterPolicy => AlterPolicyStmt, "AlterPolicyStmt";
+    CreateTransform => CreateTransformStmt, "CreateTransformStmt";
+    CreateAm => CreateAmStmt, "CreateAmStmt";
+    CreatePublication => CreatePublicationStmt, "CreatePublicationStmt";
+    AlterPublication => AlterPublicationStmt, "AlterPublicationStmt";
+    CreateSubscription => CreateSubscriptionStmt, "CreateSubscriptionStmt";
+    AlterSubscription => AlterSubscriptionStmt, "AlterSubscriptionStmt";
+    DropSubscription => DropSubscriptionStmt, "DropSubscriptionStmt";
+    CreateStats => CreateStatsStmt, "CreateStatsStmt";
+    AlterCollation => AlterCollationStmt, "AlterCollationStmt";
+    Call => CallStmt, "CallStmt";
+    AlterStats => AlterStatsStmt, "AlterStatsStmt";
+}
+
+impl StatementKind {
+    /// Returns whether the statement is a query or modifies data in tables: `SELECT`, `INSERT`, `UPDATE`,
+    /// `DELETE` or `MERGE`.
+    pub fn is_dml(self) -> bool {
+        matches!(
+            self,
+            StatementKind::Select
+                | StatementKind::SetOperation
+                | StatementKind::Insert
+                | StatementKind::Update
+                | StatementKind::Delete
+                | StatementKind::Merge
+        )
+    }
+
+    /// Returns whether the statement is a utility statement, i.e. anything but DML, which Postgres runs
+    /// without planning. This includes DDL.
+    pub fn is_utility(self) -> bool {
+        !self.is_dml()
+    }
+
+    /// Returns whether the statement defines or changes objects or privileges, like the statements logged
+    /// with `log_statement = 'ddl'`.
+    pub fn is_ddl(self) -> bool {
+        matches!(
+            self,
+            StatementKind::AlterTable
+            | StatementKind::AlterTableCmd
+            | StatementKind::AlterDomain
+            | StatementKind::Grant
+            | StatementKind::GrantRole
+            | StatementKind::AlterDefaultPrivileges
+            | StatementKind::Create
+            | StatementKind::Define
+            | StatementKind::Drop
+            | StatementKind::Comment
+            | StatementKind::Index
+            | StatementKind::CreateFunction
+            | StatementKind::AlterFunction
+            | StatementKind::Rename
+            | StatementKind::Rule
+            | StatementKind::View
+            | StatementKind::CreateDomain
+            | StatementKind::Createdb
+            | StatementKind::Dropdb
+            | StatementKind::CreateTableAs
+            | StatementKind::CreateSeq
+            | StatementKind::AlterSeq
+            | StatementKind::CreateTrig
+            | StatementKind::CreatePlang
+            | StatementKind::CreateRole
+            | StatementKind::AlterRole
+            | StatementKind::DropRole
+            | StatementKind::Cluster
+            | StatementKind::CreateSchema
+            | StatementKind::AlterDatabase
+            | StatementKind::AlterDatabaseSet
+            | StatementKind::AlterRoleSet
+            | StatementKind::CreateConversion
+            | StatementKind::CreateCast
+            | StatementKind::CreateOpClass
+            | StatementKind::CreateOpFamily
+            | StatementKind::AlterOpFamily
+            | StatementKind::CreateTableSpace
+            | StatementKind::DropTableSpace
+            | StatementKind::AlterObjectDepends
+            | StatementKind::AlterObjectSchema
+            | StatementKind::AlterOwner
+            | StatementKind::AlterOperator
+            | StatementKind::AlterType
+            | StatementKind::DropOwned
+            | StatementKind::ReassignOwned
+            | StatementKind::CompositeType
+            | StatementKind::CreateEnum
+            | StatementKind::CreateRange
+            | StatementKind::AlterEnum
+            | StatementKind::AlterTsdictionary
+            | StatementKind::AlterTsconfiguration
+            | StatementKind::CreateFdw
+            | StatementKind::AlterFdw
+            | StatementKind::CreateForeignServer
+            | StatementKind::AlterForeignServer
+            | StatementKind::CreateUserMapping
+            | StatementKind::AlterUserMapping
+            | StatementKind::DropUserMapping
+            | StatementKind::AlterTableSpaceOptions
+            | StatementKind::AlterTableMoveAll
+            | StatementKind::SecLabel
+            | StatementKind::CreateForeignTable
+            | StatementKind::ImportForeignSchema
+            | StatementKind::CreateExtension
+            | StatementKind::AlterExtension
+            | StatementKind::AlterExtensionContents
+            | StatementKind::CreateEventTrig
+            | StatementKind::AlterEventTrig
+            | StatementKind::RefreshMatView
+            | StatementKind::ReplicaIdentity
+            | StatementKind::AlterSystem
+            | StatementKind::CreatePolicy
+            | StatementKind::AlterPolicy
+            | StatementKind::CreateTransform
+            | StatementKind::CreateAm
+            | StatementKind::CreatePublication
+            | StatementKind::AlterPublication
+            | StatementKind::CreateSubscription
+            | StatementKind::AlterSubscription
+            | StatementKind::DropSubscription
+            | StatementKind::CreateStats
+            | StatementKind::AlterCollation
+            | StatementKind::AlterStats
+        )
+    }
+}
+
+impl fmt::Display for StatementKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for StatementKind {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        StatementKind::ALL.iter().copied().find(|kind| kind.as_str() == s).ok_or_else(|| Error::InvalidStatementKind(s.to_string()))
+    }
+}
@@ -0,0 +1,176 @@
+//! Structural comparison and hashing of nodes, ignoring where they appear in the query text.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use crate::*;
+
+/// What structural comparisons and [StructuralHash] ignore besides locations.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StructuralOptions {
+    /// Ignore the `AS` names of tables, subqueries, functions and joins (`Alias` nodes), and of the
+    /// output columns of `SELECT` and `RETURNING` lists. References to them, like `u.id`, are still
+    /// compared.
+    pub ignore_aliases: bool,
+}
+
+/// A hash of a subtree that ignores locations, so that structurally equal nodes (see
+/// [NodeRef::structurally_eq]) have the same hash.
+///
+/// The hash of a node is computed from its type, its scalar fields and the hashes of its children, so
+/// [StructuralHash::subtrees] finds the hashes of every subtree in a single pass. Hashes are stable
+/// within a build of the crate, but aren't meant to be persisted.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::{NodeRef, StructuralHash, StructuralOptions};
+///
+/// let result = pg_query::parse("SELECT lower(name) FROM users WHERE lower(name) = 'x'").unwrap();
+/// let stmt = result.protobuf.stmts[0].stmt.as_ref().unwrap().node.as_ref().unwrap();
+/// let calls: Vec<_> = StructuralHash::subtrees(stmt.to_ref(), StructuralOptions::default())
+///     .into_iter()
+///     .filter(|(_, node, _)| matches!(node, NodeRef::FuncCall(_)))
+///     .collect();
+/// assert_eq!(calls.len(), 2);
+/// assert_eq!(calls[0].2, calls[1].2);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StructuralHash(u64);
+
+/// The hashes of the subtrees of a node, with their paths.
+type Subtrees<'a> = Vec<(NodePath, NodeRef<'a>, StructuralHash)>;
+
+impl StructuralHash {
+    /// Returns the hash of `node`.
+    pub fn of(node: NodeRef, options: StructuralOptions) -> Self {
+        hash(node, None, options, None)
+    }
+
+    /// Returns the hash of `node` and of every node within it, children first, together with their
+    /// paths relative to `node`.
+    pub fn subtrees(node: NodeRef, options: StructuralOptions) -> Vec<(NodePath, NodeRef, StructuralHash)> {
+        let mut subtrees = Vec::new();
+        hash(node, None, options, Some((NodePath::default(), &mut subtrees)));
+        subtrees
+    }
+
+    /// Returns the hash as a number.
+    pub fn as_u64(self) -> u64 {
+        self.0
+    }
+}
+
+impl NodeRef<'_> {
+    /// Returns whether the two nodes have the same type, fields and children, ignoring their
+    /// locations, e.g. `a + 1` in `SELECT a + 1` and in `SELECT * FROM t WHERE a + 1 > 2`.
+    pub fn structurally_eq(&self, other: &NodeRef) -> bool {
+        self.structurally_eq_with(other, StructuralOptions::default())
+    }
+
+    /// Same as [NodeRef::structurally_eq], also ignoring what `options` asks for.
+    pub fn structurally_eq_with(&self, other: &NodeRef, options: StructuralOptions) -> bool {
+        equal(*self, *other, None, options)
+    }
+
+    /// Returns the hash of the node, see [StructuralHash].
+    pub fn structural_hash(&self) -> StructuralHash {
+        StructuralHash::of(*self, StructuralOptions::default())
+    }
+}
+
+impl protobuf::Node {
+    /// Returns whether the two nodes are equal, ignoring their locations. See [NodeRef::structurally_eq].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let first = pg_query::parse("SELECT * FROM users WHERE id = 1").unwrap();
+    /// let second = pg_query::parse("select *\n  from users\n where id = 1").unwrap();
+    /// let (first, second) = (first.protobuf.stmts[0].stmt.as_ref().unwrap(), second.protobuf.stmts[0].stmt.as_ref().unwrap());
+    /// assert_ne!(first, second);
+    /// assert!(first.structurally_eq(second));
+    /// ```
+    pub fn structurally_eq(&self, other: &protobuf::Node) -> bool {
+        self.structurally_eq_with(other, StructuralOptions::default())
+    }
+
+    /// Same as [protobuf::Node::structurally_eq], also ignoring what `options` asks for.
+    pub fn structurally_eq_with(&self, other: &protobuf::Node, options: StructuralOptions) -> bool {
+        match (&self.node, &other.node) {
+            (Some(node), Some(other)) => node.to_ref().structurally_eq_with(&other.to_ref(), options),
+            (node, other) => node.is_none() && other.is_none(),
+        }
+    }
+
+    /// Returns the hash of the node, see [StructuralHash]. Empty nodes all have the same hash.
+    pub fn structural_hash(&self) -> StructuralHash {
+        self.structural_hash_with(StructuralOptions::default())
+    }
+
+    /// Same as [protobuf::Node::structural_hash], also ignoring what `options` asks for.
+    pub fn structural_hash_with(&self, options: StructuralOptions) -> StructuralHash {
+        self.node.as_ref().map_or(StructuralHash(0), |node| StructuralHash::of(node.to_ref(), options))
+    }
+}
+
+/// Returns whether `field` holds a location, which structural comparisons ignore.
+pub(crate) fn is_location(field: &str) -> bool {
+    field == "location" || field.ends_with("_location")
+}
+
+/// Returns the compared scalar fields of `node`, found in `field` of a node of type `parent`, with
+/// their values.
+fn scalars<'a>(
+    node: NodeRef<'a>, parent: Option<(&str, &str)>, options: StructuralOptions,
+) -> impl Iterator<Item = (&'static str, Option<(String, bool)>)> + 'a {
+    // The name of a ResTarget is an output column alias there, and a target column elsewhere
+    let alias = options.ignore_aliases
+        && matches!(node, NodeRef::ResTarget(_))
+        && matches!(parent, Some(("SelectStmt", "target_list") | (_, "returning_list")));
+    scalar_fields(node).iter().filter(move |field| !is_location(field) && !(alias && **field == "name")).map(move |field| (*field, scalar_field(node, field)))
+}
+
+/// Returns the compared children of `node`, with their fields and indices.
+fn compared_children(node: NodeRef, options: StructuralOptions) -> Vec<(&'static str, Option<usize>, NodeRef)> {
+    let mut nodes = Vec::new();
+    children(node, &mut |field, index, child| {
+        if !(options.ignore_aliases && matches!(child, NodeRef::Alias(_))) {
+            nodes.push((field, index, child));
+        }
+    });
+    nodes
+}
+
+fn equal(node: NodeRef, other: NodeRef, parent: Option<(&str, &str)>, options: StructuralOptions) -> bool {
+    let node_type = node_type(node);
+    if node_type != crate::node_type(other) || !scalars(node, parent, options).eq(scalars(other, parent, options)) {
+        return false;
+    }
+    let (children, other_children) = (compared_children(node, options), compared_children(other, options));
+    children.len() == other_children.len()
+        && children.into_iter().zip(other_children).all(|((field, index, child), (other_field, other_index, other))| {
+            field == other_field && index == other_index && equal(child, other, Some((node_type, field)), options)
+        })
+}
+
+/// Hashes `node`, adding it and every node within it to `subtrees` if given, with `node` at the path.
+fn hash<'a>(
+    node: NodeRef<'a>, parent: Option<(&str, &str)>, options: StructuralOptions, mut subtrees: Option<(NodePath, &mut Subtrees<'a>)>,
+) -> StructuralHash {
+    let mut hasher = DefaultHasher::new();
+    let node_type = node_type(node);
+    node_type.hash(&mut hasher);
+    for scalar in scalars(node, parent, options) {
+        scalar.hash(&mut hasher);
+    }
+    for (field, index, child) in compared_children(node, options) {
+        let child_subtrees = subtrees.as_mut().map(|(path, subtrees)| (path.join(field, index), &mut **subtrees));
+        (field, index, hash(child, Some((node_type, field)), options, child_subtrees)).hash(&mut hasher);
+    }
+    let hash = StructuralHash(hasher.finish());
+    if let Some((path, subtrees)) = subtrees {
+        subtrees.push((path, node, hash));
+    }
+    hash
+}
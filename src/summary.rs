@@ -4,7 +4,9 @@ use prost::Message;
 
 use crate::bindings::*;
 use crate::error::*;
+use crate::limits::LimitKind;
 use crate::protobuf;
+use crate::query::ParseOptions;
 use crate::summary_result::SummaryResult;
 
 /// Parses the given SQL statement and provides a summary of it.
@@ -31,15 +33,36 @@ use crate::summary_result::SummaryResult;
 /// assert_eq!(result.tables(), vec!["contacts"]);
 /// ```
 pub fn summary(statement: &str, truncate_limit: i32) -> Result<SummaryResult> {
+    summary_with_options(statement, ParseOptions::default(), truncate_limit)
+}
+
+/// Same as [summary], but parses the statement with the given parser options.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::ParseOptions;
+///
+/// let options = ParseOptions { disable_standard_conforming_strings: true, ..ParseOptions::default() };
+/// let result = pg_query::summary_with_options(r"SELECT * FROM contacts WHERE name = 'O\'Brien'", options, -1).unwrap();
+/// assert_eq!(result.tables(), vec!["contacts"]);
+/// ```
+///
+/// As the summary doesn't include the parse tree, only the input length and statement count limits
+/// of [ParseOptions::limits] apply.
+pub fn summary_with_options(statement: &str, options: ParseOptions, truncate_limit: i32) -> Result<SummaryResult> {
+    options.limits.check_input(statement)?;
     let input = CString::new(statement)?;
-    let result = unsafe { pg_query_summary(input.as_ptr(), 0, truncate_limit) };
+    let result = unsafe { pg_query_summary(input.as_ptr(), options.bits(), truncate_limit) };
     let parse_result = if !result.error.is_null() {
-        let message = unsafe { CStr::from_ptr((*result.error).message) }.to_string_lossy().to_string();
-        Err(Error::Parse(message))
+        Err(Error::Syntax(Box::new(unsafe { ParseError::from_c(statement, result.error) })))
     } else {
         let data = unsafe { std::slice::from_raw_parts(result.summary.data as *const u8, result.summary.len as usize) };
         let stderr = unsafe { CStr::from_ptr(result.stderr_buffer) }.to_string_lossy().to_string();
-        protobuf::SummaryResult::decode(data).map_err(Error::Decode).map(|result| SummaryResult::new(result, stderr))
+        protobuf::SummaryResult::decode(data)
+            .map_err(Error::Decode)
+            .and_then(|result| options.limits.check(LimitKind::Statements, result.statement_types.len()).map(|()| result))
+            .map(|result| SummaryResult::new(result, stderr))
     };
     unsafe { pg_query_free_summary_parse_result(result) };
     parse_result
@@ -19,6 +19,9 @@ use crate::*;
 /// For `tables`, `functions`, and `filter_columns`, `SummaryResult` stores
 /// more details than `ParseResult`, so the signatures have changed.
 /// However, the _functions_ that correspond to them should be equivalent.
+///
+/// Summaries don't include the parse tree, so the methods of `ParseResult` that need it, such as
+/// [ParseResult::table_uses], aren't available here. Call them on [parse_raw] of the statement instead.
 #[derive(Debug, PartialEq)]
 pub struct SummaryResult {
     pub protobuf: protobuf::SummaryResult,
@@ -147,6 +150,11 @@ impl SummaryResult {
         // strict API compatibility with ParseResult.
         self.statement_types.iter().map(AsRef::as_ref).collect()
     }
+
+    /// Returns the kinds of the statements in the query. See [ParseResult::statement_kinds].
+    pub fn statement_kinds(&self) -> Vec<StatementKind> {
+        self.statement_types.iter().filter_map(|statement_type| statement_type.parse().ok()).collect()
+    }
 }
 
 #[derive(Debug, Eq, Hash, PartialEq)]
@@ -0,0 +1,176 @@
+//! Templates, which match parse trees against SQL with named placeholders.
+
+use std::collections::BTreeMap;
+
+use crate::*;
+
+/// A pattern written as SQL, with `$name` placeholders that match any node, e.g.
+/// `SELECT * FROM $table WHERE id = $value`.
+///
+/// A template matches a tree with the same structure, ignoring locations, and captures the nodes
+/// standing in for its placeholders. Placeholders can stand for a table (capturing a `RangeVar`, or
+/// whatever replaces it, like a subquery), an expression (capturing e.g. an `A_Const` or a `ParamRef`)
+/// or a name (capturing a `String`, e.g. in `$func(x)`). A placeholder used more than once must capture
+/// equal nodes each time. Numbered parameters like `$1` are matched as they are.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::{NodeRef, Template};
+///
+/// let template = Template::new("SELECT * FROM $table WHERE id = $value").unwrap();
+/// let result = pg_query::parse("SELECT * FROM users WHERE id = $1").unwrap();
+/// let bindings = template.captures(&result.protobuf).unwrap();
+/// assert!(matches!(bindings.get("table"), Some(NodeRef::RangeVar(table)) if table.relname == "users"));
+/// assert!(matches!(bindings.get("value"), Some(NodeRef::ParamRef(param)) if param.number == 1));
+///
+/// let result = pg_query::parse("SELECT * FROM users WHERE name = 'x'").unwrap();
+/// assert!(!template.is_match(&result.protobuf));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Template {
+    pattern: protobuf::ParseResult,
+}
+
+/// The nodes captured by the placeholders of a [Template], keyed by name without the `$`.
+#[derive(Clone, Debug, Default)]
+pub struct Bindings<'a>(BTreeMap<String, NodeRef<'a>>);
+
+impl Template {
+    /// Parses a template, failing if it isn't valid SQL once its placeholders are replaced by names.
+    pub fn new(template: &str) -> Result<Self> {
+        Ok(Template { pattern: parse(&quote_placeholders(template))?.protobuf })
+    }
+
+    /// Returns the nodes captured by the placeholders if `tree` matches the template, statement by
+    /// statement.
+    pub fn captures<'a>(&self, tree: &'a protobuf::ParseResult) -> Option<Bindings<'a>> {
+        if self.pattern.stmts.len() != tree.stmts.len() {
+            return None;
+        }
+        let mut bindings = Bindings::default();
+        for (pattern, stmt) in self.pattern.stmts.iter().zip(&tree.stmts) {
+            match (root(pattern), root(stmt)) {
+                (Some(pattern), Some(stmt)) if matches(pattern, stmt, &mut bindings) => {}
+                (None, None) => {}
+                _ => return None,
+            }
+        }
+        Some(bindings)
+    }
+
+    /// Returns whether `tree` matches the template.
+    pub fn is_match(&self, tree: &protobuf::ParseResult) -> bool {
+        self.captures(tree).is_some()
+    }
+}
+
+impl<'a> Bindings<'a> {
+    /// Returns the node captured by the placeholder `$name`.
+    pub fn get(&self, name: &str) -> Option<NodeRef<'a>> {
+        self.0.get(name).copied()
+    }
+
+    /// Returns the placeholders and the nodes they captured, ordered by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, NodeRef<'a>)> + '_ {
+        self.0.iter().map(|(name, node)| (name.as_str(), *node))
+    }
+
+    /// Returns the number of placeholders captured.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether no placeholders were captured.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+fn root(stmt: &protobuf::RawStmt) -> Option<NodeRef<'_>> {
+    Some(stmt.stmt.as_ref()?.node.as_ref()?.to_ref())
+}
+
+/// Returns whether `node` matches `pattern`, capturing placeholders into `bindings`.
+fn matches<'a>(pattern: NodeRef, node: NodeRef<'a>, bindings: &mut Bindings<'a>) -> bool {
+    if let Some(name) = placeholder(pattern) {
+        return match bindings.0.get(name) {
+            Some(bound) => bound.structurally_eq(&node),
+            None => {
+                bindings.0.insert(name.to_string(), node);
+                true
+            }
+        };
+    }
+    if node_type(pattern) != node_type(node) {
+        return false;
+    }
+    if scalar_fields(pattern).iter().filter(|field| !is_location(field)).any(|field| scalar_field(pattern, field) != scalar_field(node, field)) {
+        return false;
+    }
+    let (pattern_children, children) = (child_nodes(pattern), child_nodes(node));
+    pattern_children.len() == children.len()
+        && pattern_children
+            .into_iter()
+            .zip(children)
+            .all(|((field, index, pattern), (other_field, other_index, node))| {
+                field == other_field && index == other_index && matches(pattern, node, bindings)
+            })
+}
+
+fn child_nodes(node: NodeRef) -> Vec<(&'static str, Option<usize>, NodeRef)> {
+    let mut nodes = Vec::new();
+    children(node, &mut |field, index, child| nodes.push((field, index, child)));
+    nodes
+}
+
+/// Returns the name of the placeholder `node` stands for, if it's one.
+fn placeholder<'a>(node: NodeRef<'a>) -> Option<&'a str> {
+    let name = match node {
+        NodeRef::ColumnRef(column) if column.fields.len() == 1 => match column.fields[0].node.as_ref() {
+            Some(NodeEnum::String(name)) => &name.sval,
+            _ => return None,
+        },
+        NodeRef::RangeVar(table) if table.schemaname.is_empty() && table.catalogname.is_empty() && table.alias.is_none() => &table.relname,
+        NodeRef::String(name) => &name.sval,
+        _ => return None,
+    };
+    name.strip_prefix('$')
+}
+
+/// Turns the `$name` placeholders of `template` into quoted identifiers, which PostgreSQL accepts
+/// wherever a name or a column may appear, leaving strings, comments and parameters like `$1` alone.
+fn quote_placeholders(template: &str) -> String {
+    let mut sql = String::with_capacity(template.len());
+    let mut rest = template;
+    let identifier = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    while let Some(c) = rest.chars().next() {
+        let len = match c {
+            '\'' | '"' => {
+                // Doubled quotes are escapes, and read as two adjacent literals here
+                rest[1..].find(c).map_or(rest.len(), |end| end + 2)
+            }
+            '-' if rest.starts_with("--") => rest.find('\n').unwrap_or(rest.len()),
+            '/' if rest.starts_with("/*") => rest[2..].find("*/").map_or(rest.len(), |end| end + 4),
+            '$' if !sql.ends_with(identifier) => {
+                let name_len = rest[1..].find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(rest.len() - 1);
+                let name = &rest[1..1 + name_len];
+                if rest[1 + name_len..].starts_with('$') {
+                    // A dollar-quoted string, $tag$...$tag$
+                    let tag = &rest[..name_len + 2];
+                    tag.len() + rest[tag.len()..].find(tag).map_or(rest.len() - tag.len(), |end| end + tag.len())
+                } else if name.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+                    sql.push_str(&format!("\"${name}\""));
+                    rest = &rest[1 + name_len..];
+                    continue;
+                } else {
+                    1
+                }
+            }
+            c => c.len_utf8(),
+        };
+        sql.push_str(&rest[..len]);
+        rest = &rest[len..];
+    }
+    sql
+}
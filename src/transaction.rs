@@ -0,0 +1,213 @@
+//! Tracking of the transaction state of a connection over the statements it runs.
+
+use crate::locks::has_option;
+use crate::protobuf::{self, TransactionStmtKind};
+use crate::*;
+
+/// The transaction state of a connection, as in the status of `ReadyForQuery`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TransactionState {
+    /// Not in a transaction block
+    #[default]
+    Idle,
+    /// In the implicit transaction of a simple query with several statements, which commits at the end
+    /// of the query. The state after a query is never implicit.
+    Implicit,
+    /// In a transaction block, after `BEGIN`
+    InTransaction,
+    /// In a failed transaction block, where statements fail until `ROLLBACK` or `ROLLBACK TO SAVEPOINT`
+    Failed,
+}
+
+/// The change of the transaction state by a statement. See [TransactionTracker].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TransactionTransition {
+    pub from: TransactionState,
+    pub to: TransactionState,
+    /// The number of savepoints after the statement
+    pub savepoint_depth: usize,
+    /// Whether the statement failed, as reported or because it can't run in the state it ran in
+    pub error: bool,
+}
+
+/// Tracks the transaction state of a connection from the statements it runs, e.g. to know when a
+/// transaction-pooling proxy can release the connection.
+///
+/// Statements are assumed to succeed unless they can't in the state they run in, like any statement
+/// but `ROLLBACK` in a failed transaction, `SAVEPOINT` outside a transaction block, `ROLLBACK TO` an
+/// unknown savepoint or `VACUUM` or `DISCARD ALL` in a transaction block. Errors reported by the server are passed in.
+///
+/// # Example
+///
+/// ```rust
+/// use pg_query::{TransactionState, TransactionTracker};
+///
+/// let mut tracker = TransactionTracker::new();
+/// let query = pg_query::parse("BEGIN; SAVEPOINT a; UPDATE t SET a = 1").unwrap();
+/// let transitions = tracker.simple_query(&query, None);
+/// assert_eq!(transitions[0].to, TransactionState::InTransaction);
+/// assert_eq!((tracker.state(), tracker.savepoint_depth()), (TransactionState::InTransaction, 1));
+///
+/// // The server reports an error for the INSERT
+/// tracker.simple_query(&pg_query::parse("INSERT INTO t VALUES (1)").unwrap(), Some(0));
+/// assert_eq!(tracker.state(), TransactionState::Failed);
+///
+/// tracker.simple_query(&pg_query::parse("ROLLBACK TO a").unwrap(), None);
+/// assert_eq!(tracker.state(), TransactionState::InTransaction);
+/// tracker.simple_query(&pg_query::parse("COMMIT").unwrap(), None);
+/// assert_eq!((tracker.state(), tracker.savepoint_depth()), (TransactionState::Idle, 0));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TransactionTracker {
+    state: TransactionState,
+    savepoints: Vec<String>,
+}
+
+impl TransactionTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn state(&self) -> TransactionState {
+        self.state
+    }
+
+    /// Returns the number of savepoints in the transaction block.
+    pub fn savepoint_depth(&self) -> usize {
+        self.savepoints.len()
+    }
+
+    /// Runs a simple query, returning a transition for each of its statements, up to the first that
+    /// fails. `error` is the index of the statement the server reported an error for, if any.
+    ///
+    /// A query with several statements runs in an implicit transaction, unless they're in a transaction
+    /// block already, so the last transition is to [TransactionState::Idle] after an error, or after the
+    /// implicit transaction commits.
+    pub fn simple_query(&mut self, query: &ParseResult, error: Option<usize>) -> Vec<TransactionTransition> {
+        let statements: Vec<_> = query.protobuf.stmts.iter().filter_map(|stmt| stmt.stmt.as_ref().and_then(|stmt| stmt.node.as_ref())).collect();
+        let implicit = statements.len() > 1;
+        let mut transitions = Vec::new();
+        for (i, statement) in statements.into_iter().enumerate() {
+            let from = self.state;
+            if implicit && self.state == TransactionState::Idle {
+                self.state = TransactionState::Implicit;
+            }
+            let transition = self.run(from, statement.to_ref(), error == Some(i));
+            transitions.push(transition);
+            if transition.error {
+                break;
+            }
+        }
+        if self.state == TransactionState::Implicit {
+            self.state = TransactionState::Idle;
+            if let Some(transition) = transitions.last_mut() {
+                transition.to = TransactionState::Idle;
+            }
+        }
+        transitions
+    }
+
+    /// Runs a statement on its own, e.g. with the extended query protocol. `error` is whether the server
+    /// reported an error for it.
+    pub fn execute(&mut self, statement: NodeRef, error: bool) -> TransactionTransition {
+        self.run(self.state, statement, error)
+    }
+
+    fn run(&mut self, from: TransactionState, statement: NodeRef, error: bool) -> TransactionTransition {
+        let error = error || !self.apply(statement);
+        if error {
+            let ends_block = match statement {
+                NodeRef::TransactionStmt(stmt) => matches!(
+                    TransactionStmtKind::try_from(stmt.kind),
+                    Ok(TransactionStmtKind::TransStmtCommit | TransactionStmtKind::TransStmtPrepare)
+                ),
+                _ => false,
+            };
+            self.state = match self.state {
+                TransactionState::InTransaction if !ends_block => TransactionState::Failed,
+                TransactionState::Failed => TransactionState::Failed,
+                _ => TransactionState::Idle,
+            };
+        }
+        if matches!(self.state, TransactionState::Idle | TransactionState::Implicit) {
+            self.savepoints.clear();
+        }
+        TransactionTransition { from, to: self.state, savepoint_depth: self.savepoints.len(), error }
+    }
+
+    /// Applies a statement that succeeds, returning `false` instead if it can't in the current state.
+    fn apply(&mut self, statement: NodeRef) -> bool {
+        use TransactionState::*;
+        use TransactionStmtKind::*;
+        let NodeRef::TransactionStmt(stmt) = statement else {
+            return match self.state {
+                Idle => true,
+                Failed => false,
+                Implicit | InTransaction => !prevented_in_transaction_block(statement),
+            };
+        };
+        let name = &stmt.savepoint_name;
+        let position = self.savepoints.iter().rposition(|savepoint| savepoint == name);
+        match TransactionStmtKind::try_from(stmt.kind).unwrap_or(Undefined) {
+            // BEGIN in a transaction block only warns
+            TransStmtBegin | TransStmtStart if self.state != Failed => self.state = InTransaction,
+            // COMMIT and ROLLBACK outside of a transaction block only warn, but can't chain
+            TransStmtCommit | TransStmtRollback if stmt.chain => match self.state {
+                InTransaction | Failed => {
+                    self.savepoints.clear();
+                    self.state = InTransaction;
+                }
+                Idle | Implicit => return false,
+            },
+            TransStmtCommit | TransStmtRollback | TransStmtPrepare => self.state = Idle,
+            TransStmtSavepoint if self.state == InTransaction => self.savepoints.push(name.clone()),
+            TransStmtRelease if self.state == InTransaction => match position {
+                Some(position) => self.savepoints.truncate(position),
+                None => return false,
+            },
+            TransStmtRollbackTo if matches!(self.state, InTransaction | Failed) => match position {
+                Some(position) => {
+                    self.savepoints.truncate(position + 1);
+                    self.state = InTransaction;
+                }
+                None => return false,
+            },
+            TransStmtCommitPrepared | TransStmtRollbackPrepared => return self.state == Idle,
+            Undefined => return self.state != Failed,
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Returns whether the statement can't run in a transaction block, including an implicit one. From
+/// Postgres source: callers of PreventInTransactionBlock.
+fn prevented_in_transaction_block(statement: NodeRef) -> bool {
+    match statement {
+        NodeRef::VacuumStmt(vacuum) => vacuum.is_vacuumcmd,
+        NodeRef::IndexStmt(index) => index.concurrent,
+        NodeRef::DropStmt(drop) => drop.concurrent,
+        NodeRef::ReindexStmt(reindex) => {
+            has_option(&reindex.params, "concurrently")
+                || matches!(
+                    protobuf::ReindexObjectType::try_from(reindex.kind),
+                    Ok(protobuf::ReindexObjectType::ReindexObjectSystem | protobuf::ReindexObjectType::ReindexObjectDatabase)
+                )
+        }
+        NodeRef::DiscardStmt(discard) => discard.target == protobuf::DiscardMode::DiscardAll as i32,
+        // CLUSTER without a table reclusters all the tables that were clustered before
+        NodeRef::ClusterStmt(cluster) => cluster.relation.is_none(),
+        NodeRef::AlterTableStmt(alter) => alter.cmds.iter().any(|cmd| match &cmd.node {
+            Some(NodeEnum::AlterTableCmd(cmd)) if cmd.subtype == protobuf::AlterTableType::AtDetachPartition as i32 => {
+                matches!(cmd.def.as_ref().and_then(|def| def.node.as_ref()), Some(NodeEnum::PartitionCmd(partition)) if partition.concurrent)
+            }
+            _ => false,
+        }),
+        NodeRef::CreatedbStmt(_)
+        | NodeRef::DropdbStmt(_)
+        | NodeRef::CreateTableSpaceStmt(_)
+        | NodeRef::DropTableSpaceStmt(_)
+        | NodeRef::AlterSystemStmt(_) => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,250 @@
+//! A typed view of `CREATE TRIGGER` statements.
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use crate::protobuf;
+use crate::resolve::strings;
+use crate::*;
+
+/// When a trigger fires, relative to the event.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TriggerTiming {
+    Before,
+    #[default]
+    After,
+    /// Instead of the event, for triggers on views
+    InsteadOf,
+}
+
+/// An event that fires a trigger.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TriggerEvent {
+    Insert,
+    Update,
+    Delete,
+    Truncate,
+}
+
+impl TriggerEvent {
+    const ALL: [TriggerEvent; 4] = [TriggerEvent::Insert, TriggerEvent::Update, TriggerEvent::Delete, TriggerEvent::Truncate];
+
+    /// Returns the flag of the event in the `events` of a `CreateTrigStmt`.
+    pub fn trigger_type(self) -> TriggerType {
+        match self {
+            TriggerEvent::Insert => TriggerType::Insert,
+            TriggerEvent::Update => TriggerType::Update,
+            TriggerEvent::Delete => TriggerType::Delete,
+            TriggerEvent::Truncate => TriggerType::Truncate,
+        }
+    }
+}
+
+/// A transition table of `REFERENCING`, e.g. `NEW TABLE AS inserted`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TransitionTable {
+    pub name: String,
+    /// Whether the table has the new rows rather than the old ones
+    pub is_new: bool,
+}
+
+/// The name of a table or function, with its schema if given.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct QualifiedName {
+    pub schema: Option<String>,
+    pub name: String,
+}
+
+impl QualifiedName {
+    fn from_range_var(relation: &protobuf::RangeVar) -> Self {
+        let schema = Some(relation.schemaname.clone()).filter(|schema| !schema.is_empty());
+        Self { schema, name: relation.relname.clone() }
+    }
+
+    /// Returns the name from the `String` nodes of a qualified name, e.g. the `funcname` of a `FuncCall`.
+    fn from_strings(nodes: &[Node]) -> Self {
+        let mut names = strings(nodes);
+        let name = names.pop().unwrap_or_default();
+        Self { schema: names.pop(), name }
+    }
+
+    fn range_var(&self) -> protobuf::RangeVar {
+        protobuf::RangeVar {
+            schemaname: self.schema.clone().unwrap_or_default(),
+            relname: self.name.clone(),
+            inh: true,
+            relpersistence: "p".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn string_nodes(&self) -> Vec<Node> {
+        string_nodes(self.schema.iter().chain([&self.name]))
+    }
+}
+
+impl fmt::Display for QualifiedName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.schema {
+            Some(schema) => write!(f, "{schema}.{}", self.name),
+            None => f.write_str(&self.name),
+        }
+    }
+}
+
+/// A trigger, as defined by `CREATE TRIGGER`. See [ParseResult::triggers].
+///
+/// Converting a definition into a [protobuf::CreateTrigStmt] builds the statement back, so definitions can
+/// also be written from scratch, starting from [TriggerDefinition::default].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TriggerDefinition {
+    pub name: String,
+    /// The table or view the trigger is on
+    pub relation: QualifiedName,
+    pub timing: TriggerTiming,
+    pub events: BTreeSet<TriggerEvent>,
+    /// The columns of `UPDATE OF`, if given
+    pub update_columns: Vec<String>,
+    /// Whether the trigger fires `FOR EACH ROW` rather than `FOR EACH STATEMENT`
+    pub for_each_row: bool,
+    /// The condition of `WHEN`
+    pub when: Option<Node>,
+    pub transition_tables: Vec<TransitionTable>,
+    /// The function the trigger executes
+    pub function: QualifiedName,
+    /// The arguments of the function, which are always string literals
+    pub arguments: Vec<String>,
+    /// `CREATE OR REPLACE TRIGGER`
+    pub or_replace: bool,
+    /// `CREATE CONSTRAINT TRIGGER`
+    pub constraint: bool,
+    pub deferrable: bool,
+    pub initially_deferred: bool,
+    /// The table of `FROM` in a constraint trigger
+    pub referenced_table: Option<QualifiedName>,
+}
+
+impl TriggerDefinition {
+    /// Returns the `CREATE TRIGGER` statement of the trigger.
+    pub fn deparse(&self) -> Result<String> {
+        Node { node: Some(NodeEnum::CreateTrigStmt(Box::new(self.into()))) }.deparse()
+    }
+}
+
+impl From<&protobuf::CreateTrigStmt> for TriggerDefinition {
+    fn from(stmt: &protobuf::CreateTrigStmt) -> Self {
+        let timing = if stmt.timing & TriggerType::Before as i32 != 0 {
+            TriggerTiming::Before
+        } else if stmt.timing & TriggerType::Instead as i32 != 0 {
+            TriggerTiming::InsteadOf
+        } else {
+            TriggerTiming::After
+        };
+        let transition_tables = stmt
+            .transition_rels
+            .iter()
+            .filter_map(|node| match &node.node {
+                Some(NodeEnum::TriggerTransition(transition)) => Some(TransitionTable { name: transition.name.clone(), is_new: transition.is_new }),
+                _ => None,
+            })
+            .collect();
+        Self {
+            name: stmt.trigname.clone(),
+            relation: stmt.relation.as_ref().map(QualifiedName::from_range_var).unwrap_or_default(),
+            timing,
+            events: TriggerEvent::ALL.into_iter().filter(|event| stmt.events & event.trigger_type() as i32 != 0).collect(),
+            update_columns: strings(&stmt.columns),
+            for_each_row: stmt.row,
+            when: stmt.when_clause.as_deref().cloned(),
+            transition_tables,
+            function: QualifiedName::from_strings(&stmt.funcname),
+            arguments: strings(&stmt.args),
+            or_replace: stmt.replace,
+            constraint: stmt.isconstraint,
+            deferrable: stmt.deferrable,
+            initially_deferred: stmt.initdeferred,
+            referenced_table: stmt.constrrel.as_ref().map(QualifiedName::from_range_var),
+        }
+    }
+}
+
+impl From<&TriggerDefinition> for protobuf::CreateTrigStmt {
+    fn from(trigger: &TriggerDefinition) -> Self {
+        let timing = match trigger.timing {
+            TriggerTiming::Before => TriggerType::Before as i32,
+            TriggerTiming::After => 0,
+            TriggerTiming::InsteadOf => TriggerType::Instead as i32,
+        };
+        let transition_rels = trigger
+            .transition_tables
+            .iter()
+            .map(|table| Node {
+                node: Some(NodeEnum::TriggerTransition(protobuf::TriggerTransition {
+                    name: table.name.clone(),
+                    is_new: table.is_new,
+                    is_table: true,
+                })),
+            })
+            .collect();
+        Self {
+            replace: trigger.or_replace,
+            isconstraint: trigger.constraint,
+            trigname: trigger.name.clone(),
+            relation: Some(trigger.relation.range_var()),
+            funcname: trigger.function.string_nodes(),
+            args: string_nodes(&trigger.arguments),
+            row: trigger.for_each_row,
+            timing,
+            events: trigger.events.iter().fold(0, |events, event| events | event.trigger_type() as i32),
+            columns: string_nodes(&trigger.update_columns),
+            when_clause: trigger.when.clone().map(Box::new),
+            transition_rels,
+            deferrable: trigger.deferrable,
+            initdeferred: trigger.initially_deferred,
+            constrrel: trigger.referenced_table.as_ref().map(QualifiedName::range_var),
+        }
+    }
+}
+
+impl protobuf::ParseResult {
+    /// Returns the triggers the statements create. See [ParseResult::triggers].
+    pub fn triggers(&self) -> Vec<TriggerDefinition> {
+        self.nodes()
+            .into_iter()
+            .filter_map(|(node, ..)| match node {
+                NodeRef::CreateTrigStmt(stmt) => Some(stmt.into()),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl ParseResult {
+    /// Returns the triggers the statements create, including those in `CREATE SCHEMA`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pg_query::{TriggerEvent, TriggerTiming};
+    ///
+    /// let result = pg_query::parse("CREATE TRIGGER a AFTER INSERT OR UPDATE OF email ON users FOR EACH ROW EXECUTE FUNCTION log('users')").unwrap();
+    /// let mut trigger = result.triggers().remove(0);
+    /// assert_eq!((trigger.timing, trigger.relation.name.as_str(), trigger.function.to_string()), (TriggerTiming::After, "users", "log".to_string()));
+    /// assert_eq!(trigger.events.iter().collect::<Vec<_>>(), [&TriggerEvent::Insert, &TriggerEvent::Update]);
+    /// assert_eq!(trigger.update_columns, ["email"]);
+    /// assert_eq!(trigger.arguments, ["users"]);
+    ///
+    /// trigger.update_columns = Vec::new();
+    /// trigger.when = Some(pg_query::parse_expression("new.email IS NOT NULL").unwrap());
+    /// let sql = trigger.deparse().unwrap();
+    /// assert!(sql.contains("WHEN (new.email IS NOT NULL)"));
+    /// assert_eq!(pg_query::parse(&sql).unwrap().triggers()[0].deparse().unwrap(), sql);
+    /// ```
+    pub fn triggers(&self) -> Vec<TriggerDefinition> {
+        self.protobuf.triggers()
+    }
+}
+
+fn string_nodes<S: AsRef<str>>(values: impl IntoIterator<Item = S>) -> Vec<Node> {
+    values.into_iter().map(|sval| Node { node: Some(NodeEnum::String(protobuf::String { sval: sval.as_ref().to_string() })) }).collect()
+}
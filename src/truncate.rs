@@ -1,4 +1,5 @@
 use std::cmp::Ordering;
+use std::collections::HashMap;
 
 use crate::*;
 
@@ -14,7 +15,8 @@ enum TruncationAttr {
 #[derive(Debug)]
 struct PossibleTruncation {
     attr: TruncationAttr,
-    node: NodeMut,
+    /// Path of the node holding the attribute
+    path: NodePath,
     depth: i32,
     length: i32,
 }
@@ -25,237 +27,89 @@ pub fn truncate(protobuf: &protobuf::ParseResult, max_length: usize) -> Result<S
         return Ok(output);
     }
 
-    // SAFETY: within this scope nobody expects to have exclusive access to `protobuf`'s contents, so we can have multiple shared accesses.
-    //
-    // Raw pointer documentation:
-    //
-    // https://doc.rust-lang.org/book/ch19-01-unsafe-rust.html#dereferencing-a-raw-pointer
-    // https://doc.rust-lang.org/std/primitive.pointer.html
-    // https://manishearth.github.io/blog/2015/05/17/the-problem-with-shared-mutability
-    // https://ricardomartins.cc/2016/07/11/interior-mutability-behind-the-curtain
-    unsafe {
-        let mut protobuf = protobuf.clone();
-        let mut truncations: Vec<PossibleTruncation> = Vec::new();
-        for (node, depth, _context) in protobuf.nodes_mut().into_iter() {
-            match node {
-                NodeMut::SelectStmt(s) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    if !s.target_list.is_empty() {
-                        truncations.push(PossibleTruncation {
-                            attr: TruncationAttr::TargetList,
-                            node,
-                            depth,
-                            length: select_target_list_len(s.target_list.clone())?,
-                        });
-                    }
-                    if let Some(clause) = s.where_clause.as_ref() {
-                        truncations.push(PossibleTruncation {
-                            attr: TruncationAttr::WhereClause,
-                            node,
-                            depth,
-                            length: where_clause_len((*clause).clone())?,
-                        });
-                    }
-                    if !s.values_lists.is_empty() {
-                        truncations.push(PossibleTruncation {
-                            attr: TruncationAttr::ValuesLists,
-                            node,
-                            depth,
-                            length: select_values_lists_len(s.values_lists.clone())?,
-                        });
-                    }
+    // nodes() decides which nodes can be truncated and how deep they are, iter_nodes() where they are
+    let paths: HashMap<usize, NodePath> = protobuf.iter_nodes().filter_map(|(path, node)| Some((address(node)?, path))).collect();
+    let mut truncations: Vec<PossibleTruncation> = Vec::new();
+    for (node, depth, _context, _) in protobuf.nodes().into_iter() {
+        let Some(path) = address(node).and_then(|address| paths.get(&address)) else { continue };
+        let mut push = |attr, depth, length| truncations.push(PossibleTruncation { attr, path: path.clone(), depth, length });
+        match node {
+            NodeRef::SelectStmt(s) => {
+                if !s.target_list.is_empty() {
+                    push(TruncationAttr::TargetList, depth, select_target_list_len(s.target_list.clone())?);
                 }
-                NodeMut::UpdateStmt(s) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    if !s.target_list.is_empty() {
-                        truncations.push(PossibleTruncation {
-                            attr: TruncationAttr::TargetList,
-                            node,
-                            depth,
-                            length: update_target_list_len(s.target_list.clone())?,
-                        });
-                    }
-                    if let Some(clause) = s.where_clause.as_ref() {
-                        truncations.push(PossibleTruncation {
-                            attr: TruncationAttr::WhereClause,
-                            node,
-                            depth,
-                            length: where_clause_len((*clause).clone())?,
-                        });
-                    }
+                if let Some(clause) = s.where_clause.as_ref() {
+                    push(TruncationAttr::WhereClause, depth, where_clause_len(clause.clone())?);
                 }
-                NodeMut::DeleteStmt(s) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    if let Some(clause) = s.where_clause.as_ref() {
-                        truncations.push(PossibleTruncation {
-                            attr: TruncationAttr::WhereClause,
-                            node,
-                            depth,
-                            length: where_clause_len((*clause).clone())?,
-                        });
-                    }
+                if !s.values_lists.is_empty() {
+                    push(TruncationAttr::ValuesLists, depth, select_values_lists_len(s.values_lists.clone())?);
                 }
-                NodeMut::CopyStmt(s) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    if let Some(clause) = s.where_clause.as_ref() {
-                        truncations.push(PossibleTruncation {
-                            attr: TruncationAttr::WhereClause,
-                            node,
-                            depth,
-                            length: where_clause_len((*clause).clone())?,
-                        });
-                    }
+            }
+            NodeRef::UpdateStmt(s) => {
+                if !s.target_list.is_empty() {
+                    push(TruncationAttr::TargetList, depth, update_target_list_len(s.target_list.clone())?);
                 }
-                NodeMut::InsertStmt(s) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    if !s.cols.is_empty() {
-                        truncations.push(PossibleTruncation { attr: TruncationAttr::Cols, node, depth, length: cols_len(s.cols.clone())? });
-                    }
+                if let Some(clause) = s.where_clause.as_ref() {
+                    push(TruncationAttr::WhereClause, depth, where_clause_len(clause.clone())?);
                 }
-                NodeMut::IndexStmt(s) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    if let Some(clause) = s.where_clause.as_ref() {
-                        truncations.push(PossibleTruncation {
-                            attr: TruncationAttr::WhereClause,
-                            node,
-                            depth,
-                            length: where_clause_len((*clause).clone())?,
-                        });
-                    }
+            }
+            NodeRef::InsertStmt(s) => {
+                if !s.cols.is_empty() {
+                    push(TruncationAttr::Cols, depth, cols_len(s.cols.clone())?);
                 }
-                NodeMut::RuleStmt(s) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    if let Some(clause) = s.where_clause.as_ref() {
-                        truncations.push(PossibleTruncation {
-                            attr: TruncationAttr::WhereClause,
-                            node,
-                            depth,
-                            length: where_clause_len((*clause).clone())?,
-                        });
-                    }
+            }
+            NodeRef::CommonTableExpr(s) => {
+                if let Some(cte) = s.ctequery.as_ref() {
+                    push(TruncationAttr::CTEQuery, depth + 1, cte.deparse()?.len() as i32);
                 }
-                NodeMut::CommonTableExpr(s) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    if let Some(cte) = s.ctequery.as_ref() {
-                        truncations.push(PossibleTruncation {
-                            attr: TruncationAttr::CTEQuery,
-                            node,
-                            depth: depth + 1,
-                            length: cte.deparse()?.len() as i32,
-                        });
-                    }
+            }
+            NodeRef::OnConflictClause(s) => {
+                if !s.target_list.is_empty() {
+                    push(TruncationAttr::TargetList, depth, update_target_list_len(s.target_list.clone())?);
                 }
-                NodeMut::InferClause(s) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    if let Some(clause) = s.where_clause.as_ref() {
-                        truncations.push(PossibleTruncation {
-                            attr: TruncationAttr::WhereClause,
-                            node,
-                            depth,
-                            length: where_clause_len((*clause).clone())?,
-                        });
-                    }
+                if let Some(clause) = s.where_clause.as_ref() {
+                    push(TruncationAttr::WhereClause, depth, where_clause_len(clause.clone())?);
                 }
-                NodeMut::OnConflictClause(s) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    if !s.target_list.is_empty() {
-                        truncations.push(PossibleTruncation {
-                            attr: TruncationAttr::TargetList,
-                            node,
-                            depth,
-                            length: update_target_list_len(s.target_list.clone())?,
-                        });
-                    }
-                    if let Some(clause) = s.where_clause.as_ref() {
-                        truncations.push(PossibleTruncation {
-                            attr: TruncationAttr::WhereClause,
-                            node,
-                            depth,
-                            length: where_clause_len((*clause).clone())?,
-                        });
-                    }
+            }
+            _ => {
+                let where_clause = match node {
+                    NodeRef::DeleteStmt(s) => &s.where_clause,
+                    NodeRef::CopyStmt(s) => &s.where_clause,
+                    NodeRef::IndexStmt(s) => &s.where_clause,
+                    NodeRef::RuleStmt(s) => &s.where_clause,
+                    NodeRef::InferClause(s) => &s.where_clause,
+                    _ => &None,
+                };
+                if let Some(clause) = where_clause {
+                    push(TruncationAttr::WhereClause, depth, where_clause_len(clause.clone())?);
                 }
-                _ => (),
             }
         }
+    }
 
-        truncations.sort_by(|a, b| match a.depth.cmp(&b.depth).reverse() {
-            Ordering::Equal => a.length.cmp(&b.length).reverse(),
-            other => other,
-        });
+    truncations.sort_by(|a, b| match a.depth.cmp(&b.depth).reverse() {
+        Ordering::Equal => a.length.cmp(&b.length).reverse(),
+        other => other,
+    });
 
-        while !truncations.is_empty() {
-            let truncation = truncations.remove(0);
-            match (truncation.node, truncation.attr) {
-                (NodeMut::SelectStmt(s), TruncationAttr::TargetList) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    s.target_list = vec![dummy_target()];
-                }
-                (NodeMut::SelectStmt(s), TruncationAttr::WhereClause) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    s.where_clause = Some(dummy_column());
-                }
-                (NodeMut::SelectStmt(s), TruncationAttr::ValuesLists) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    s.values_lists = vec![Node { node: Some(NodeEnum::List(protobuf::List { items: vec![*dummy_column()] })) }]
-                }
-                (NodeMut::UpdateStmt(s), TruncationAttr::TargetList) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    s.target_list = vec![dummy_target()];
-                }
-                (NodeMut::UpdateStmt(s), TruncationAttr::WhereClause) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    s.where_clause = Some(dummy_column());
-                }
-                (NodeMut::DeleteStmt(s), TruncationAttr::WhereClause) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    s.where_clause = Some(dummy_column());
-                }
-                (NodeMut::CopyStmt(s), TruncationAttr::WhereClause) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    s.where_clause = Some(dummy_column());
-                }
-                (NodeMut::InsertStmt(s), TruncationAttr::Cols) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    s.cols = vec![dummy_target()];
-                }
-                (NodeMut::IndexStmt(s), TruncationAttr::WhereClause) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    s.where_clause = Some(dummy_column());
-                }
-                (NodeMut::RuleStmt(s), TruncationAttr::WhereClause) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    s.where_clause = Some(dummy_column());
-                }
-                (NodeMut::CommonTableExpr(s), TruncationAttr::CTEQuery) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    let old = s.ctequery.replace(dummy_select(vec![], Some(dummy_column()), vec![]));
-                    if let Some(s) = old {
-                        let node = s.node.ok_or(Error::InvalidPointer)?;
-                        truncations.retain(|t| t.node.to_enum().unwrap() != node);
-                    }
-                }
-                (NodeMut::InferClause(s), TruncationAttr::WhereClause) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    s.where_clause = Some(dummy_column());
-                }
-                (NodeMut::OnConflictClause(s), TruncationAttr::TargetList) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    s.target_list = vec![dummy_target()];
-                }
-                (NodeMut::OnConflictClause(s), TruncationAttr::WhereClause) => {
-                    let s = s.as_mut().ok_or(Error::InvalidPointer)?;
-                    s.where_clause = Some(dummy_column());
-                }
-                _ => panic!("unimplemented truncation"),
-            }
-            output = protobuf.deparse()?;
-            output = output.replace("SELECT WHERE \"…\"", "...").replace("\"…\"", "...");
-            // the unwanted AS doesn't happen in the Ruby version. I'm not sure where it's coming from
-            output = output.replace("SELECT ... AS ...", "SELECT ...");
-            if output.len() <= max_length {
-                return Ok(output);
-            }
+    let mut protobuf = protobuf.clone();
+    while !truncations.is_empty() {
+        let truncation = truncations.remove(0);
+        let Some(node) = protobuf.get_by_path(&truncation.path) else { continue };
+        let mut node = node.to_enum();
+        truncate_attr(&mut node, &truncation.attr);
+        protobuf.replace_at_path(&truncation.path, node)?;
+        if let TruncationAttr::CTEQuery = truncation.attr {
+            // The nodes of the replaced query are gone
+            let query = truncation.path.join("ctequery", None);
+            truncations.retain(|t| !t.path.segments().starts_with(&query.segments()));
+        }
+        output = protobuf.deparse()?;
+        output = output.replace("SELECT WHERE \"…\"", "...").replace("\"…\"", "...");
+        // the unwanted AS doesn't happen in the Ruby version. I'm not sure where it's coming from
+        output = output.replace("SELECT ... AS ...", "SELECT ...");
+        if output.len() <= max_length {
+            return Ok(output);
         }
     }
 
@@ -263,6 +117,49 @@ pub fn truncate(protobuf: &protobuf::ParseResult, max_length: usize) -> Result<S
     Ok(format!("{}...", truncate_str(&output, max_length - 3)))
 }
 
+/// Returns the address of a node that can be truncated, to find its path while the tree is borrowed.
+fn address(node: NodeRef) -> Option<usize> {
+    fn of<T>(node: &T) -> Option<usize> {
+        Some(node as *const T as usize)
+    }
+    match node {
+        NodeRef::SelectStmt(s) => of(s),
+        NodeRef::UpdateStmt(s) => of(s),
+        NodeRef::DeleteStmt(s) => of(s),
+        NodeRef::CopyStmt(s) => of(s),
+        NodeRef::InsertStmt(s) => of(s),
+        NodeRef::IndexStmt(s) => of(s),
+        NodeRef::RuleStmt(s) => of(s),
+        NodeRef::CommonTableExpr(s) => of(s),
+        NodeRef::InferClause(s) => of(s),
+        NodeRef::OnConflictClause(s) => of(s),
+        _ => None,
+    }
+}
+
+/// Replaces an attribute of a node with a placeholder.
+fn truncate_attr(node: &mut NodeEnum, attr: &TruncationAttr) {
+    match (node, attr) {
+        (NodeEnum::SelectStmt(s), TruncationAttr::TargetList) => s.target_list = vec![dummy_target()],
+        (NodeEnum::SelectStmt(s), TruncationAttr::WhereClause) => s.where_clause = Some(dummy_column()),
+        (NodeEnum::SelectStmt(s), TruncationAttr::ValuesLists) => {
+            s.values_lists = vec![Node { node: Some(NodeEnum::List(protobuf::List { items: vec![*dummy_column()] })) }]
+        }
+        (NodeEnum::UpdateStmt(s), TruncationAttr::TargetList) => s.target_list = vec![dummy_target()],
+        (NodeEnum::UpdateStmt(s), TruncationAttr::WhereClause) => s.where_clause = Some(dummy_column()),
+        (NodeEnum::InsertStmt(s), TruncationAttr::Cols) => s.cols = vec![dummy_target()],
+        (NodeEnum::CommonTableExpr(s), TruncationAttr::CTEQuery) => s.ctequery = Some(dummy_select(vec![], Some(dummy_column()), vec![])),
+        (NodeEnum::OnConflictClause(s), TruncationAttr::TargetList) => s.target_list = vec![dummy_target()],
+        (NodeEnum::OnConflictClause(s), TruncationAttr::WhereClause) => s.where_clause = Some(dummy_column()),
+        (NodeEnum::DeleteStmt(s), TruncationAttr::WhereClause) => s.where_clause = Some(dummy_column()),
+        (NodeEnum::CopyStmt(s), TruncationAttr::WhereClause) => s.where_clause = Some(dummy_column()),
+        (NodeEnum::IndexStmt(s), TruncationAttr::WhereClause) => s.where_clause = Some(dummy_column()),
+        (NodeEnum::RuleStmt(s), TruncationAttr::WhereClause) => s.where_clause = Some(dummy_column()),
+        (NodeEnum::InferClause(s), TruncationAttr::WhereClause) => s.where_clause = Some(dummy_column()),
+        _ => panic!("unimplemented truncation"),
+    }
+}
+
 // Truncates at character boundaries to prevent panics.
 fn truncate_str(string: &str, max_chars: usize) -> &str {
     match string.char_indices().nth(max_chars) {
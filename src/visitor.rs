@@ -0,0 +1,10 @@
+//! Traversal of every node in a parse tree.
+//!
+//! [Visitor] has a `visit_*` method for each message in [crate::protobuf], and this module a `walk_*`
+//! function for each that visits the message's children. [VisitorMut] and the `walk_*_mut` functions
+//! do the same with mutable references, for rewriting trees. All of them are generated from
+//! `protobuf.rs` by the build script, so they stay in sync with the node types of the parser.
+
+use crate::protobuf;
+
+include!(concat!(env!("OUT_DIR"), "/visitor.rs"));
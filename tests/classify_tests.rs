@@ -0,0 +1,86 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse, Classification, ClassificationReason, StatementClass};
+
+#[macro_use]
+mod support;
+
+fn classes(query: &str) -> Vec<StatementClass> {
+    parse(query).unwrap().classify().into_iter().map(|classification| classification.class).collect()
+}
+
+fn classification(query: &str) -> Classification {
+    parse(query).unwrap().classify().remove(0)
+}
+
+#[test]
+fn it_classifies_statement_types() {
+    use StatementClass::*;
+    assert_eq!(classes("SELECT 1; SHOW work_mem; COPY t TO STDOUT; EXPLAIN DELETE FROM t"), [ReadOnly, ReadOnly, ReadOnly, ReadOnly]);
+    assert_eq!(
+        classes("INSERT INTO t VALUES (1); UPDATE t SET a = 1; DELETE FROM t; TRUNCATE t; COPY t FROM STDIN; CALL p(); NOTIFY c"),
+        [Write, Write, Write, Write, Write, Write, Write]
+    );
+    assert_eq!(classes("CREATE TABLE t (a int); ALTER TABLE t ADD b int; DROP TABLE t; GRANT SELECT ON t TO u"), [Ddl, Ddl, Ddl, Ddl]);
+    assert_eq!(classes("BEGIN; SAVEPOINT s; COMMIT; SET CONSTRAINTS ALL DEFERRED"), [Transaction, Transaction, Transaction, Transaction]);
+    assert_eq!(
+        classes("SET search_path = app; PREPARE p AS SELECT 1; EXECUTE p; LISTEN c; DISCARD ALL"),
+        [Session, Session, Session, Session, Session]
+    );
+    assert_eq!(classes("VACUUM t; CHECKPOINT; REINDEX TABLE t"), [Utility, Utility, Utility]);
+}
+
+#[test]
+fn it_finds_writes_hidden_in_queries() {
+    let result = classification("WITH x AS (DELETE FROM jobs RETURNING *) SELECT * FROM x");
+    assert_eq!(result.class, StatementClass::Write);
+    assert_eq!(
+        result.reasons,
+        [ClassificationReason::Statement("SelectStmt"), ClassificationReason::DataModifyingCte("stmts[0].with_clause.ctes[0]".parse().unwrap())]
+    );
+
+    let result = classification("SELECT * FROM t WHERE id = 1 FOR UPDATE");
+    assert_eq!(result.class, StatementClass::Write);
+    assert_eq!(result.reasons[1], ClassificationReason::RowLock("stmts[0]".parse().unwrap()));
+
+    let result = classification("SELECT a, nextval('s') FROM t");
+    assert_eq!(result.class, StatementClass::Write);
+    assert_eq!(result.reasons[1], ClassificationReason::Function("nextval".into(), "stmts[0].target_list[1].val".parse().unwrap()));
+
+    let result = classification("SELECT * INTO t_copy FROM t");
+    assert_eq!((result.class, &result.reasons[1]), (StatementClass::Ddl, &ClassificationReason::SelectInto("stmts[0]".parse().unwrap())));
+
+    // Session state changes only matter to otherwise read-only statements
+    assert_eq!(
+        classes("SELECT pg_advisory_lock(1); SELECT set_config('a.b', 'c', false); INSERT INTO t VALUES (nextval('s'))"),
+        [StatementClass::Session, StatementClass::Session, StatementClass::Write]
+    );
+    assert!(classification("SELECT lower(name) FROM t").is_read_only());
+}
+
+#[test]
+fn it_classifies_EXPLAIN_ANALYZE_as_its_statement() {
+    let result = classification("EXPLAIN ANALYZE UPDATE t SET a = 1");
+    assert_eq!(result.class, StatementClass::Write);
+    assert_eq!(
+        result.reasons,
+        [ClassificationReason::Statement("ExplainStmt"), ClassificationReason::ExplainAnalyze, ClassificationReason::Statement("UpdateStmt")]
+    );
+    assert_eq!(classification("EXPLAIN (ANALYZE, BUFFERS) SELECT 1").class, StatementClass::ReadOnly);
+    assert_eq!(classification("EXPLAIN (ANALYZE false) DELETE FROM t").reasons, [ClassificationReason::Statement("ExplainStmt")]);
+    assert_eq!(classification("EXPLAIN SELECT * FROM t FOR UPDATE").class, StatementClass::ReadOnly);
+}
+
+#[test]
+fn it_classifies_COPY_TO_as_its_query() {
+    let result = classification("COPY (DELETE FROM t RETURNING *) TO STDOUT");
+    assert_eq!(result.class, StatementClass::Write);
+    assert_eq!(result.reasons, [ClassificationReason::Statement("CopyStmt"), ClassificationReason::Statement("DeleteStmt")]);
+    assert_eq!(classification("COPY (INSERT INTO t VALUES (1) RETURNING id) TO STDOUT").class, StatementClass::Write);
+    assert_eq!(
+        classification("COPY (SELECT a FROM t) TO STDOUT").reasons,
+        [ClassificationReason::Statement("CopyStmt"), ClassificationReason::Statement("SelectStmt")]
+    );
+    assert_eq!(classification("COPY (SELECT nextval('s')) TO STDOUT").class, StatementClass::Write);
+}
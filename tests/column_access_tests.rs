@@ -0,0 +1,70 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use std::collections::BTreeSet;
+
+use pg_query::parse;
+
+#[macro_use]
+mod support;
+
+/// Returns the columns read and written per table, as `table: read [columns], written [columns]`, with
+/// ` + *` for all columns.
+fn access(query: &str) -> Vec<String> {
+    let columns = |columns: BTreeSet<String>, all: bool| format!("[{}]{}", Vec::from_iter(columns).join(", "), if all { " + *" } else { "" });
+    parse(query)
+        .unwrap()
+        .column_access()
+        .into_iter()
+        .map(|(table, access)| {
+            format!("{table}: read {}, written {}", columns(access.read, access.read_all), columns(access.written, access.written_all))
+        })
+        .collect()
+}
+
+#[test]
+fn it_finds_read_columns() {
+    assert_eq!(
+        access("SELECT u.name, o.* FROM users u JOIN orders o ON o.user_id = u.id WHERE u.active ORDER BY u.created_at"),
+        ["orders: read [user_id] + *, written []", "users: read [active, created_at, id, name], written []"]
+    );
+    // Through subqueries and CTEs, to every table that may have an ambiguous column
+    assert_eq!(
+        access("WITH c AS (SELECT id, amount FROM payments) SELECT s.n, c.amount, code FROM (SELECT name AS n FROM users) s, c, countries"),
+        ["countries: read [code], written []", "payments: read [amount, id], written []", "users: read [name], written []"]
+    );
+    assert_eq!(access("DELETE FROM t WHERE id = 1"), ["t: read [id], written []"]);
+}
+
+#[test]
+fn it_finds_written_columns() {
+    assert_eq!(
+        access("INSERT INTO t (a, b) SELECT x, y FROM s ON CONFLICT (a) DO UPDATE SET b = excluded.b RETURNING a"),
+        ["s: read [x, y], written []", "t: read [a], written [a, b]"]
+    );
+    assert_eq!(access("INSERT INTO t VALUES (1)"), ["t: read [], written [] + *"]);
+    assert_eq!(access("INSERT INTO t DEFAULT VALUES"), ["t: read [], written []"]);
+    assert_eq!(access("UPDATE t SET (a, b) = (1, 2), c = d WHERE id = 1"), ["t: read [d, id], written [a, b, c]"]);
+    assert_eq!(
+        access("MERGE INTO t USING s ON t.id = s.id WHEN MATCHED THEN UPDATE SET v = s.v WHEN NOT MATCHED THEN INSERT VALUES (s.id, s.v)"),
+        ["s: read [id, v], written []", "t: read [id], written [v] + *"]
+    );
+}
+
+#[test]
+fn it_finds_COPY_columns() {
+    assert_eq!(access("COPY t (a, b) FROM STDIN"), ["t: read [], written [a, b]"]);
+    assert_eq!(access("COPY t FROM STDIN"), ["t: read [], written [] + *"]);
+    assert_eq!(access("COPY t TO STDOUT"), ["t: read [] + *, written []"]);
+    assert_eq!(access("COPY (SELECT a FROM t) TO STDOUT"), ["t: read [a], written []"]);
+}
+
+#[test]
+fn it_reads_all_columns_of_whole_row_references() {
+    assert_eq!(
+        access("SELECT u, row_to_json(u), to_jsonb(o) FROM users u JOIN orders o ON o.user_id = u.id"),
+        ["orders: read [user_id] + *, written []", "users: read [id] + *, written []"]
+    );
+    // Unless the entry is known to have a column of that name
+    assert_eq!(access("SELECT s FROM (SELECT a AS s FROM t) s"), ["t: read [a], written []"]);
+}
@@ -6,9 +6,13 @@ use pg_query::parse;
 #[cfg(test)]
 use regex::Regex;
 
+mod support;
+use support::assert_json_roundtrip;
+
 fn assert_deparse(input: &str, output: &str) {
     let result = parse(input).unwrap();
     assert_eq!(result.deparse().unwrap(), output);
+    assert_json_roundtrip(input);
 }
 
 fn oneline(query: &str) -> String {
@@ -0,0 +1,70 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse, Error, NodeEnum, ParseResult};
+
+#[macro_use]
+mod support;
+use support::assert_json_roundtrip;
+
+#[test]
+fn it_tags_nodes_with_their_type() {
+    let result = parse("SELECT a FROM t").unwrap();
+    let json: serde_json::Value = serde_json::from_str(&result.to_json()).unwrap();
+    let select = &json["stmts"][0]["stmt"]["node"]["SelectStmt"];
+    assert_eq!(select["target_list"][0]["node"]["ResTarget"]["val"]["node"]["ColumnRef"]["fields"][0]["node"]["String"]["sval"], "a");
+    assert_eq!(select["from_clause"][0]["node"]["RangeVar"]["relname"], "t");
+    assert_eq!(select["op"], 1);
+}
+
+#[test]
+fn it_loads_trees_from_json() {
+    let query = "INSERT INTO contacts (id, name) SELECT id, name FROM people WHERE created_at > now() RETURNING id";
+    let result = parse(query).unwrap();
+    let loaded = ParseResult::from_json(&result.to_json()).unwrap();
+    assert_eq!(loaded.protobuf, result.protobuf);
+    assert_eq!(loaded.tables, result.tables);
+    assert_eq!(loaded.functions, result.functions);
+    assert_eq!(loaded.deparse().unwrap(), result.deparse().unwrap());
+    assert_eq!(loaded.statement_types(), ["InsertStmt"]);
+}
+
+#[test]
+fn it_fills_in_missing_fields() {
+    let json = r#"{"stmts":[{"stmt":{"node":{"SelectStmt":{"target_list":[{"node":{"ResTarget":{"val":{"node":{"AConst":{"val":{"Ival":{"ival":1}}}}}}}}],"op":1}}}}]}"#;
+    let loaded = ParseResult::from_json(json).unwrap();
+    assert_eq!(loaded.protobuf.version, 0);
+    assert!(matches!(loaded.protobuf.stmts[0].stmt.as_ref().unwrap().node, Some(NodeEnum::SelectStmt(_))));
+    assert_eq!(loaded.deparse().unwrap(), "SELECT 1");
+}
+
+#[test]
+fn it_rejects_invalid_json() {
+    assert!(matches!(ParseResult::from_json("{\"stmts\": [}"), Err(Error::InvalidJson(_))));
+    assert!(matches!(ParseResult::from_json(r#"{"stmts":[{"stmt":{"node":{"NotANode":{}}}}]}"#), Err(Error::InvalidJson(_))));
+    assert!(matches!(ParseResult::from_json("{} {}"), Err(Error::InvalidJson(_))));
+}
+
+#[test]
+fn it_roundtrips_deeply_nested_expressions() {
+    // Well beyond serde_json's default limit of 128 nested objects
+    assert_json_roundtrip(&format!("SELECT 1{}", " + 1".repeat(100)));
+}
+
+#[test]
+fn it_roundtrips_statements() {
+    for query in [
+        "SELECT DISTINCT ON (a) a, b FROM t WHERE c IN (SELECT d FROM u) ORDER BY a, b DESC NULLS LAST LIMIT 10 FOR UPDATE",
+        "WITH RECURSIVE r AS (SELECT 1 AS n UNION ALL SELECT n + 1 FROM r WHERE n < 10) SELECT * FROM r",
+        "UPDATE accounts SET balance = balance - $1 FROM transfers WHERE transfers.account_id = accounts.id",
+        "DELETE FROM sessions USING users WHERE sessions.user_id = users.id AND users.deleted",
+        "MERGE INTO t USING s ON t.id = s.id WHEN MATCHED THEN UPDATE SET v = s.v WHEN NOT MATCHED THEN INSERT VALUES (s.id, s.v)",
+        "CREATE TABLE IF NOT EXISTS events (id bigserial PRIMARY KEY, payload jsonb NOT NULL DEFAULT '{}', created_at timestamptz)",
+        "ALTER TABLE events ADD CONSTRAINT fk FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE",
+        "CREATE INDEX CONCURRENTLY ON events USING gin (payload jsonb_path_ops) WHERE created_at > '2020-01-01'",
+        "SELECT x::numeric(10, 2), CASE WHEN y THEN 'a' ELSE NULL END, array_agg(z) FILTER (WHERE z > 0) OVER (PARTITION BY w) FROM t",
+        "BEGIN; SET LOCAL statement_timeout = '5s'; COMMIT",
+    ] {
+        assert_json_roundtrip(query);
+    }
+}
@@ -0,0 +1,72 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse, parse_json, Error, ParseResult};
+
+#[macro_use]
+mod support;
+
+fn assert_libpg_query_json_roundtrip(query: &str) {
+    let json = parse_json(query).unwrap();
+    let loaded = ParseResult::from_libpg_query_json(&json).unwrap();
+    assert_eq!(loaded.protobuf, parse(query).unwrap().protobuf);
+}
+
+#[test]
+fn it_produces_libpg_query_json() {
+    let json = parse_json("SELECT a FROM t WHERE b = 1").unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["version"], pg_query::PG_VERSION_NUM);
+    let select = &value["stmts"][0]["stmt"]["SelectStmt"];
+    assert_eq!(select["targetList"][0]["ResTarget"]["val"]["ColumnRef"]["fields"][0]["String"]["sval"], "a");
+    assert_eq!(select["fromClause"][0]["RangeVar"]["relname"], "t");
+    assert_eq!(select["whereClause"]["A_Expr"]["kind"], "AEXPR_OP");
+    assert_eq!(select["whereClause"]["A_Expr"]["rexpr"]["A_Const"]["ival"]["ival"], 1);
+    assert_eq!(select["op"], "SETOP_NONE");
+}
+
+#[test]
+fn it_reports_syntax_errors() {
+    let error = parse_json("SELECT * FORM t").err().unwrap();
+    assert_eq!(cast!(error, Error::Syntax).message, "syntax error at or near \"FORM\"");
+}
+
+#[test]
+fn it_reads_libpg_query_json() {
+    // As written by pg_query for Ruby
+    let json = r#"{"version":170004,"stmts":[{"stmt":{"InsertStmt":{"relation":{"relname":"contacts","inh":true,"relpersistence":"p","location":12}, "cols":[{"ResTarget":{"name":"name","location":22}}],"selectStmt":{"SelectStmt":{"valuesLists":[{"List":{"items":[{"A_Const":{"sval":{"sval":"Paul"},"location":36}}]}}],"limitOption":"LIMIT_OPTION_DEFAULT","op":"SETOP_NONE"}},"override":"OVERRIDING_NOT_SET"}},"stmt_len":42}]}"#;
+    let result = ParseResult::from_libpg_query_json(json).unwrap();
+    assert_eq!(result.dml_tables(), vec!["contacts"]);
+    assert_eq!(result.statement_types(), ["InsertStmt"]);
+    assert_eq!(result.deparse().unwrap(), "INSERT INTO contacts (name) VALUES ('Paul')");
+    assert_eq!(result.protobuf.stmts[0].stmt_len, 42);
+}
+
+#[test]
+fn it_rejects_invalid_libpg_query_json() {
+    assert!(matches!(ParseResult::from_libpg_query_json("{\"stmts\": [}"), Err(Error::InvalidJson(_))));
+    assert!(matches!(ParseResult::from_libpg_query_json(r#"{"stmts":[{"stmt":{"NotANode":{}}}]}"#), Err(Error::InvalidJson(_))));
+    assert!(matches!(ParseResult::from_libpg_query_json(r#"{"stmts":[{"stmt":{"SelectStmt":{"op":"NOT_AN_OP"}}}]}"#), Err(Error::InvalidJson(_))));
+}
+
+#[test]
+fn it_roundtrips_libpg_query_json() {
+    for query in [
+        "SELECT DISTINCT ON (a) a, b FROM t WHERE c IN (SELECT d FROM u) ORDER BY a, b DESC NULLS LAST LIMIT 10 FOR UPDATE",
+        "SELECT 1.5, true, false, NULL, B'101', 'text', -1, $1, ARRAY[1, 2]",
+        "WITH RECURSIVE r AS (SELECT 1 AS n UNION ALL SELECT n + 1 FROM r WHERE n < 10) SELECT * FROM r",
+        "INSERT INTO t (a) VALUES (1) ON CONFLICT (a) DO UPDATE SET b = excluded.b RETURNING *",
+        "MERGE INTO t USING s ON t.id = s.id WHEN MATCHED THEN UPDATE SET v = s.v WHEN NOT MATCHED THEN INSERT VALUES (s.id, s.v)",
+        "CREATE TABLE events (id bigserial PRIMARY KEY, payload jsonb NOT NULL DEFAULT '{}') PARTITION BY RANGE (id)",
+        "GRANT SELECT ON contacts TO reader",
+        "CREATE FUNCTION f(a int) RETURNS int LANGUAGE sql AS $$ SELECT a $$",
+        "SELECT json_object('a' VALUE 1 RETURNING jsonb), x::varchar(10)[] FROM t",
+    ] {
+        assert_libpg_query_json_roundtrip(query);
+    }
+}
+
+#[test]
+fn it_roundtrips_deeply_nested_libpg_query_json() {
+    assert_libpg_query_json_roundtrip(&format!("SELECT 1{}", " + 1".repeat(100)));
+}
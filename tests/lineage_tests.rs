@@ -0,0 +1,83 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::parse;
+
+#[macro_use]
+mod support;
+
+/// Returns the output columns of the query as `name: sources`, flagging aggregates and constants.
+fn lineage(query: &str) -> Vec<String> {
+    parse(query)
+        .unwrap()
+        .column_lineage()
+        .into_iter()
+        .map(|column| {
+            let sources: Vec<_> = column.sources.iter().map(ToString::to_string).collect();
+            let flags = match (column.aggregate, column.constant) {
+                (true, _) => " (aggregate)",
+                (_, true) => " (constant)",
+                _ => "",
+            };
+            format!("{}: {}{flags}", column.name.as_deref().unwrap_or("*"), sources.join(", "))
+        })
+        .collect()
+}
+
+#[test]
+fn it_traces_expressions() {
+    assert_eq!(
+        lineage("SELECT o.id, o.amount * i.qty AS total, upper(u.name), $1 AS param, now() FROM orders o JOIN line_items i ON i.order_id = o.id, users u"),
+        ["id: orders.id", "total: line_items.qty, orders.amount", "upper: users.name", "param:  (constant)", "now:  (constant)"]
+    );
+    assert_eq!(
+        lineage("SELECT count(*), sum(amount) FILTER (WHERE paid), rank() OVER (ORDER BY amount), max(amount)::int FROM orders"),
+        ["count:  (aggregate)", "sum: orders.amount, orders.paid (aggregate)", "rank: orders.amount (aggregate)", "max: orders.amount (aggregate)"]
+    );
+    // Scalar subqueries contribute their output, not the columns they filter on
+    assert_eq!(
+        lineage("SELECT u.id, (SELECT max(o.amount) FROM orders o WHERE o.user_id = u.id) AS top FROM users u"),
+        ["id: users.id", "top: orders.amount (aggregate)"]
+    );
+}
+
+#[test]
+fn it_traces_subqueries_and_CTEs() {
+    assert_eq!(
+        lineage(
+            "WITH t AS (SELECT user_id, sum(amount) AS total FROM orders GROUP BY user_id)
+             SELECT s.name, t.total, t.user_id FROM (SELECT id, name FROM users) s JOIN t ON t.user_id = s.id"
+        ),
+        ["name: users.name", "total: orders.amount (aggregate)", "user_id: orders.user_id"]
+    );
+    // Column aliases rename the first output columns
+    assert_eq!(lineage("SELECT x.a, x.b FROM (SELECT id, name AS b FROM users) x (a)"), ["a: users.id", "b: users.name"]);
+    assert_eq!(lineage("WITH c (a) AS (SELECT id FROM users) SELECT a FROM c"), ["a: users.id"]);
+    // Through `*`, and for constant subqueries
+    assert_eq!(lineage("SELECT s.name FROM (SELECT * FROM users) s"), ["name: users.name"]);
+    assert_eq!(lineage("SELECT s.a FROM (SELECT 1 AS a) s"), ["a:  (constant)"]);
+    assert_eq!(lineage("SELECT v.column1 FROM (VALUES (1), (2)) v"), ["column1:  (constant)"]);
+    // Recursive CTEs stop at their own references
+    assert_eq!(
+        lineage("WITH RECURSIVE r AS (SELECT id FROM nodes UNION ALL SELECT n.parent FROM nodes n JOIN r ON n.id = r.id) SELECT id FROM r"),
+        ["id: nodes.id, nodes.parent"]
+    );
+}
+
+#[test]
+fn it_traces_set_operations_and_stars() {
+    assert_eq!(lineage("SELECT id, name FROM users UNION SELECT id, title FROM posts"), ["id: posts.id, users.id", "name: posts.title, users.name"]);
+    assert_eq!(lineage("SELECT * FROM users u, (SELECT id FROM posts) p"), ["*: posts.id, users.*"]);
+    assert_eq!(lineage("SELECT j.id FROM (users JOIN posts USING (id)) j"), ["id: posts.id, users.id"]);
+    // Ambiguous columns come from every table that may have them
+    assert_eq!(lineage("SELECT name FROM users, posts"), ["name: posts.name, users.name"]);
+    // Columns of functions have no sources, and only SELECT statements have lineage
+    assert_eq!(lineage("SELECT 1; INSERT INTO t VALUES (1); SELECT a FROM generate_series(1, 2) a"), ["?column?:  (constant)", "a: "]);
+}
+
+#[test]
+fn it_returns_paths_of_output_columns() {
+    let result = parse("SELECT a FROM t UNION SELECT b FROM u; SELECT 1, 2").unwrap();
+    let paths: Vec<_> = result.column_lineage().into_iter().map(|column| column.path.to_string()).collect();
+    assert_eq!(paths, ["stmts[0].larg.target_list[0]", "stmts[1].target_list[0]", "stmts[1].target_list[1]"]);
+}
@@ -0,0 +1,114 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse, LockMode};
+
+#[macro_use]
+mod support;
+
+/// Returns the locks of the statements as `(statement, relation, mode)`.
+fn locks(query: &str) -> Vec<(usize, String, LockMode)> {
+    parse(query).unwrap().locks().into_iter().map(|lock| (lock.statement, lock.relation, lock.mode)).collect()
+}
+
+fn lock(statement: usize, relation: &str, mode: LockMode) -> (usize, String, LockMode) {
+    (statement, relation.to_string(), mode)
+}
+
+#[test]
+fn it_finds_locks_of_queries() {
+    use LockMode::*;
+    assert_eq!(
+        locks("SELECT * FROM a JOIN s.b ON a.id = b.id; INSERT INTO t SELECT * FROM a; UPDATE t SET x = 1 FROM a WHERE t.id = a.id"),
+        [
+            lock(0, "a", AccessShareLock),
+            lock(0, "s.b", AccessShareLock),
+            lock(1, "t", RowExclusiveLock),
+            lock(1, "a", AccessShareLock),
+            lock(2, "t", RowExclusiveLock),
+            lock(2, "a", AccessShareLock)
+        ]
+    );
+    assert_eq!(locks("SELECT * FROM t, u FOR UPDATE OF t"), [lock(0, "t", RowShareLock), lock(0, "u", AccessShareLock)]);
+    // The strongest mode wins for a relation referenced several times
+    assert_eq!(locks("DELETE FROM t WHERE id IN (SELECT id FROM t)"), [lock(0, "t", RowExclusiveLock)]);
+    assert_eq!(locks("WITH c AS (SELECT * FROM t) SELECT * FROM c"), [lock(0, "t", AccessShareLock)]);
+}
+
+#[test]
+fn it_finds_locks_of_LOCK_and_TRUNCATE() {
+    use LockMode::*;
+    assert_eq!(locks("LOCK TABLE a, b IN SHARE MODE"), [lock(0, "a", ShareLock), lock(0, "b", ShareLock)]);
+    assert_eq!(locks("LOCK t"), [lock(0, "t", AccessExclusiveLock)]);
+    assert_eq!(locks("TRUNCATE t"), [lock(0, "t", AccessExclusiveLock)]);
+}
+
+#[test]
+fn it_finds_locks_of_COPY() {
+    use LockMode::*;
+    assert_eq!(locks("COPY t FROM STDIN; COPY s.t (a, b) TO STDOUT"), [lock(0, "t", RowExclusiveLock), lock(1, "s.t", AccessShareLock)]);
+    assert_eq!(locks("COPY (SELECT * FROM t) TO STDOUT"), [lock(0, "t", AccessShareLock)]);
+}
+
+#[test]
+fn it_finds_locks_of_ALTER_TABLE() {
+    use LockMode::*;
+    assert_eq!(locks("ALTER TABLE t ADD COLUMN a int"), [lock(0, "t", AccessExclusiveLock)]);
+    assert_eq!(locks("ALTER TABLE t VALIDATE CONSTRAINT c"), [lock(0, "t", ShareUpdateExclusiveLock)]);
+    assert_eq!(locks("ALTER TABLE t ALTER COLUMN a SET STATISTICS 100"), [lock(0, "t", ShareUpdateExclusiveLock)]);
+    assert_eq!(locks("ALTER TABLE t DISABLE TRIGGER ALL"), [lock(0, "t", ShareRowExclusiveLock)]);
+    assert_eq!(
+        locks("ALTER TABLE orders ADD CONSTRAINT fk FOREIGN KEY (user_id) REFERENCES users (id) NOT VALID"),
+        [lock(0, "orders", ShareRowExclusiveLock), lock(0, "users", ShareRowExclusiveLock)]
+    );
+    // The strongest mode of the subcommands
+    assert_eq!(locks("ALTER TABLE t VALIDATE CONSTRAINT c, ALTER COLUMN a TYPE bigint"), [lock(0, "t", AccessExclusiveLock)]);
+    assert_eq!(
+        locks("ALTER TABLE p ATTACH PARTITION p1 FOR VALUES IN (1); ALTER TABLE p DETACH PARTITION p2 CONCURRENTLY"),
+        [
+            lock(0, "p", ShareUpdateExclusiveLock),
+            lock(0, "p1", AccessExclusiveLock),
+            lock(1, "p", ShareUpdateExclusiveLock),
+            lock(1, "p2", ShareUpdateExclusiveLock)
+        ]
+    );
+}
+
+#[test]
+fn it_finds_locks_of_DDL() {
+    use LockMode::*;
+    assert_eq!(
+        locks("CREATE INDEX ON t (a); CREATE INDEX CONCURRENTLY ON t (b)"),
+        [lock(0, "t", ShareLock), lock(1, "t", ShareUpdateExclusiveLock)]
+    );
+    assert_eq!(
+        locks("DROP TABLE a, s.b; DROP INDEX CONCURRENTLY i; DROP TRIGGER trg ON t"),
+        [lock(0, "a", AccessExclusiveLock), lock(0, "s.b", AccessExclusiveLock), lock(1, "i", ShareUpdateExclusiveLock), lock(2, "t", AccessExclusiveLock)]
+    );
+    assert_eq!(locks("CREATE TRIGGER trg AFTER INSERT ON t FOR EACH ROW EXECUTE FUNCTION f()"), [lock(0, "t", ShareRowExclusiveLock)]);
+    assert_eq!(locks("CREATE TABLE t (id int, user_id int REFERENCES users)"), [lock(0, "users", ShareRowExclusiveLock)]);
+    assert_eq!(
+        locks("VACUUM t; VACUUM FULL t; ANALYZE t"),
+        [lock(0, "t", ShareUpdateExclusiveLock), lock(1, "t", AccessExclusiveLock), lock(2, "t", ShareUpdateExclusiveLock)]
+    );
+    assert_eq!(
+        locks("REFRESH MATERIALIZED VIEW CONCURRENTLY mv; REINDEX TABLE CONCURRENTLY t; ALTER TABLE t RENAME TO u"),
+        [lock(0, "mv", ExclusiveLock), lock(1, "t", ShareUpdateExclusiveLock), lock(2, "t", AccessExclusiveLock)]
+    );
+}
+
+#[test]
+fn it_checks_lock_conflicts() {
+    use LockMode::*;
+    assert!(AccessShareLock.conflicts_with(AccessExclusiveLock));
+    assert!(!AccessShareLock.conflicts_with(ExclusiveLock));
+    assert!(!RowExclusiveLock.conflicts_with(RowExclusiveLock));
+    assert!(ShareLock.conflicts_with(RowExclusiveLock) && RowExclusiveLock.conflicts_with(ShareLock));
+    assert!(ShareUpdateExclusiveLock.conflicts_with(ShareUpdateExclusiveLock));
+    assert!(!NoLock.conflicts_with(AccessExclusiveLock));
+
+    assert!(!ShareUpdateExclusiveLock.blocks_reads() && !ShareUpdateExclusiveLock.blocks_writes());
+    assert!(!ShareLock.blocks_reads() && ShareLock.blocks_writes());
+    assert!(!ExclusiveLock.blocks_reads() && ExclusiveLock.blocks_writes());
+    assert!(AccessExclusiveLock.blocks_reads());
+}
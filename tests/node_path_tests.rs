@@ -0,0 +1,140 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::protobuf::{ParamRef, ParseResult};
+use pg_query::{parse, Error, NodeEnum, NodePath, NodeRef, TraversalOrder};
+
+#[macro_use]
+mod support;
+
+fn path(path: &str) -> NodePath {
+    path.parse().unwrap()
+}
+
+fn paths(result: &ParseResult, order: TraversalOrder) -> Vec<String> {
+    result.iter_nodes_with_order(order).map(|(path, _)| path.to_string()).collect()
+}
+
+#[test]
+fn it_iterates_in_pre_order() {
+    let result = parse("SELECT a FROM t WHERE b = 1").unwrap();
+    assert_eq!(
+        paths(&result.protobuf, TraversalOrder::PreOrder),
+        [
+            "stmts[0]",
+            "stmts[0].target_list[0]",
+            "stmts[0].target_list[0].val",
+            "stmts[0].target_list[0].val.fields[0]",
+            "stmts[0].from_clause[0]",
+            "stmts[0].where_clause",
+            "stmts[0].where_clause.name[0]",
+            "stmts[0].where_clause.lexpr",
+            "stmts[0].where_clause.lexpr.fields[0]",
+            "stmts[0].where_clause.rexpr",
+            "stmts[0].where_clause.rexpr.val",
+        ]
+    );
+    let kinds: Vec<_> = result.protobuf.iter_nodes().take(3).map(|(_, node)| node).collect();
+    assert!(matches!(kinds[..], [NodeRef::SelectStmt(_), NodeRef::ResTarget(_), NodeRef::ColumnRef(_)]));
+}
+
+#[test]
+fn it_iterates_in_post_order() {
+    let result = parse("SELECT a FROM t; SELECT 1").unwrap();
+    assert_eq!(
+        paths(&result.protobuf, TraversalOrder::PostOrder),
+        [
+            "stmts[0].target_list[0].val.fields[0]",
+            "stmts[0].target_list[0].val",
+            "stmts[0].target_list[0]",
+            "stmts[0].from_clause[0]",
+            "stmts[0]",
+            "stmts[1].target_list[0].val.val",
+            "stmts[1].target_list[0].val",
+            "stmts[1].target_list[0]",
+            "stmts[1]",
+        ]
+    );
+}
+
+#[test]
+fn it_iterates_over_every_node_type() {
+    let result = parse("MERGE INTO t USING s ON t.id = s.id WHEN NOT MATCHED THEN INSERT VALUES (s.id, now())").unwrap();
+    let (path, _) = result.protobuf.iter_nodes().find(|(_, node)| matches!(node, NodeRef::FuncCall(_))).unwrap();
+    assert_eq!(path.to_string(), "stmts[0].merge_when_clauses[0].values[1]");
+    assert_eq!(path.len(), 3);
+    assert_eq!(path.parent().unwrap().to_string(), "stmts[0].merge_when_clauses[0]");
+    assert_eq!(path.last().unwrap().field, "values");
+
+    // Relative to the node
+    let NodeRef::MergeStmt(merge) = result.protobuf.get_by_path(&self::path("stmts[0]")).unwrap() else { panic!("expected a MergeStmt") };
+    let node = NodeEnum::MergeStmt(Box::new(merge.clone()));
+    let relation = node.iter_nodes().nth(1).unwrap();
+    assert_eq!(relation.0.to_string(), "relation");
+    assert!(matches!(relation.1, NodeRef::RangeVar(table) if table.relname == "t"));
+}
+
+#[test]
+fn it_iterates_over_wide_trees() {
+    let query = format!("SELECT {} FROM t", (0..5000).map(|i| format!("c{i}")).collect::<Vec<_>>().join(", "));
+    let result = parse(&query).unwrap();
+    // The statement, each target with its column and name, and the table
+    assert_eq!(result.protobuf.iter_nodes().count(), 1 + 5000 * 3 + 1);
+    assert_eq!(result.protobuf.iter_nodes().last().unwrap().0, path("stmts[0].from_clause[0]"));
+}
+
+#[test]
+fn it_finds_nodes_by_path() {
+    let queries = [
+        "SELECT * FROM users u JOIN orders o USING (id) WHERE o.total > 10 ORDER BY u.name",
+        "WITH recent AS (SELECT * FROM events) INSERT INTO archive SELECT * FROM recent ON CONFLICT (id) DO NOTHING",
+        "CREATE TABLE t (id int PRIMARY KEY, name text DEFAULT 'x' CHECK (length(name) > 0))",
+    ];
+    for query in queries {
+        let result = parse(query).unwrap();
+        for (path, node) in result.protobuf.iter_nodes() {
+            let found = result.protobuf.get_by_path(&path.to_string().parse().unwrap()).unwrap();
+            assert!(found.to_enum() == node.to_enum(), "{path}");
+        }
+    }
+
+    let result = parse(queries[0]).unwrap();
+    assert!(matches!(result.protobuf.get_by_path(&path("stmts[0].from_clause[0].larg")), Some(NodeRef::RangeVar(table)) if table.relname == "users"));
+    assert!(result.protobuf.get_by_path(&path("stmts[0].from_clause[1]")).is_none());
+    assert!(result.protobuf.get_by_path(&path("stmts[1]")).is_none());
+    assert!(result.protobuf.get_by_path(&path("stmts[0].having_clause")).is_none());
+    assert!(result.protobuf.get_by_path(&path("")).is_none());
+}
+
+#[test]
+fn it_replaces_nodes_at_paths() {
+    let mut result = parse("SELECT * FROM users WHERE id = 1 AND name = 'x'").unwrap();
+    let param = NodeEnum::ParamRef(ParamRef { number: 1, location: 0 });
+    let old = result.protobuf.replace_at_path(&path("stmts[0].where_clause.args[1].rexpr"), param).unwrap();
+    assert!(matches!(old, NodeEnum::AConst(_)));
+    assert_eq!(result.protobuf.deparse().unwrap(), "SELECT * FROM users WHERE id = 1 AND name = $1");
+
+    // Fields holding one type of node only accept that type
+    let table = result.protobuf.get_by_path(&path("stmts[0].from_clause[0]")).unwrap().to_enum();
+    let mut update = parse("UPDATE accounts SET a = 1").unwrap();
+    update.protobuf.replace_at_path(&path("stmts[0].relation"), table).unwrap();
+    assert_eq!(update.protobuf.deparse().unwrap(), "UPDATE users SET a = 1");
+    let column = update.protobuf.get_by_path(&path("stmts[0].target_list[0].val")).unwrap().to_enum();
+    assert_eq!(
+        update.protobuf.replace_at_path(&path("stmts[0].relation"), column.clone()),
+        Err(Error::InvalidPath("stmts[0].relation holds a different type of node".to_string()))
+    );
+    assert_eq!(update.protobuf.replace_at_path(&path("stmts[0].where_clause"), column), Err(Error::InvalidPath("stmts[0].where_clause".to_string())));
+}
+
+#[test]
+fn it_parses_paths() {
+    let parsed = path("stmts[0].where_clause.args[12]");
+    assert_eq!(parsed.to_string(), "stmts[0].where_clause.args[12]");
+    assert_eq!(parsed.len(), 3);
+    assert_eq!(parsed, NodePath::default().join("stmts", Some(0)).join("where_clause", None).join("args", Some(12)));
+    assert!(path("").is_empty());
+    for invalid in ["stmts[x]", "stmts[0]..args", "where clause", "args[1"] {
+        assert_eq!(invalid.parse::<NodePath>(), Err(Error::InvalidPath(invalid.to_string())));
+    }
+}
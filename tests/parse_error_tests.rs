@@ -0,0 +1,93 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{fingerprint, fingerprint_raw, normalize, parse, parse_raw, split_with_parser, summary, Error, ParseError};
+
+#[macro_use]
+mod support;
+
+fn parse_error(query: &str) -> ParseError {
+    *cast!(parse(query).err().unwrap(), Error::Syntax)
+}
+
+#[test]
+fn it_reports_error_positions() {
+    let error = parse_error("CREATE RANDOM ix_test ON contacts.person;");
+    assert_eq!(error.message, "syntax error at or near \"RANDOM\"");
+    assert_eq!(error.cursor, Some(7));
+    assert_eq!(error.line, Some(1));
+    assert_eq!(error.column, Some(8));
+    assert_eq!(error.token.as_deref(), Some("RANDOM"));
+    assert_eq!(error.sqlstate, Some("42601"));
+    assert_eq!(error.filename.as_deref(), Some("scan.l"));
+}
+
+#[test]
+fn it_reports_error_positions_on_later_lines() {
+    let error = parse_error("SELECT *\nFROM contacts\nWHERE id = = 1");
+    assert_eq!(error.cursor, Some(34));
+    assert_eq!(error.line, Some(3));
+    assert_eq!(error.column, Some(12));
+    assert_eq!(error.token.as_deref(), Some("="));
+}
+
+#[test]
+fn it_reports_error_positions_after_multibyte_characters() {
+    let query = "SELECT 'ü', FROM t";
+    let error = parse_error(query);
+    assert_eq!(error.cursor, Some(13));
+    assert_eq!(&query[13..], "FROM t");
+    assert_eq!(error.column, Some(13));
+}
+
+#[test]
+fn it_reports_errors_at_end_of_input() {
+    let error = parse_error("SELECT * FROM");
+    assert_eq!(error.message, "syntax error at end of input");
+    assert_eq!(error.cursor, Some(13));
+    assert_eq!(error.column, Some(14));
+    assert_eq!(error.token, None);
+    assert_eq!(error.sqlstate, Some("42601"));
+}
+
+#[test]
+fn it_renders_errors() {
+    let query = "CREATE RANDOM ix_test ON contacts.person;";
+    assert_eq!(
+        parse_error(query).render(query),
+        "ERROR:  syntax error at or near \"RANDOM\"\nLINE 1: CREATE RANDOM ix_test ON contacts.person;\n               ^^^^^^"
+    );
+
+    let query = "SELECT *\nFROM contacts\nWHERE id = = 1";
+    assert_eq!(parse_error(query).render(query), "ERROR:  syntax error at or near \"=\"\nLINE 3: WHERE id = = 1\n                   ^");
+
+    let query = "SELECT 'ERR";
+    assert_eq!(parse_error(query).render(query), "ERROR:  unterminated quoted string at or near \"'ERR\"\nLINE 1: SELECT 'ERR\n               ^^^^");
+
+    let query = "SELECT * FROM";
+    assert_eq!(parse_error(query).render(query), "ERROR:  syntax error at end of input\nLINE 1: SELECT * FROM\n                     ^");
+
+    // Other text than the statement that failed doesn't have the cursor in it
+    assert_eq!(parse_error(query).render("SELECT"), "ERROR:  syntax error at end of input");
+    assert_eq!(parse_error("SELECT ab)").render("SELECT 'é' = 1"), "ERROR:  syntax error at or near \")\"");
+}
+
+#[test]
+fn it_reports_the_same_error_from_every_entry_point() {
+    let query = "SELECT * FROM contacts WHERE id = = 1";
+    let error = parse(query).err().unwrap();
+    assert_eq!(cast!(&error, Error::Syntax).cursor, Some(34));
+
+    assert_eq!(parse_raw(query).err().unwrap(), error);
+    assert_eq!(normalize(query).err().unwrap(), error);
+    assert_eq!(fingerprint(query).err().unwrap(), error);
+    assert_eq!(fingerprint_raw(query).err().unwrap(), error);
+    assert_eq!(summary(query, -1).err().unwrap(), error);
+    assert_eq!(split_with_parser(query).err().unwrap(), error);
+}
+
+#[test]
+fn it_displays_the_message() {
+    let error = parse("SELECT 'ERR").err().unwrap();
+    assert_eq!(error.to_string(), "Invalid statement: unterminated quoted string at or near \"'ERR\"");
+}
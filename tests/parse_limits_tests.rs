@@ -0,0 +1,123 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{
+    parse, parse_raw_tree_with_options, parse_raw_with_options, parse_with_options, summary_with_options, Error, LimitKind, ParseLimits,
+    ParseOptions, Parser,
+};
+
+#[macro_use]
+mod support;
+
+fn with_limits(limits: ParseLimits) -> ParseOptions {
+    ParseOptions { limits, ..ParseOptions::default() }
+}
+
+fn exceeded(kind: LimitKind, limit: usize) -> Error {
+    Error::LimitExceeded { kind, limit }
+}
+
+// Nests a join per table, as in benches/parse_vs_summary.rs
+fn joins(tables: usize) -> String {
+    let mut query = "SELECT * FROM t".to_string();
+    for i in 0..tables {
+        query = format!("{query} JOIN t{i} ON t.id = t{i}.t_id AND t{i}.f IN (SELECT o FROM p WHERE q = 'foo')");
+    }
+    query
+}
+
+#[test]
+fn it_accepts_statements_within_the_limits() {
+    let query = "SELECT * FROM contacts WHERE id = 1; SELECT 2";
+    let options = with_limits(ParseLimits { max_input_bytes: Some(100), max_depth: Some(10), max_nodes: Some(100), max_statements: Some(2) });
+    assert_eq!(parse_with_options(query, options).unwrap().protobuf, parse(query).unwrap().protobuf);
+    assert_eq!(parse_raw_with_options(query, options).unwrap().protobuf, parse(query).unwrap().protobuf);
+    assert_eq!(summary_with_options(query, options, -1).unwrap().tables(), ["contacts"]);
+    assert_eq!(parse_raw_tree_with_options(query, options).unwrap().stmts().len(), 2);
+}
+
+#[test]
+fn it_limits_the_input_length() {
+    let options = with_limits(ParseLimits { max_input_bytes: Some(10), ..ParseLimits::default() });
+    assert_eq!(parse_with_options("SELECT 1", options).unwrap().statement_types(), ["SelectStmt"]);
+    let query = "SELECT * FROM contacts";
+    assert_eq!(parse_with_options(query, options).err(), Some(exceeded(LimitKind::InputBytes, 10)));
+    assert_eq!(parse_raw_with_options(query, options).err(), Some(exceeded(LimitKind::InputBytes, 10)));
+    assert_eq!(summary_with_options(query, options, -1).err(), Some(exceeded(LimitKind::InputBytes, 10)));
+    assert_eq!(parse_raw_tree_with_options(query, options).err(), Some(exceeded(LimitKind::InputBytes, 10)));
+    // Checked before parsing, so invalid statements fail the same way
+    assert_eq!(parse_with_options("SELECT * FORM contacts", options).err(), Some(exceeded(LimitKind::InputBytes, 10)));
+}
+
+#[test]
+fn it_limits_the_statement_count() {
+    let options = with_limits(ParseLimits { max_statements: Some(2), ..ParseLimits::default() });
+    let query = "SELECT 1; SELECT 2; SELECT 3";
+    assert_eq!(parse_with_options(query, options).err(), Some(exceeded(LimitKind::Statements, 2)));
+    assert_eq!(parse_raw_with_options(query, options).err(), Some(exceeded(LimitKind::Statements, 2)));
+    assert_eq!(summary_with_options(query, options, -1).err(), Some(exceeded(LimitKind::Statements, 2)));
+    assert_eq!(parse_raw_tree_with_options(query, options).err(), Some(exceeded(LimitKind::Statements, 2)));
+}
+
+#[test]
+fn it_limits_the_nesting_depth() {
+    let options = with_limits(ParseLimits { max_depth: Some(64), ..ParseLimits::default() });
+    let shallow = format!("SELECT 1{}", " + 1".repeat(20));
+    let deep = format!("SELECT 1{}", " + 1".repeat(100));
+    assert!(parse_with_options(&shallow, options).is_ok());
+    assert!(parse_raw_with_options(&shallow, options).is_ok());
+    assert_eq!(parse_with_options(&deep, options).err(), Some(exceeded(LimitKind::Depth, 64)));
+    assert_eq!(parse_raw_with_options(&deep, options).err(), Some(exceeded(LimitKind::Depth, 64)));
+}
+
+#[test]
+fn it_counts_set_operations_towards_the_depth() {
+    let options = with_limits(ParseLimits { max_depth: Some(64), ..ParseLimits::default() });
+    let query = vec!["SELECT 1"; 100].join(" UNION ");
+    assert_eq!(parse_with_options(&query, options).err(), Some(exceeded(LimitKind::Depth, 64)));
+    assert_eq!(parse_raw_with_options(&query, options).err(), Some(exceeded(LimitKind::Depth, 64)));
+}
+
+#[test]
+fn it_limits_the_node_count() {
+    let options = with_limits(ParseLimits { max_nodes: Some(1000), ..ParseLimits::default() });
+    assert!(parse_with_options(&joins(5), options).is_ok());
+    assert!(parse_raw_with_options(&joins(5), options).is_ok());
+    let query = joins(100);
+    assert_eq!(parse_with_options(&query, options).err(), Some(exceeded(LimitKind::Nodes, 1000)));
+    assert_eq!(parse_raw_with_options(&query, options).err(), Some(exceeded(LimitKind::Nodes, 1000)));
+    // The count spans all statements
+    let query = vec![joins(5); 20].join("; ");
+    assert_eq!(parse_with_options(&query, options).err(), Some(exceeded(LimitKind::Nodes, 1000)));
+    assert_eq!(parse_raw_with_options(&query, options).err(), Some(exceeded(LimitKind::Nodes, 1000)));
+}
+
+#[test]
+fn it_applies_limits_in_parsers() {
+    let mut parser = Parser::with_options(with_limits(ParseLimits { max_depth: Some(64), ..ParseLimits::default() }));
+    let query = format!("SELECT 1{}", " + 1".repeat(100));
+    assert_eq!(parser.parse(&query).err(), Some(exceeded(LimitKind::Depth, 64)));
+    assert_eq!(parser.parse_raw(&query).err(), Some(exceeded(LimitKind::Depth, 64)));
+    // Later conversions on the same thread aren't limited
+    assert!(pg_query::parse_raw(&query).is_ok());
+}
+
+#[test]
+fn it_limits_node_walks() {
+    let result = parse(&joins(50)).unwrap();
+    let nodes = result.protobuf.nodes();
+    assert_eq!(result.protobuf.nodes_with_limits(&ParseLimits::default()).unwrap().len(), nodes.len());
+
+    let limits = ParseLimits { max_nodes: Some(nodes.len() - 1), ..ParseLimits::default() };
+    assert_eq!(result.protobuf.nodes_with_limits(&limits).err(), Some(exceeded(LimitKind::Nodes, nodes.len() - 1)));
+    let depth = nodes.iter().map(|(_, depth, _, _)| *depth as usize).max().unwrap();
+    let limits = ParseLimits { max_depth: Some(depth - 1), ..ParseLimits::default() };
+    assert_eq!(result.protobuf.nodes_with_limits(&limits).err(), Some(exceeded(LimitKind::Depth, depth - 1)));
+    let limits = ParseLimits { max_statements: Some(0), ..ParseLimits::default() };
+    assert_eq!(result.protobuf.nodes_with_limits(&limits).err(), Some(exceeded(LimitKind::Statements, 0)));
+}
+
+#[test]
+fn it_describes_the_exceeded_limit() {
+    assert_eq!(exceeded(LimitKind::Depth, 64).to_string(), "Statement exceeds the nesting depth limit of 64");
+}
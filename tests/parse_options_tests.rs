@@ -0,0 +1,117 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{
+    parse_expression, parse_raw_strict_with_options, parse_raw_with_options, parse_type_name, parse_with_options, summary_with_options, NodeEnum,
+    ParseMode, ParseOptions,
+};
+
+#[macro_use]
+mod support;
+
+fn first_node(result: &pg_query::ParseResult) -> Option<&NodeEnum> {
+    result.protobuf.stmts.first().and_then(|s| s.stmt.as_ref()).and_then(|s| s.node.as_ref())
+}
+
+#[test]
+fn it_parses_type_names() {
+    let result = parse_with_options("varchar(20)[]", ParseOptions::with_mode(ParseMode::TypeName)).unwrap();
+    assert_eq!(result.protobuf.stmts.len(), 1);
+    let type_name = match first_node(&result) {
+        Some(NodeEnum::TypeName(t)) => t,
+        other => panic!("expected a TypeName, got {other:?}"),
+    };
+    let names: Vec<&str> = type_name
+        .names
+        .iter()
+        .filter_map(|n| match &n.node {
+            Some(NodeEnum::String(s)) => Some(s.sval.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(names, ["pg_catalog", "varchar"]);
+    assert_eq!(type_name.typmods.len(), 1);
+    assert_eq!(type_name.array_bounds.len(), 1);
+
+    assert_eq!(parse_type_name("varchar(20)[]").unwrap(), *type_name);
+}
+
+#[test]
+fn it_parses_qualified_type_names() {
+    let type_name = parse_type_name("public.my_type").unwrap();
+    assert_eq!(type_name.names.len(), 2);
+    assert!(type_name.array_bounds.is_empty());
+}
+
+#[test]
+fn it_rejects_invalid_type_names() {
+    assert!(parse_type_name("SELECT 1").is_err());
+}
+
+#[test]
+fn it_parses_expressions() {
+    let result = parse_with_options("a + 1 > $1", ParseOptions::with_mode(ParseMode::PlpgsqlExpr)).unwrap();
+    assert!(matches!(first_node(&result), Some(NodeEnum::SelectStmt(_))));
+
+    let expr = parse_expression("a + 1 > $1").unwrap();
+    let expr = match expr.node {
+        Some(NodeEnum::AExpr(e)) => e,
+        other => panic!("expected an AExpr, got {other:?}"),
+    };
+    assert!(matches!(expr.lexpr.as_ref().and_then(|n| n.node.as_ref()), Some(NodeEnum::AExpr(_))));
+    assert!(matches!(expr.rexpr.as_ref().and_then(|n| n.node.as_ref()), Some(NodeEnum::ParamRef(_))));
+}
+
+#[test]
+fn it_rejects_expressions_with_multiple_targets() {
+    assert!(parse_expression("a, b").is_err());
+    assert!(parse_expression("a FROM b").is_err());
+}
+
+#[test]
+fn it_parses_plpgsql_assignments() {
+    let result = parse_with_options("x := y + 1", ParseOptions::with_mode(ParseMode::PlpgsqlAssign1)).unwrap();
+    let assign = match first_node(&result) {
+        Some(NodeEnum::PlassignStmt(a)) => a,
+        other => panic!("expected a PlassignStmt, got {other:?}"),
+    };
+    assert_eq!(assign.name, "x");
+    assert_eq!(assign.nnames, 1);
+    assert!(assign.val.is_some());
+
+    let result = parse_with_options("rec.field := 1", ParseOptions::with_mode(ParseMode::PlpgsqlAssign2)).unwrap();
+    let assign = match first_node(&result) {
+        Some(NodeEnum::PlassignStmt(a)) => a,
+        other => panic!("expected a PlassignStmt, got {other:?}"),
+    };
+    assert_eq!(assign.name, "rec");
+    assert_eq!(assign.nnames, 2);
+}
+
+#[test]
+fn it_matches_parse_raw_with_options() {
+    for (query, mode) in [
+        ("varchar(20)[]", ParseMode::TypeName),
+        ("a + 1 > $1", ParseMode::PlpgsqlExpr),
+        ("x := y + 1", ParseMode::PlpgsqlAssign1),
+        ("SELECT 1", ParseMode::Default),
+    ] {
+        let options = ParseOptions::with_mode(mode);
+        let result = parse_with_options(query, options).unwrap();
+        let raw_result = parse_raw_with_options(query, options).unwrap();
+        assert_eq!(result.protobuf, raw_result.protobuf);
+        assert_eq!(result.protobuf, parse_raw_strict_with_options(query, options).unwrap().protobuf);
+    }
+}
+
+#[test]
+fn it_respects_string_options() {
+    let query = r"SELECT 'O\'Brien'";
+    assert!(pg_query::parse(query).is_err());
+
+    let options = ParseOptions { disable_standard_conforming_strings: true, ..ParseOptions::default() };
+    assert!(parse_with_options(query, options).is_ok());
+
+    let result = summary_with_options(r"SELECT * FROM contacts WHERE name = 'O\'Brien'", options, -1).unwrap();
+    assert_eq!(result.tables(), vec!["contacts"]);
+}
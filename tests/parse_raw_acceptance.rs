@@ -6,14 +6,19 @@
 //! The tests are organized by SQL category and cover many edge cases and
 //! PostgreSQL-specific features.
 
-use pg_query::{parse, parse_raw, Error};
+use pg_query::{parse, parse_json, parse_raw, Error, ParseResult};
 
-/// Helper macro for simple parse comparison tests
+/// Helper macro for simple parse comparison tests. Also checks that the tree survives JSON roundtrips.
 macro_rules! assert_parse_raw_matches {
     ($query:expr) => {{
         let raw_result = parse_raw($query).expect(&format!("parse_raw failed for: {}", $query));
         let proto_result = parse($query).expect(&format!("parse failed for: {}", $query));
         assert_eq!(raw_result.protobuf, proto_result.protobuf, "Mismatch for query: {}", $query);
+        let loaded = ParseResult::from_json(&proto_result.to_json()).expect(&format!("from_json failed for: {}", $query));
+        assert_eq!(loaded.protobuf, proto_result.protobuf, "JSON roundtrip mismatch for query: {}", $query);
+        let json = parse_json($query).expect(&format!("parse_json failed for: {}", $query));
+        let loaded = ParseResult::from_libpg_query_json(&json).expect(&format!("from_libpg_query_json failed for: {}", $query));
+        assert_eq!(loaded.protobuf, proto_result.protobuf, "libpg_query JSON mismatch for query: {}", $query);
     }};
 }
 
@@ -2088,9 +2093,9 @@ fn error_cases() {
         assert!(raw_result.is_err(), "Expected parse_raw to fail for: {}", query);
         assert!(proto_result.is_err(), "Expected parse to fail for: {}", query);
 
-        // Both should produce Parse errors
-        assert!(matches!(raw_result, Err(Error::Parse(_))), "Expected Parse error from parse_raw for: {}", query);
-        assert!(matches!(proto_result, Err(Error::Parse(_))), "Expected Parse error from parse for: {}", query);
+        // Both should produce syntax errors
+        assert!(matches!(raw_result, Err(Error::Syntax(_))), "Expected syntax error from parse_raw for: {}", query);
+        assert!(matches!(proto_result, Err(Error::Syntax(_))), "Expected syntax error from parse for: {}", query);
     }
 }
 
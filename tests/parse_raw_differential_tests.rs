@@ -0,0 +1,19 @@
+//! Checks that parse_raw agrees with parse on every statement of the corpus in `data/raw_parse_corpus.sql`.
+
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse, parse_raw_strict, split_with_parser};
+
+#[macro_use]
+mod support;
+
+#[test]
+fn it_matches_parse_for_every_corpus_statement() {
+    let queries = split_with_parser(include_str!("data/raw_parse_corpus.sql")).unwrap();
+    assert!(queries.len() > 500, "only found {} statements", queries.len());
+
+    let mismatches: Vec<_> =
+        queries.iter().filter(|query| parse_raw_strict(query).map(|result| result.protobuf) != Ok(parse(query).unwrap().protobuf)).collect();
+    assert!(mismatches.is_empty(), "parse_raw differs from parse for {} statements:\n{:#?}", mismatches.len(), mismatches);
+}
@@ -0,0 +1,80 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse_script, NodeEnum};
+
+#[macro_use]
+mod support;
+
+#[test]
+fn it_parses_every_statement() {
+    let script = "INSERT INTO contacts SELECT * FROM people; SELECT my_fn() FROM orders";
+    let statements = parse_script(script);
+    assert_eq!(statements.len(), 2);
+    assert!(statements.iter().all(|s| s.is_ok()));
+
+    assert_eq!(statements[0].sql, "INSERT INTO contacts SELECT * FROM people");
+    assert_eq!(statements[0].span, 0..41);
+    let result = statements[0].result.as_ref().unwrap();
+    assert_eq!(result.select_tables(), vec!["people"]);
+    assert_eq!(result.dml_tables(), vec!["contacts"]);
+
+    assert_eq!(&script[statements[1].span.clone()], "SELECT my_fn() FROM orders");
+    let result = statements[1].result.as_ref().unwrap();
+    assert_eq!(result.tables(), vec!["orders"]);
+    assert_eq!(result.functions(), vec!["my_fn"]);
+    let stmt = statements[1].stmt().unwrap();
+    assert!(matches!(stmt.stmt.as_ref().and_then(|n| n.node.as_ref()), Some(NodeEnum::SelectStmt(_))));
+}
+
+#[test]
+fn it_reports_every_invalid_statement() {
+    let script = "SELECT 1;\nSELECT * FROM;\nSELECT 2;\nDELETE x;";
+    let statements = parse_script(script);
+    assert_eq!(statements.iter().map(|s| s.sql).collect::<Vec<_>>(), ["SELECT 1", "SELECT * FROM", "SELECT 2", "DELETE x"]);
+    assert_eq!(statements.iter().map(|s| s.is_ok()).collect::<Vec<_>>(), [true, false, true, false]);
+
+    let error = statements[1].error().unwrap();
+    assert_eq!(error.message, "syntax error at end of input");
+    assert_eq!(error.cursor, Some(23));
+    assert_eq!((error.line, error.column), (Some(2), Some(14)));
+
+    let error = statements[3].error().unwrap();
+    assert_eq!(error.message, "syntax error at or near \"x\"");
+    assert_eq!(error.cursor, Some(42));
+    assert_eq!((error.line, error.column), (Some(4), Some(8)));
+    assert_eq!(error.render(script), "ERROR:  syntax error at or near \"x\"\nLINE 4: DELETE x;\n               ^");
+}
+
+#[test]
+fn it_skips_comments_and_empty_statements() {
+    let script = "-- header\nSELECT 1;;\n/* second */ SELECT 2; -- trailing\n";
+    let statements = parse_script(script);
+    assert_eq!(statements.iter().map(|s| s.sql).collect::<Vec<_>>(), ["SELECT 1", "SELECT 2"]);
+    assert!(parse_script("  -- nothing here\n ; ").is_empty());
+}
+
+#[test]
+fn it_keeps_routine_bodies_together() {
+    let script = "CREATE FUNCTION f() RETURNS int LANGUAGE sql BEGIN ATOMIC SELECT CASE WHEN true THEN 1 END; SELECT 2; END; SELECT 3";
+    let statements = parse_script(script);
+    assert_eq!(statements.len(), 2);
+    assert!(statements.iter().all(|s| s.is_ok()));
+    assert!(statements[0].sql.ends_with("SELECT 2; END"));
+    assert_eq!(statements[1].sql, "SELECT 3");
+}
+
+#[test]
+fn it_recovers_from_scanner_errors() {
+    let statements = parse_script("SELECT 1; SELECT 123abc; SELECT 2");
+    assert_eq!(statements.iter().map(|s| s.sql).collect::<Vec<_>>(), ["SELECT 1", "SELECT 123abc", "SELECT 2"]);
+    assert_eq!(statements.iter().map(|s| s.is_ok()).collect::<Vec<_>>(), [true, false, true]);
+    assert_eq!(statements[1].error().unwrap().cursor, Some(17));
+
+    let statements = parse_script("SELECT 1; SELECT 'oops; SELECT 2");
+    assert_eq!(statements.len(), 2);
+    assert_eq!(statements[1].sql, "SELECT 'oops; SELECT 2");
+    let error = statements[1].error().unwrap();
+    assert_eq!(error.message, "unterminated quoted string at or near \"'oops; SELECT 2\"");
+    assert_eq!(error.cursor, Some(17));
+}
@@ -38,8 +38,8 @@ fn it_handles_parse_errors() {
     let raw_error = parse_raw(query).err().unwrap();
     let proto_error = parse(query).err().unwrap();
 
-    assert!(matches!(raw_error, Error::Parse(_)));
-    assert!(matches!(proto_error, Error::Parse(_)));
+    assert!(matches!(raw_error, Error::Syntax(_)));
+    assert_eq!(raw_error, proto_error);
 }
 
 /// Test that parse_raw and parse produce equivalent results for simple SELECT
@@ -714,6 +714,20 @@ fn it_parses_alter_function() {
     parse_test!(query);
 }
 
+/// Test CREATE FUNCTION with SQL-standard bodies, which hold ReturnStmt nodes
+#[test]
+fn it_parses_create_function_with_sql_body() {
+    for query in [
+        "CREATE FUNCTION add(a int, b int) RETURNS int LANGUAGE sql RETURN a + b",
+        "CREATE FUNCTION one() RETURNS int LANGUAGE sql BEGIN ATOMIC SELECT 1; RETURN 1; END",
+    ] {
+        let raw_result = pg_query::parse_raw_strict(query).unwrap();
+        let proto_result = parse(query).unwrap();
+
+        assert_eq!(raw_result.protobuf, proto_result.protobuf);
+    }
+}
+
 /// Test ALTER TYPE ADD VALUE
 #[test]
 fn it_parses_alter_type_add_value() {
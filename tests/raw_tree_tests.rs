@@ -0,0 +1,135 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::protobuf::{AExprKind, BoolExprType, JoinType, TransactionStmtKind};
+use pg_query::{
+    parse_raw, parse_raw_tree, parse_raw_tree_with_options, Error, NodeEnum, ParseMode, ParseOptions, RawConstValue, RawNode, RawNodeRef,
+};
+
+#[macro_use]
+mod support;
+
+fn names<'t>(node: RawNode<'t>) -> Vec<&'t str> {
+    let fields = match node.to_ref() {
+        RawNodeRef::ColumnRef(column) => column.fields(),
+        RawNodeRef::FuncCall(func) => func.funcname(),
+        _ => panic!("expected a ColumnRef or FuncCall"),
+    };
+    fields
+        .iter()
+        .map(|field| match field.to_ref() {
+            RawNodeRef::String(name) => name,
+            RawNodeRef::AStar => "*",
+            _ => panic!("expected a String or A_Star"),
+        })
+        .collect()
+}
+
+#[test]
+fn it_reads_statements_in_place() {
+    let query = "SELECT 1; UPDATE accounts SET balance = 0 WHERE id = $1; BEGIN";
+    let tree = parse_raw_tree(query).unwrap();
+    assert_eq!(tree.statement(), query);
+    assert_eq!(tree.stmts().len(), 3);
+    assert_eq!(tree.stmts().map(|stmt| stmt.sql()).collect::<Vec<_>>(), ["SELECT 1", " UPDATE accounts SET balance = 0 WHERE id = $1", " BEGIN"]);
+
+    let stmts: Vec<_> = tree.stmts().filter_map(|stmt| stmt.stmt()).collect();
+    assert!(matches!(stmts[0].to_ref(), RawNodeRef::SelectStmt(_)));
+
+    let RawNodeRef::UpdateStmt(update) = stmts[1].to_ref() else { panic!("expected an UpdateStmt") };
+    assert_eq!(update.relation().unwrap().relname(), "accounts");
+    assert_eq!(update.target_list().len(), 1);
+    let RawNodeRef::AExpr(expr) = update.where_clause().unwrap().to_ref() else { panic!("expected an A_Expr") };
+    assert_eq!(expr.kind(), AExprKind::AexprOp);
+    assert_eq!(names(expr.lexpr().unwrap()), ["id"]);
+    let RawNodeRef::ParamRef(param) = expr.rexpr().unwrap().to_ref() else { panic!("expected a ParamRef") };
+    assert_eq!(param.number(), 1);
+
+    let RawNodeRef::TransactionStmt(begin) = stmts[2].to_ref() else { panic!("expected a TransactionStmt") };
+    assert_eq!(begin.kind(), TransactionStmtKind::TransStmtBegin);
+}
+
+#[test]
+fn it_reads_select_statements() {
+    let query = "SELECT u.*, count(*) AS n, 'x', 1.5, true, NULL FROM public.users u JOIN orders o ON o.user_id = u.id \
+                 WHERE u.active AND o.total > 10 GROUP BY u.id LIMIT 5 FOR UPDATE";
+    let tree = parse_raw_tree(query).unwrap();
+    let RawNodeRef::SelectStmt(select) = tree.stmts().next().unwrap().stmt().unwrap().to_ref() else { panic!("expected a SelectStmt") };
+
+    let targets: Vec<_> = select
+        .target_list()
+        .iter()
+        .map(|target| match target.to_ref() {
+            RawNodeRef::ResTarget(target) => target,
+            _ => panic!("expected a ResTarget"),
+        })
+        .collect();
+    assert_eq!(targets.iter().map(|target| target.name()).collect::<Vec<_>>(), [None, Some("n"), None, None, None, None]);
+    assert_eq!(names(targets[0].val().unwrap()), ["u", "*"]);
+    assert_eq!(names(targets[1].val().unwrap()), ["count"]);
+    let values: Vec<_> = targets[2..]
+        .iter()
+        .map(|target| match target.val().unwrap().to_ref() {
+            RawNodeRef::AConst(value) => value.value(),
+            _ => panic!("expected an A_Const"),
+        })
+        .collect();
+    assert_eq!(values, [RawConstValue::String("x"), RawConstValue::Float("1.5"), RawConstValue::Boolean(true), RawConstValue::Null]);
+
+    let RawNodeRef::JoinExpr(join) = select.from_clause().iter().next().unwrap().to_ref() else { panic!("expected a JoinExpr") };
+    assert_eq!(join.jointype(), JoinType::JoinInner);
+    let RawNodeRef::RangeVar(users) = join.larg().unwrap().to_ref() else { panic!("expected a RangeVar") };
+    assert_eq!((users.schemaname(), users.relname(), users.aliasname()), (Some("public"), "users", Some("u")));
+    assert!(join.quals().is_some());
+
+    let RawNodeRef::BoolExpr(filter) = select.where_clause().unwrap().to_ref() else { panic!("expected a BoolExpr") };
+    assert_eq!(filter.boolop(), BoolExprType::AndExpr);
+    assert_eq!(filter.args().len(), 2);
+    assert_eq!(select.group_clause().len(), 1);
+    assert!(select.limit_count().is_some());
+    assert_eq!(select.locking_clause().len(), 1);
+    assert!(select.larg().is_none());
+}
+
+#[test]
+fn it_converts_to_the_parse_raw_result() {
+    for query in [
+        "SELECT * FROM contacts WHERE id = 1",
+        "INSERT INTO orders (id, total) VALUES (1, 9.99) RETURNING id",
+        "WITH recent AS (SELECT * FROM events) SELECT count(*) FROM recent; SET search_path = public",
+    ] {
+        let tree = parse_raw_tree(query).unwrap();
+        let result = parse_raw(query).unwrap();
+        assert_eq!(tree.to_owned().protobuf, result.protobuf);
+        assert_eq!(tree.to_owned().tables(), result.tables());
+
+        // Subtrees convert to the same nodes
+        for (stmt, raw_stmt) in tree.stmts().zip(&result.protobuf.stmts) {
+            assert_eq!(stmt.stmt().map(|node| node.to_owned()).as_ref(), raw_stmt.stmt.as_deref());
+        }
+    }
+}
+
+#[test]
+fn it_returns_other_nodes_for_conversion() {
+    let tree = parse_raw_tree("CREATE TABLE t (id int)").unwrap();
+    let node = tree.stmts().next().unwrap().stmt().unwrap();
+    assert!(matches!(node.to_ref(), RawNodeRef::Other(_)));
+    let NodeEnum::CreateStmt(create) = node.to_owned().node.unwrap() else { panic!("expected a CreateStmt") };
+    assert_eq!(create.relation.unwrap().relname, "t");
+}
+
+#[test]
+fn it_uses_parse_modes() {
+    let tree = parse_raw_tree_with_options("a + 1", ParseOptions::with_mode(ParseMode::PlpgsqlExpr)).unwrap();
+    let stmt = tree.stmts().next().unwrap();
+    assert_eq!(stmt.sql(), "a + 1");
+    assert!(matches!(stmt.stmt().unwrap().to_ref(), RawNodeRef::SelectStmt(_)));
+}
+
+#[test]
+fn it_reports_errors() {
+    let error = parse_raw_tree("SELECT * FORM contacts").err().unwrap();
+    assert_eq!(cast!(error, Error::Syntax).message, "syntax error at or near \"FORM\"");
+    assert!(matches!(parse_raw_tree("SELECT\0 1"), Err(Error::Conversion(_))));
+}
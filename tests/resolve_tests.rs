@@ -0,0 +1,204 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse, ColumnBinding, ParseResult, RangeEntryKind, Resolution};
+
+#[macro_use]
+mod support;
+
+/// Returns the name of the entry each column reference is bound to, as `path -> name`.
+fn bindings(result: &ParseResult) -> Vec<String> {
+    let resolution = result.resolve();
+    resolution.columns().iter().map(|column| format!("{} -> {}", column.path, describe(&resolution, &column.binding))).collect()
+}
+
+fn describe(resolution: &Resolution, binding: &ColumnBinding) -> String {
+    let name = |entry: &usize| {
+        let entry = &resolution.entries()[*entry];
+        let kind = match entry.kind {
+            RangeEntryKind::Relation(_, Some(_)) => "cte",
+            RangeEntryKind::Relation(..) => "table",
+            RangeEntryKind::Cte(_) => "cte definition",
+            RangeEntryKind::Subquery(_) => "subquery",
+            RangeEntryKind::Function(_) => "function",
+            RangeEntryKind::TableFunction(_) => "table function",
+            RangeEntryKind::Join(_) => "join",
+            RangeEntryKind::Excluded => "excluded",
+        };
+        format!("{kind} {}", entry.name.as_deref().unwrap_or("?"))
+    };
+    match binding {
+        ColumnBinding::Entry(entry) => name(entry),
+        ColumnBinding::Ambiguous(entries) => format!("one of {}", entries.iter().map(name).collect::<Vec<_>>().join(", ")),
+        ColumnBinding::Star(entries) => format!("all of {}", entries.iter().map(name).collect::<Vec<_>>().join(", ")),
+        ColumnBinding::Output(index) => format!("output {index}"),
+        ColumnBinding::Unresolved => "unresolved".into(),
+    }
+}
+
+#[test]
+fn it_resolves_CTEs_shadowing_tables() {
+    let result = parse("with f as (select * from f limit 1) select * from f").unwrap();
+    let resolution = result.resolve();
+    let tables: Vec<_> = resolution.tables().iter().map(|table| (table.path.to_string(), table.cte.is_some())).collect();
+    assert_eq!(tables, [("stmts[0].with_clause.ctes[0].ctequery.from_clause[0]".to_string(), false), ("stmts[0].from_clause[0]".to_string(), true)]);
+    assert_eq!(result.tables(), ["f"]);
+    assert_eq!(result.cte_names, ["f"]);
+
+    // CTEs are visible to the CTEs after them, and to themselves in WITH RECURSIVE
+    let result = parse("WITH a AS (SELECT * FROM b), b AS (SELECT * FROM a) SELECT * FROM b").unwrap();
+    let ctes: Vec<_> = result.resolve().tables().iter().map(|table| table.cte.is_some()).collect();
+    assert_eq!(ctes, [false, true, true]);
+    let result = parse("WITH RECURSIVE t AS (SELECT 1 AS n UNION ALL SELECT n + 1 FROM t) SELECT n FROM t").unwrap();
+    assert!(result.resolve().tables().iter().all(|table| table.cte.is_some()));
+    assert!(result.tables().is_empty());
+}
+
+#[test]
+fn it_scopes_aliases_to_their_subquery() {
+    let result = parse("SELECT x.a FROM (SELECT x.b FROM orders x) s, users x WHERE s.b = x.id AND EXISTS (SELECT 1 FROM items x WHERE x.id = s.b)").unwrap();
+    assert_eq!(
+        bindings(&result),
+        [
+            "stmts[0].from_clause[0].subquery.target_list[0].val -> table x",
+            "stmts[0].target_list[0].val -> table x",
+            "stmts[0].where_clause.args[0].lexpr -> subquery s",
+            "stmts[0].where_clause.args[0].rexpr -> table x",
+            "stmts[0].where_clause.args[1].subselect.where_clause.lexpr -> table x",
+            "stmts[0].where_clause.args[1].subselect.where_clause.rexpr -> subquery s",
+        ]
+    );
+    let resolution = result.resolve();
+    let relations: Vec<_> = resolution
+        .columns()
+        .iter()
+        .map(|column| match column.binding {
+            ColumnBinding::Entry(entry) => match resolution.entries()[entry].kind {
+                RangeEntryKind::Relation(relation, _) => relation.relname.as_str(),
+                _ => "-",
+            },
+            _ => "?",
+        })
+        .collect();
+    assert_eq!(relations, ["orders", "users", "-", "users", "items", "-"]);
+}
+
+#[test]
+fn it_binds_unqualified_columns() {
+    // Subqueries and aliases with column names are known to have them, tables may have any column
+    let result = parse("SELECT a, b, c, d FROM (SELECT 1 AS a) s, t1, generate_series(1, 2) AS g (b)").unwrap();
+    assert_eq!(
+        bindings(&result),
+        [
+            "stmts[0].target_list[0].val -> subquery s",
+            "stmts[0].target_list[1].val -> function g",
+            "stmts[0].target_list[2].val -> table t1",
+            "stmts[0].target_list[3].val -> table t1",
+        ]
+    );
+
+    let result = parse("SELECT id FROM users, orders WHERE id IN (SELECT user_id FROM items)").unwrap();
+    assert_eq!(
+        bindings(&result),
+        [
+            "stmts[0].target_list[0].val -> one of table users, table orders",
+            "stmts[0].where_clause.testexpr -> one of table users, table orders",
+            "stmts[0].where_clause.subselect.target_list[0].val -> table items",
+        ]
+    );
+
+    let result = parse("SELECT * FROM users u, LATERAL (SELECT * FROM orders WHERE user_id = u.id) o ORDER BY o.id").unwrap();
+    assert_eq!(
+        bindings(&result),
+        [
+            "stmts[0].from_clause[1].subquery.target_list[0].val -> all of table orders",
+            "stmts[0].from_clause[1].subquery.where_clause.lexpr -> table orders",
+            "stmts[0].from_clause[1].subquery.where_clause.rexpr -> table u",
+            "stmts[0].sort_clause[0].node -> subquery o",
+            "stmts[0].target_list[0].val -> all of table u, subquery o",
+        ]
+    );
+    // Without LATERAL, the other FROM items aren't visible
+    let result = parse("SELECT * FROM users u, (SELECT * FROM orders o WHERE o.user_id = u.id) o").unwrap();
+    assert_eq!(bindings(&result)[2], "stmts[0].from_clause[1].subquery.where_clause.rexpr -> unresolved");
+
+    let result = parse("SELECT a AS x FROM t ORDER BY x, a").unwrap();
+    assert_eq!(bindings(&result), ["stmts[0].sort_clause[0].node -> output 0", "stmts[0].sort_clause[1].node -> table t", "stmts[0].target_list[0].val -> table t"]);
+
+    // The ORDER BY of a set operation can only name the output columns of its left-most query
+    let result = parse("SELECT a FROM t UNION SELECT b FROM u ORDER BY a, b").unwrap();
+    assert_eq!(
+        bindings(&result),
+        [
+            "stmts[0].sort_clause[0].node -> output 0",
+            "stmts[0].sort_clause[1].node -> unresolved",
+            "stmts[0].larg.target_list[0].val -> table t",
+            "stmts[0].rarg.target_list[0].val -> table u",
+        ]
+    );
+    let result = parse("SELECT (SELECT a FROM t UNION SELECT 1 ORDER BY b) FROM v").unwrap();
+    assert_eq!(bindings(&result)[0], "stmts[0].target_list[0].val.subselect.sort_clause[0].node -> unresolved");
+}
+
+#[test]
+fn it_resolves_joins() {
+    let result = parse("SELECT id, a.x, j.y FROM (a JOIN b USING (id)) AS j, c").unwrap();
+    assert_eq!(
+        bindings(&result),
+        [
+            "stmts[0].target_list[0].val -> one of join j, table c",
+            "stmts[0].target_list[1].val -> unresolved",
+            "stmts[0].target_list[2].val -> join j",
+        ]
+    );
+
+    let result = parse("SELECT id, name FROM a JOIN b USING (id) JOIN c ON c.a_id = a.id").unwrap();
+    assert_eq!(
+        bindings(&result),
+        [
+            "stmts[0].from_clause[0].quals.lexpr -> table c",
+            "stmts[0].from_clause[0].quals.rexpr -> table a",
+            "stmts[0].target_list[0].val -> join ?",
+            "stmts[0].target_list[1].val -> one of table a, table b, table c",
+        ]
+    );
+}
+
+#[test]
+fn it_resolves_DML_targets() {
+    let result = parse("WITH n AS (SELECT 1 AS id) UPDATE users u SET name = o.name FROM orders o WHERE u.id = o.user_id AND id IN (SELECT id FROM n) RETURNING u.id").unwrap();
+    assert_eq!(
+        bindings(&result),
+        [
+            "stmts[0].target_list[0].val -> table o",
+            "stmts[0].where_clause.args[0].lexpr -> table u",
+            "stmts[0].where_clause.args[0].rexpr -> table o",
+            "stmts[0].where_clause.args[1].testexpr -> one of table u, table o",
+            "stmts[0].where_clause.args[1].subselect.target_list[0].val -> cte n",
+            "stmts[0].returning_list[0].val -> table u",
+        ]
+    );
+
+    let result = parse("INSERT INTO t (id, n) SELECT id, n FROM s ON CONFLICT (id) DO UPDATE SET n = excluded.n + n").unwrap();
+    assert_eq!(
+        bindings(&result),
+        [
+            "stmts[0].select_stmt.target_list[0].val -> table s",
+            "stmts[0].select_stmt.target_list[1].val -> table s",
+            "stmts[0].on_conflict_clause.target_list[0].val.lexpr -> excluded excluded",
+            "stmts[0].on_conflict_clause.target_list[0].val.rexpr -> table t",
+        ]
+    );
+}
+
+#[test]
+fn it_finds_references_by_node() {
+    let result = parse("SELECT u.id FROM users u").unwrap();
+    let resolution = result.resolve();
+    let column = resolution.columns()[0].node;
+    assert_eq!(resolution.column(column).unwrap().binding, ColumnBinding::Entry(0));
+    let table = resolution.tables()[0].node;
+    assert_eq!(resolution.table(table).unwrap().entry, Some(0));
+    assert!(resolution.column(&column.clone()).is_none());
+    assert_eq!(resolution.scopes().len(), 2);
+}
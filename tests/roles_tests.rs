@@ -0,0 +1,79 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse, ColumnRole, ColumnUse, TableRole, TableUse};
+
+#[macro_use]
+mod support;
+
+fn tables(query: &str) -> Vec<(String, TableRole)> {
+    parse(query).unwrap().table_uses().into_iter().map(|TableUse { name, role, .. }| (name, role)).collect()
+}
+
+/// Returns the columns referenced by the query as `table.column Role`, with `?` for columns not bound to a table.
+fn columns(query: &str) -> Vec<String> {
+    let describe = |ColumnUse { table, column, role, .. }| format!("{}.{column} {role:?}", table.unwrap_or_else(|| "?".into()));
+    parse(query).unwrap().column_uses().into_iter().map(describe).collect()
+}
+
+fn named(tables: &[(&str, TableRole)]) -> Vec<(String, TableRole)> {
+    tables.iter().map(|(name, role)| (name.to_string(), *role)).collect()
+}
+
+#[test]
+fn it_finds_DML_targets() {
+    use TableRole::*;
+    assert_eq!(tables("INSERT INTO t (a) SELECT a FROM src"), named(&[("t", InsertTarget), ("src", ReadInDml)]));
+    assert_eq!(tables("UPDATE t SET a = 1 WHERE id IN (SELECT id FROM s)"), named(&[("t", UpdateTarget), ("s", ReadInDml)]));
+    assert_eq!(tables("DELETE FROM public.t USING s WHERE t.id = s.id"), named(&[("public.t", DeleteTarget), ("s", ReadInDml)]));
+    assert_eq!(
+        tables("MERGE INTO t USING (SELECT * FROM s) AS src ON t.id = src.id WHEN MATCHED THEN UPDATE SET a = src.a"),
+        named(&[("t", MergeTarget), ("s", MergeSource)])
+    );
+    // Data-modifying CTEs, whose references aren't tables
+    assert_eq!(
+        tables("WITH d AS (DELETE FROM old RETURNING *) INSERT INTO archive SELECT * FROM d"),
+        named(&[("archive", InsertTarget), ("old", DeleteTarget)])
+    );
+    assert_eq!(tables("SELECT * FROM a WHERE EXISTS (SELECT 1 FROM b)"), named(&[("a", Select), ("b", Select)]));
+}
+
+#[test]
+fn it_finds_locked_and_truncated_tables() {
+    use TableRole::*;
+    assert_eq!(tables("SELECT * FROM a JOIN b ON a.id = b.a_id FOR UPDATE OF b"), named(&[("a", Select), ("b", Select), ("b", Lock)]));
+    assert_eq!(tables("SELECT * FROM a, b x FOR SHARE"), named(&[("a", Select), ("b", Select), ("a", Lock), ("b", Lock)]));
+    assert_eq!(tables("SELECT * FROM a x FOR UPDATE OF x FOR SHARE"), named(&[("a", Select), ("a", Lock)]));
+    assert_eq!(tables("LOCK TABLE a IN SHARE MODE"), named(&[("a", Lock)]));
+    assert_eq!(tables("TRUNCATE a, b"), named(&[("a", Truncate), ("b", Truncate)]));
+    assert_eq!(tables("CREATE TABLE n AS SELECT * FROM a"), named(&[("a", Select), ("n", Ddl)]));
+}
+
+#[test]
+fn it_finds_column_roles() {
+    assert_eq!(
+        columns("SELECT a.x, count(*) FROM a JOIN b ON a.id = b.a_id WHERE b.y > 1 GROUP BY a.x HAVING sum(b.z) > 1 ORDER BY a.x"),
+        ["a.x Output", "a.id JoinCondition", "b.a_id JoinCondition", "b.y Filter", "a.x GroupBy", "b.z Filter", "a.x OrderBy"]
+    );
+    // The output of a subquery is used by the clause it's in
+    assert_eq!(
+        columns("SELECT id, (SELECT max(total) FROM orders) FROM users WHERE id IN (SELECT user_id FROM orders)"),
+        ["users.id Output", "orders.total Output", "users.id Filter", "orders.user_id Filter"]
+    );
+}
+
+#[test]
+fn it_finds_column_roles_in_DML() {
+    assert_eq!(
+        columns("UPDATE users u SET name = o.name FROM orders o WHERE u.id = o.user_id RETURNING u.id"),
+        ["orders.name Assignment", "users.id Filter", "orders.user_id Filter", "users.id Returning"]
+    );
+    assert_eq!(
+        columns("INSERT INTO t (id, n) VALUES (1, 2) ON CONFLICT (id) DO UPDATE SET n = excluded.n WHERE t.n < excluded.n"),
+        ["?.n Assignment", "t.n Filter", "?.n Filter"]
+    );
+    assert_eq!(
+        columns("MERGE INTO t USING s ON t.id = s.id WHEN MATCHED AND s.deleted THEN DELETE WHEN NOT MATCHED THEN INSERT (id) VALUES (s.id)"),
+        ["t.id JoinCondition", "s.id JoinCondition", "s.deleted Filter", "s.id Assignment"]
+    );
+}
@@ -0,0 +1,87 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse, Error, NodeRef, Selector};
+
+#[macro_use]
+mod support;
+
+fn find(selector: &str, query: &str) -> Vec<String> {
+    let result = parse(query).unwrap();
+    Selector::new(selector).unwrap().find(&result.protobuf).into_iter().map(|(path, _)| path.to_string()).collect()
+}
+
+#[test]
+fn it_finds_nodes_by_type() {
+    let query = "SELECT a, count(*) FROM users JOIN orders ON users.id = orders.user_id GROUP BY a";
+    assert_eq!(find("RangeVar", query), ["stmts[0].from_clause[0].larg", "stmts[0].from_clause[0].rarg"]);
+    assert_eq!(find("FuncCall", query), ["stmts[0].target_list[1].val"]);
+    // Type names may also be written as in libpg_query's JSON
+    assert_eq!(find("A_Expr", query), find("AExpr", query));
+    assert_eq!(find("*", "SELECT 1").len(), 4);
+}
+
+#[test]
+fn it_matches_attributes() {
+    let query = "SELECT * FROM users u, public.orders WHERE u.tenant_id = 1 AND tenant = 'x' AND lower(name) = 'y'";
+    assert_eq!(find(r#"RangeVar[relname="users"]"#, query), ["stmts[0].from_clause[0]"]);
+    assert_eq!(find("RangeVar[schemaname]", query), ["stmts[0].from_clause[1]"]);
+    assert_eq!(find("RangeVar[alias]", query), ["stmts[0].from_clause[0]"]);
+    assert_eq!(find("RangeVar[relname!=users]", query), ["stmts[0].from_clause[1]"]);
+    // Names match on their trailing components
+    assert_eq!(find(r#"ColumnRef[fields="tenant_id"]"#, query), ["stmts[0].where_clause.args[0].lexpr"]);
+    assert_eq!(find(r#"ColumnRef[fields="u.tenant_id"]"#, query), ["stmts[0].where_clause.args[0].lexpr"]);
+    assert_eq!(find(r#"ColumnRef[fields="orders.tenant_id"]"#, query), Vec::<String>::new());
+    assert_eq!(find("FuncCall[funcname=lower]", query), ["stmts[0].where_clause.args[2].lexpr"]);
+    // Enums match by name
+    assert_eq!(find("BoolExpr[boolop=AND_EXPR]", query), ["stmts[0].where_clause"]);
+    assert_eq!(find("SelectStmt[op=SETOP_UNION]", "SELECT 1 UNION SELECT 2"), ["stmts[0]"]);
+    assert_eq!(find("AConst[isnull=true]", "SELECT NULL, 1"), ["stmts[0].target_list[0].val"]);
+}
+
+#[test]
+fn it_matches_descendants_and_children() {
+    let query = "UPDATE accounts SET tenant_id = 2 WHERE tenant_id = (SELECT tenant_id FROM users WHERE id = 1)";
+    assert_eq!(
+        find(r#"UpdateStmt > whereClause ColumnRef[fields="tenant_id"]"#, query),
+        ["stmts[0].where_clause.lexpr", "stmts[0].where_clause.rexpr.subselect.target_list[0].val"]
+    );
+    assert_eq!(find(r#"UpdateStmt > whereClause > ColumnRef[fields="tenant_id"]"#, query), ["stmts[0].where_clause.lexpr"]);
+    assert_eq!(find("SubLink SelectStmt > whereClause", query), ["stmts[0].where_clause.rexpr.subselect.where_clause"]);
+    assert_eq!(find("UpdateStmt > RangeVar", query), ["stmts[0].relation"]);
+    assert_eq!(find("SelectStmt > fromClause", query), ["stmts[0].where_clause.rexpr.subselect.from_clause[0]"]);
+    assert_eq!(find("DeleteStmt ColumnRef", query), Vec::<String>::new());
+}
+
+#[test]
+fn it_matches_alternatives() {
+    let result = parse("SELECT * FROM t WHERE a = $1; DELETE FROM u WHERE b = $2").unwrap();
+    let selector: Selector = "DeleteStmt ParamRef, SelectStmt > RangeVar".parse().unwrap();
+    let matches = selector.find(&result.protobuf);
+    assert_eq!(matches.len(), 2);
+    assert!(matches!(matches[0].1, NodeRef::RangeVar(table) if table.relname == "t"));
+    assert!(matches!(matches[1].1, NodeRef::ParamRef(param) if param.number == 2));
+    assert!(selector.is_match(&result.protobuf));
+    assert!(!Selector::new("InsertStmt").unwrap().is_match(&result.protobuf));
+    assert_eq!(selector.to_string(), "DeleteStmt ParamRef, SelectStmt > RangeVar");
+}
+
+#[test]
+fn it_rejects_invalid_selectors() {
+    for selector in
+        ["", "SelectStmt >", "NoSuchStmt", "RangeVar[relname", "RangeVar[relname=]", "RangeVar[relname='users]", "RangeVar, ", "RangeVar ! x"]
+    {
+        assert!(matches!(Selector::new(selector), Err(Error::InvalidSelector(_))), "{selector}");
+    }
+    assert_eq!(Selector::new("NoSuchStmt"), Err(Error::InvalidSelector(r#"unknown node type NoSuchStmt at position 0 of "NoSuchStmt""#.to_string())));
+
+    // Field names are checked against the fields of the node type, or of any node type
+    assert_eq!(
+        Selector::new(r#"ColumnRef[field="tenant_id"]"#),
+        Err(Error::InvalidSelector(r#"unknown field field of ColumnRef at position 10 of "ColumnRef[field=\"tenant_id\"]""#.to_string()))
+    );
+    for selector in ["SelectStmt > whereClauses", "*[relnam]", "fromClause[nope]", "RangeVar[fields]"] {
+        assert!(matches!(Selector::new(selector), Err(Error::InvalidSelector(_))), "{selector}");
+    }
+    assert!(Selector::new("*[relname]").is_ok());
+}
@@ -0,0 +1,95 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::protobuf::Alias;
+use pg_query::{parse, parse_raw, NodeRef, ParseResult};
+
+#[macro_use]
+mod support;
+
+/// Returns the source text of the nodes for which `filter` holds, in the order of [ParseResult::nodes].
+fn texts(result: &ParseResult, filter: impl Fn(&NodeRef) -> bool) -> Vec<&str> {
+    result.protobuf.nodes().into_iter().filter(|(node, ..)| filter(node)).map(|(node, ..)| result.source_text(&node).unwrap()).collect()
+}
+
+#[test]
+fn it_spans_statements() {
+    let query = "SELECT 1; /* next */ UPDATE t SET a = 1 WHERE b = 2 ; -- done";
+    let result = parse(query).unwrap();
+    let stmts: Vec<_> = result.protobuf.stmts.iter().map(|stmt| stmt.stmt.as_ref().unwrap().node.as_ref().unwrap().to_ref()).collect();
+    assert_eq!(result.span(&stmts[0]), Some(0..8));
+    assert_eq!(result.source_text(&stmts[1]), Some("UPDATE t SET a = 1 WHERE b = 2"));
+}
+
+#[test]
+fn it_spans_expressions() {
+    let result = parse("SELECT * FROM t WHERE (a = 1 OR b = 2) AND c IS NOT NULL").unwrap();
+    assert_eq!(texts(&result, |node| matches!(node, NodeRef::BoolExpr(_))), ["(a = 1 OR b = 2) AND c IS NOT NULL", "a = 1 OR b = 2"]);
+    assert_eq!(texts(&result, |node| matches!(node, NodeRef::NullTest(_))), ["c IS NOT NULL"]);
+    assert_eq!(texts(&result, |node| matches!(node, NodeRef::AExpr(_))), ["a = 1", "b = 2"]);
+}
+
+#[test]
+fn it_spans_closing_tokens() {
+    let result = parse("SELECT count(*), now(), lower(name), CASE WHEN a THEN 1 ELSE 2 END AS x FROM t ORDER BY x DESC NULLS LAST").unwrap();
+    assert_eq!(texts(&result, |node| matches!(node, NodeRef::FuncCall(_))), ["count(*)", "now()", "lower(name)"]);
+    assert_eq!(texts(&result, |node| matches!(node, NodeRef::CaseExpr(_))), ["CASE WHEN a THEN 1 ELSE 2 END"]);
+    assert_eq!(texts(&result, |node| matches!(node, NodeRef::SortBy(_))), ["x DESC NULLS LAST"]);
+}
+
+#[test]
+fn it_spans_nested_statements() {
+    let result = parse("SELECT * FROM (SELECT id FROM users) u WHERE EXISTS (SELECT 1 FROM orders WHERE orders.user_id = u.id)").unwrap();
+    let mut subqueries = texts(&result, |node| matches!(node, NodeRef::SelectStmt(_)));
+    subqueries.sort();
+    assert_eq!(
+        subqueries,
+        [
+            "SELECT * FROM (SELECT id FROM users) u WHERE EXISTS (SELECT 1 FROM orders WHERE orders.user_id = u.id)",
+            "SELECT 1 FROM orders WHERE orders.user_id = u.id",
+            "SELECT id FROM users"
+        ]
+    );
+
+    let result = parse("WITH moved AS (DELETE FROM jobs WHERE done RETURNING *) INSERT INTO archive (id, done) VALUES (1, true)").unwrap();
+    assert_eq!(texts(&result, |node| matches!(node, NodeRef::DeleteStmt(_))), ["DELETE FROM jobs WHERE done RETURNING *"]);
+    assert_eq!(texts(&result, |node| matches!(node, NodeRef::SelectStmt(_))), ["VALUES (1, true)"]);
+}
+
+#[test]
+fn it_matches_parse_raw() {
+    let query = "SELECT a + b FROM t WHERE c = $1";
+    let (result, raw) = (parse(query).unwrap(), parse_raw(query).unwrap());
+    assert_eq!(raw.statement(), Some(query));
+    let filter = |node: &NodeRef| matches!(node, NodeRef::AExpr(_));
+    assert_eq!(texts(&raw, filter), texts(&result, filter));
+}
+
+#[test]
+fn it_returns_none_without_locations() {
+    let result = parse("SELECT 1").unwrap();
+    assert_eq!(result.span(&NodeRef::Alias(&Alias { aliasname: "x".to_string(), colnames: vec![] })), None);
+
+    let loaded = ParseResult::from_json(&result.to_json()).unwrap();
+    let stmt = loaded.protobuf.stmts[0].stmt.as_ref().unwrap().node.as_ref().unwrap().to_ref();
+    assert_eq!(loaded.statement(), None);
+    assert_eq!(loaded.span(&stmt), None);
+    assert_eq!(loaded.line_column(0), None);
+
+    // Until the statement is set
+    let loaded = ParseResult::from_json(&result.to_json()).unwrap().with_statement("SELECT 1");
+    let stmt = loaded.protobuf.stmts[0].stmt.as_ref().unwrap().node.as_ref().unwrap().to_ref();
+    assert_eq!(loaded.source_text(&stmt), Some("SELECT 1"));
+}
+
+#[test]
+fn it_converts_offsets_to_lines_and_columns() {
+    let result = parse("SELECT 'é',\n  b\nFROM t").unwrap();
+    assert_eq!(result.line_column(0), Some((1, 1)));
+    assert_eq!(result.line_column(8), Some((1, 9)));
+    assert_eq!(result.line_column(15), Some((2, 3)));
+    assert_eq!(result.line_column(100), Some((3, 7)));
+    // Offsets within a character count as that character
+    assert_eq!(result.line_column(9), Some((1, 9)));
+    assert_eq!(result.line_column(10), Some((1, 10)));
+}
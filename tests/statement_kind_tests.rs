@@ -0,0 +1,44 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse, summary, Error, StatementKind};
+
+#[macro_use]
+mod support;
+
+#[test]
+fn it_returns_statement_kinds() {
+    let query = "SELECT 1; UPDATE t SET a = 1; CREATE LANGUAGE plsample; ALTER TEXT SEARCH DICTIONARY d (a = 1); VACUUM t";
+    let result = parse(query).unwrap();
+    assert_eq!(
+        result.statement_kinds(),
+        [StatementKind::Select, StatementKind::Update, StatementKind::CreatePlang, StatementKind::AlterTsdictionary, StatementKind::Vacuum]
+    );
+    assert_eq!(result.statement_types(), ["SelectStmt", "UpdateStmt", "CreatePLangStmt", "AlterTSDictionaryStmt", "VacuumStmt"]);
+    assert_eq!(summary(query, -1).unwrap().statement_kinds(), result.statement_kinds());
+}
+
+#[test]
+fn it_converts_statement_kinds_to_and_from_strings() {
+    for kind in StatementKind::ALL {
+        assert_eq!(kind.as_str().parse::<StatementKind>(), Ok(*kind));
+        assert_eq!(kind.to_string(), kind.as_str());
+    }
+    assert_eq!("AlterTSConfigurationStmt".parse::<StatementKind>(), Ok(StatementKind::AlterTsconfiguration));
+    assert_eq!("CreatePlangStmt".parse::<StatementKind>(), Err(Error::InvalidStatementKind("CreatePlangStmt".into())));
+}
+
+#[test]
+fn it_categorizes_statement_kinds() {
+    for kind in [StatementKind::Select, StatementKind::Insert, StatementKind::Update, StatementKind::Delete, StatementKind::Merge] {
+        assert!(kind.is_dml() && !kind.is_utility() && !kind.is_ddl(), "{kind}");
+    }
+    for kind in
+        [StatementKind::Create, StatementKind::AlterTable, StatementKind::Drop, StatementKind::Index, StatementKind::Grant, StatementKind::Cluster]
+    {
+        assert!(kind.is_ddl() && kind.is_utility() && !kind.is_dml(), "{kind}");
+    }
+    for kind in [StatementKind::Transaction, StatementKind::Copy, StatementKind::Truncate, StatementKind::VariableSet, StatementKind::Explain] {
+        assert!(!kind.is_ddl() && kind.is_utility() && !kind.is_dml(), "{kind}");
+    }
+}
@@ -0,0 +1,82 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use std::collections::HashMap;
+
+use pg_query::protobuf::Node;
+use pg_query::{parse, NodeRef, StructuralHash, StructuralOptions, TraversalOrder};
+
+#[macro_use]
+mod support;
+
+fn stmt(query: &str) -> Node {
+    *parse(query).unwrap().protobuf.stmts.remove(0).stmt.unwrap()
+}
+
+const IGNORE_ALIASES: StructuralOptions = StructuralOptions { ignore_aliases: true };
+
+#[test]
+fn it_ignores_locations() {
+    let (first, second) = (stmt("SELECT a + 1 FROM t WHERE b = $1"), stmt("  select a+1\n    from t\n   where b = $1"));
+    assert_ne!(first, second);
+    assert!(first.structurally_eq(&second));
+    assert_eq!(first.structural_hash(), second.structural_hash());
+
+    for other in ["SELECT a + 2 FROM t WHERE b = $1", "SELECT a + 1 FROM t WHERE b = $2", "SELECT a - 1 FROM t WHERE b = $1", "SELECT a + 1 FROM t"] {
+        let other = stmt(other);
+        assert!(!first.structurally_eq(&other));
+        assert_ne!(first.structural_hash(), other.structural_hash());
+    }
+    assert!(!stmt("SELECT 1").structurally_eq(&stmt("SELECT '1'")));
+}
+
+#[test]
+fn it_optionally_ignores_aliases() {
+    let (first, second) = (stmt("SELECT u.id AS key FROM users u"), stmt("SELECT u.id AS id FROM users AS u"));
+    assert!(!first.structurally_eq(&second));
+    assert!(first.structurally_eq_with(&second, IGNORE_ALIASES));
+    assert_eq!(first.structural_hash_with(IGNORE_ALIASES), second.structural_hash_with(IGNORE_ALIASES));
+    assert_ne!(first.structural_hash(), second.structural_hash());
+
+    // References to aliases, and target columns, are still compared
+    assert!(!first.structurally_eq_with(&stmt("SELECT x.id AS key FROM users x"), IGNORE_ALIASES));
+    assert!(!stmt("UPDATE t SET a = 1").structurally_eq_with(&stmt("UPDATE t SET b = 1"), IGNORE_ALIASES));
+    assert!(!stmt("INSERT INTO t (a) VALUES (1)").structurally_eq_with(&stmt("INSERT INTO t (b) VALUES (1)"), IGNORE_ALIASES));
+    assert!(stmt("DELETE FROM t RETURNING a AS x").structurally_eq_with(&stmt("DELETE FROM t RETURNING a"), IGNORE_ALIASES));
+}
+
+#[test]
+fn it_hashes_every_subtree() {
+    let result = parse("SELECT lower(name) FROM users WHERE lower(name) = 'x' OR upper(name) = 'x'").unwrap();
+    let root = result.protobuf.stmts[0].stmt.as_ref().unwrap().node.as_ref().unwrap().to_ref();
+    let subtrees = StructuralHash::subtrees(root, StructuralOptions::default());
+    assert_eq!(subtrees.len(), root.to_enum().iter_nodes().count());
+
+    // Children come before their parents, and the root last
+    let order: Vec<_> = root.to_enum().iter_nodes_with_order(TraversalOrder::PostOrder).map(|(path, _)| path).collect();
+    assert_eq!(subtrees.iter().map(|(path, _, _)| path.clone()).collect::<Vec<_>>(), order);
+    let (path, node, hash) = subtrees.last().unwrap();
+    assert!(path.is_empty() && matches!(node, NodeRef::SelectStmt(_)));
+    assert_eq!(*hash, StructuralHash::of(root, StructuralOptions::default()));
+    for (path, node, hash) in &subtrees {
+        assert_eq!(*hash, node.structural_hash(), "{path}");
+    }
+
+    // Repeated subexpressions share a hash
+    let mut calls: HashMap<StructuralHash, Vec<String>> = HashMap::new();
+    for (path, node, hash) in &subtrees {
+        if let NodeRef::FuncCall(_) = node {
+            calls.entry(*hash).or_default().push(path.to_string());
+        }
+    }
+    let mut calls: Vec<_> = calls.into_values().collect();
+    calls.sort();
+    assert_eq!(calls, [vec!["target_list[0].val", "where_clause.args[0].lexpr"], vec!["where_clause.args[1].lexpr"]]);
+}
+
+#[test]
+fn it_compares_empty_nodes() {
+    assert!(Node { node: None }.structurally_eq(&Node { node: None }));
+    assert!(!Node { node: None }.structurally_eq(&stmt("SELECT 1")));
+    assert_eq!(Node { node: None }.structural_hash(), Node { node: None }.structural_hash());
+}
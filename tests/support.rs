@@ -72,6 +72,15 @@ pub fn assert_deparse_raw_roundtrip(query: &str) {
     );
 }
 
+/// Verifies that a parse tree loaded back from JSON is identical to the original, and deparses the same way.
+pub fn assert_json_roundtrip(query: &str) {
+    let parse_result = pg_query::parse(query).expect("parse failed");
+    let json = parse_result.to_json();
+    let loaded = pg_query::ParseResult::from_json(&json).expect(&format!("loading JSON failed for query: {query}"));
+    assert!(parse_result.protobuf == loaded.protobuf, "JSON roundtrip produced a different protobuf for query: {query}");
+    std::assert_eq!(parse_result.deparse(), loaded.deparse(), "JSON roundtrip deparsed differently for query: {query}");
+}
+
 macro_rules! cast {
     ($target: expr, $pat: path) => {{
         if let $pat(a) = $target {
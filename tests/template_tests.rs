@@ -0,0 +1,78 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse, Error, NodeRef, Template};
+
+#[macro_use]
+mod support;
+
+#[test]
+fn it_captures_placeholders() {
+    let template = Template::new("SELECT * FROM $table WHERE id = $value").unwrap();
+    let result = parse("select *\n  from public.users\n where id = 42").unwrap();
+    let bindings = template.captures(&result.protobuf).unwrap();
+    assert_eq!(bindings.len(), 2);
+    assert!(matches!(bindings.get("table"), Some(NodeRef::RangeVar(table)) if table.schemaname == "public" && table.relname == "users"));
+    assert!(matches!(bindings.get("value"), Some(NodeRef::AConst(_))));
+    assert_eq!(bindings.iter().map(|(name, _)| name).collect::<Vec<_>>(), ["table", "value"]);
+
+    // Placeholders capture whatever node stands in their place
+    let result = parse("SELECT * FROM (SELECT * FROM users) u WHERE id = $1").unwrap();
+    let bindings = template.captures(&result.protobuf).unwrap();
+    assert!(matches!(bindings.get("table"), Some(NodeRef::RangeSubselect(_))));
+    assert!(matches!(bindings.get("value"), Some(NodeRef::ParamRef(param)) if param.number == 1));
+}
+
+#[test]
+fn it_matches_structurally() {
+    let template = Template::new("SELECT * FROM $table WHERE id = $value").unwrap();
+    for query in [
+        "SELECT * FROM users WHERE name = 'x'",
+        "SELECT * FROM users WHERE id = 1 AND tenant_id = 2",
+        "SELECT id FROM users WHERE id = 1",
+        "SELECT * FROM users WHERE id > 1",
+        "SELECT * FROM users WHERE id = 1; SELECT 1",
+        "DELETE FROM users WHERE id = 1",
+    ] {
+        assert!(!template.is_match(&parse(query).unwrap().protobuf), "{query}");
+    }
+
+    let template = Template::new("INSERT INTO $table (id, name) VALUES ($id, $name) ON CONFLICT DO NOTHING").unwrap();
+    let result = parse("INSERT INTO accounts (id, name) VALUES ($1, lower($2)) ON CONFLICT DO NOTHING").unwrap();
+    let bindings = template.captures(&result.protobuf).unwrap();
+    assert!(matches!(bindings.get("name"), Some(NodeRef::FuncCall(_))));
+    assert!(!template.is_match(&parse("INSERT INTO accounts (id, name) VALUES ($1, $2)").unwrap().protobuf));
+
+    // Numbered parameters are matched as they are
+    let template = Template::new("SELECT * FROM t WHERE id = $1").unwrap();
+    assert!(template.is_match(&parse("SELECT * FROM t WHERE id = $1").unwrap().protobuf));
+    assert!(!template.is_match(&parse("SELECT * FROM t WHERE id = $2").unwrap().protobuf));
+}
+
+#[test]
+fn it_requires_repeated_placeholders_to_capture_equal_nodes() {
+    let template = Template::new("SELECT $column FROM t WHERE $column IS NOT NULL").unwrap();
+    assert!(template.is_match(&parse("SELECT t.a FROM t WHERE t.a IS NOT NULL").unwrap().protobuf));
+    assert!(!template.is_match(&parse("SELECT a FROM t WHERE b IS NOT NULL").unwrap().protobuf));
+}
+
+#[test]
+fn it_captures_names() {
+    let template = Template::new("SELECT $func(x) FROM t").unwrap();
+    let result = parse("SELECT lower(x) FROM t").unwrap();
+    let bindings = template.captures(&result.protobuf).unwrap();
+    assert!(matches!(bindings.get("func"), Some(NodeRef::String(name)) if name.sval == "lower"));
+}
+
+#[test]
+fn it_leaves_strings_and_comments_alone() {
+    let template = Template::new("SELECT '$value', $$ $body $$ /* $comment */ FROM t").unwrap();
+    let result = parse("SELECT '$value', ' $body ' FROM t").unwrap();
+    assert!(template.captures(&result.protobuf).unwrap().is_empty());
+    assert!(!template.is_match(&parse("SELECT 'x', ' $body ' FROM t").unwrap().protobuf));
+}
+
+#[test]
+fn it_rejects_invalid_templates() {
+    assert!(matches!(Template::new("SELECT * FROM WHERE id = $value"), Err(Error::Syntax(_))));
+}
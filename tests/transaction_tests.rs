@@ -0,0 +1,110 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse, TransactionState, TransactionTracker, TransactionTransition};
+
+#[macro_use]
+mod support;
+
+use TransactionState::*;
+
+/// Runs the simple queries, returning the state and savepoint depth after each of them.
+fn states(tracker: &mut TransactionTracker, queries: &[&str]) -> Vec<(TransactionState, usize)> {
+    queries
+        .iter()
+        .map(|query| {
+            tracker.simple_query(&parse(query).unwrap(), None);
+            (tracker.state(), tracker.savepoint_depth())
+        })
+        .collect()
+}
+
+fn transition(from: TransactionState, to: TransactionState, savepoint_depth: usize, error: bool) -> TransactionTransition {
+    TransactionTransition { from, to, savepoint_depth, error }
+}
+
+#[test]
+fn it_tracks_transaction_blocks() {
+    let mut tracker = TransactionTracker::new();
+    assert_eq!(
+        states(&mut tracker, &["SELECT 1", "BEGIN", "INSERT INTO t VALUES (1)", "COMMIT", "START TRANSACTION", "ROLLBACK", "COMMIT"]),
+        [(Idle, 0), (InTransaction, 0), (InTransaction, 0), (Idle, 0), (InTransaction, 0), (Idle, 0), (Idle, 0)]
+    );
+    assert_eq!(
+        states(&mut tracker, &["BEGIN", "COMMIT AND CHAIN", "PREPARE TRANSACTION 'x'", "COMMIT PREPARED 'x'"]),
+        [(InTransaction, 0), (InTransaction, 0), (Idle, 0), (Idle, 0)]
+    );
+}
+
+#[test]
+fn it_tracks_savepoints() {
+    let mut tracker = TransactionTracker::new();
+    assert_eq!(
+        states(&mut tracker, &["BEGIN", "SAVEPOINT a", "SAVEPOINT b", "SAVEPOINT a", "RELEASE a", "ROLLBACK TO a", "RELEASE SAVEPOINT a", "COMMIT"]),
+        [(InTransaction, 0), (InTransaction, 1), (InTransaction, 2), (InTransaction, 3), (InTransaction, 2), (InTransaction, 1), (InTransaction, 0), (Idle, 0)]
+    );
+    // Unknown savepoints fail the transaction
+    assert_eq!(states(&mut tracker, &["BEGIN", "SAVEPOINT a", "RELEASE b"]), [(InTransaction, 0), (InTransaction, 1), (Failed, 1)]);
+    assert_eq!(states(&mut tracker, &["ROLLBACK TO a", "ROLLBACK"]), [(InTransaction, 1), (Idle, 0)]);
+    // Savepoints need a transaction block
+    assert_eq!(states(&mut tracker, &["SAVEPOINT a"]), [(Idle, 0)]);
+}
+
+#[test]
+fn it_tracks_failed_transactions() {
+    let mut tracker = TransactionTracker::new();
+    tracker.simple_query(&parse("BEGIN").unwrap(), None);
+    assert_eq!(tracker.simple_query(&parse("SELECT 1 / 0").unwrap(), Some(0)), [transition(InTransaction, Failed, 0, true)]);
+    assert_eq!(tracker.simple_query(&parse("SELECT 1").unwrap(), None), [transition(Failed, Failed, 0, true)]);
+    assert_eq!(tracker.simple_query(&parse("COMMIT").unwrap(), None), [transition(Failed, Idle, 0, false)]);
+
+    // Errors outside of transaction blocks don't change the state, and neither does VACUUM
+    assert_eq!(tracker.simple_query(&parse("SELECT 1 / 0").unwrap(), Some(0)), [transition(Idle, Idle, 0, true)]);
+    let vacuum = parse("VACUUM t").unwrap();
+    let statement = vacuum.protobuf.stmts[0].stmt.as_ref().unwrap().node.as_ref().unwrap();
+    assert_eq!(tracker.execute(statement.to_ref(), false), transition(Idle, Idle, 0, false));
+    tracker.simple_query(&parse("BEGIN").unwrap(), None);
+    assert_eq!(tracker.execute(statement.to_ref(), false), transition(InTransaction, Failed, 0, true));
+}
+
+#[test]
+fn it_fails_statements_prevented_in_transaction_blocks() {
+    let mut tracker = TransactionTracker::new();
+    assert_eq!(states(&mut tracker, &["BEGIN", "DISCARD ALL", "ROLLBACK"]), [(InTransaction, 0), (Failed, 0), (Idle, 0)]);
+    assert_eq!(
+        states(&mut tracker, &["BEGIN", "DISCARD PLANS", "CLUSTER t", "COMMIT"]),
+        [(InTransaction, 0), (InTransaction, 0), (InTransaction, 0), (Idle, 0)]
+    );
+    for query in ["CLUSTER", "ALTER TABLE p DETACH PARTITION p1 CONCURRENTLY", "CREATE INDEX CONCURRENTLY ON t (a)"] {
+        assert_eq!(states(&mut tracker, &["BEGIN", query, "ROLLBACK"]), [(InTransaction, 0), (Failed, 0), (Idle, 0)], "{query}");
+    }
+    assert_eq!(states(&mut tracker, &["BEGIN", "ALTER TABLE p DETACH PARTITION p1", "COMMIT"]), [(InTransaction, 0), (InTransaction, 0), (Idle, 0)]);
+}
+
+#[test]
+fn it_tracks_implicit_transactions() {
+    let mut tracker = TransactionTracker::new();
+    assert_eq!(
+        tracker.simple_query(&parse("INSERT INTO t VALUES (1); UPDATE t SET a = 2").unwrap(), None),
+        [transition(Idle, Implicit, 0, false), transition(Implicit, Idle, 0, false)]
+    );
+    // An error rolls back the implicit transaction, and the statements after it don't run
+    assert_eq!(
+        tracker.simple_query(&parse("INSERT INTO t VALUES (1); SELECT 1 / 0; SELECT 1").unwrap(), Some(1)),
+        [transition(Idle, Implicit, 0, false), transition(Implicit, Idle, 0, true)]
+    );
+    assert_eq!(
+        tracker.simple_query(&parse("SELECT 1; VACUUM t").unwrap(), None),
+        [transition(Idle, Implicit, 0, false), transition(Implicit, Idle, 0, true)]
+    );
+    // BEGIN turns the implicit transaction into a transaction block, and COMMIT ends it
+    assert_eq!(
+        tracker.simple_query(&parse("SELECT 1; BEGIN; SELECT 2").unwrap(), None),
+        [transition(Idle, Implicit, 0, false), transition(Implicit, InTransaction, 0, false), transition(InTransaction, InTransaction, 0, false)]
+    );
+    assert_eq!(
+        tracker.simple_query(&parse("COMMIT; SELECT 1").unwrap(), None),
+        [transition(InTransaction, Idle, 0, false), transition(Idle, Idle, 0, false)]
+    );
+    assert_eq!(tracker.state(), Idle);
+}
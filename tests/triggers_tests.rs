@@ -0,0 +1,91 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::{parse, protobuf, NodeEnum, QualifiedName, TransitionTable, TriggerDefinition, TriggerEvent, TriggerTiming};
+
+#[macro_use]
+mod support;
+
+fn definition(query: &str) -> TriggerDefinition {
+    parse(query).unwrap().triggers().remove(0)
+}
+
+#[test]
+fn it_decodes_triggers() {
+    let trigger = definition("CREATE TRIGGER trg BEFORE UPDATE OF a, b ON s.t FOR EACH ROW WHEN (old.a IS DISTINCT FROM new.a) EXECUTE FUNCTION s.f('x', 1)");
+    assert_eq!((trigger.name.as_str(), trigger.relation.schema.as_deref(), trigger.relation.name.as_str()), ("trg", Some("s"), "t"));
+    assert_eq!(trigger.timing, TriggerTiming::Before);
+    assert_eq!(trigger.events.into_iter().collect::<Vec<_>>(), [TriggerEvent::Update]);
+    assert_eq!(trigger.update_columns, ["a", "b"]);
+    assert!(trigger.for_each_row);
+    assert!(matches!(trigger.when.and_then(|when| when.node), Some(NodeEnum::AExpr(_))));
+    assert_eq!((trigger.function.to_string(), trigger.arguments), ("s.f".to_string(), vec!["x".to_string(), "1".to_string()]));
+    assert!(!trigger.or_replace && !trigger.constraint);
+
+    let trigger = definition("CREATE OR REPLACE TRIGGER trg INSTEAD OF INSERT OR DELETE ON v FOR EACH ROW EXECUTE PROCEDURE f()");
+    assert_eq!(trigger.function, QualifiedName { schema: None, name: "f".into() });
+    assert_eq!(trigger.timing, TriggerTiming::InsteadOf);
+    assert_eq!(trigger.events.into_iter().collect::<Vec<_>>(), [TriggerEvent::Insert, TriggerEvent::Delete]);
+    assert!(trigger.or_replace && trigger.arguments.is_empty() && trigger.when.is_none());
+
+    let trigger = definition("CREATE TRIGGER trg AFTER TRUNCATE ON t EXECUTE FUNCTION f()");
+    assert_eq!((trigger.timing, trigger.for_each_row), (TriggerTiming::After, false));
+    assert_eq!(trigger.events.into_iter().collect::<Vec<_>>(), [TriggerEvent::Truncate]);
+}
+
+#[test]
+fn it_decodes_transition_tables() {
+    let trigger = definition("CREATE TRIGGER trg AFTER UPDATE ON t REFERENCING OLD TABLE AS old_rows NEW TABLE AS new_rows FOR EACH STATEMENT EXECUTE FUNCTION f()");
+    assert_eq!(
+        trigger.transition_tables,
+        [TransitionTable { name: "old_rows".into(), is_new: false }, TransitionTable { name: "new_rows".into(), is_new: true }]
+    );
+}
+
+#[test]
+fn it_decodes_constraint_triggers() {
+    let trigger = definition("CREATE CONSTRAINT TRIGGER trg AFTER INSERT ON t FROM s.u DEFERRABLE INITIALLY DEFERRED FOR EACH ROW EXECUTE FUNCTION f()");
+    assert!(trigger.constraint && trigger.deferrable && trigger.initially_deferred);
+    assert_eq!(trigger.referenced_table, Some(QualifiedName { schema: Some("s".into()), name: "u".into() }));
+}
+
+#[test]
+fn it_finds_triggers_in_CREATE_SCHEMA() {
+    let result = parse("CREATE SCHEMA s CREATE TABLE t (a int) CREATE TRIGGER trg AFTER INSERT ON t EXECUTE FUNCTION f(); SELECT 1").unwrap();
+    let names: Vec<_> = result.triggers().into_iter().map(|trigger| trigger.name).collect();
+    assert_eq!(names, ["trg"]);
+}
+
+#[test]
+fn it_builds_triggers() {
+    let trigger = TriggerDefinition {
+        name: "audit".into(),
+        relation: QualifiedName { schema: Some("app".into()), name: "users".into() },
+        timing: TriggerTiming::Before,
+        events: [TriggerEvent::Insert, TriggerEvent::Update].into(),
+        update_columns: vec!["email".into()],
+        for_each_row: true,
+        function: QualifiedName { schema: Some("audit".into()), name: "log".into() },
+        arguments: vec!["users".into()],
+        ..TriggerDefinition::default()
+    };
+    let stmt = protobuf::CreateTrigStmt::from(&trigger);
+    assert_eq!(stmt.timing, pg_query::TriggerType::Before as i32);
+    assert_eq!(stmt.events, pg_query::TriggerType::Insert as i32 | pg_query::TriggerType::Update as i32);
+
+    let sql = trigger.deparse().unwrap();
+    assert_eq!(sql, "CREATE TRIGGER audit BEFORE INSERT OR UPDATE OF email ON app.users FOR EACH ROW EXECUTE FUNCTION audit.log('users')");
+    assert_eq!(parse(&sql).unwrap().triggers(), [trigger]);
+
+    // Names are quoted rather than split, even with a dot
+    let trigger = TriggerDefinition {
+        name: "trg".into(),
+        relation: QualifiedName { schema: None, name: "a.b".into() },
+        events: [TriggerEvent::Insert].into(),
+        function: QualifiedName { schema: Some("s".into()), name: "f.g".into() },
+        ..TriggerDefinition::default()
+    };
+    let sql = trigger.deparse().unwrap();
+    assert_eq!(sql, "CREATE TRIGGER trg AFTER INSERT ON \"a.b\" EXECUTE FUNCTION s.\"f.g\"()");
+    assert_eq!(parse(&sql).unwrap().triggers(), [trigger]);
+}
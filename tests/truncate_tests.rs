@@ -125,3 +125,10 @@ fn byte_truncate_fails() {
     let query = "WITH \"原チコ氏にはす腹腹腹腹腹腹腹腹腹腹腹\" AS (SELECT) SELECT w".to_string();
     query[0..=21].to_string();
 }
+
+#[test]
+fn it_omits_target_lists_of_UNION_arms() {
+    let query = "SELECT a, b, c FROM x UNION SELECT d, e, f FROM y";
+    let result = parse(query).unwrap();
+    assert_eq!(result.truncate(41).unwrap(), "SELECT ... FROM x UNION SELECT ... FROM y")
+}
@@ -0,0 +1,145 @@
+#![allow(non_snake_case)]
+#![cfg(test)]
+
+use pg_query::protobuf::{BoolExpr, BoolExprType, FuncCall, Node, RangeVar, SelectStmt};
+use pg_query::visitor::{walk_func_call, walk_node, walk_select_stmt_mut, Visitor, VisitorMut};
+use pg_query::{parse, NodeEnum};
+
+#[macro_use]
+mod support;
+
+#[derive(Default)]
+struct Functions(Vec<String>);
+
+impl<'ast> Visitor<'ast> for Functions {
+    fn visit_func_call(&mut self, node: &'ast FuncCall) {
+        if let Some(NodeEnum::String(name)) = node.funcname.last().and_then(|name| name.node.as_ref()) {
+            self.0.push(name.sval.clone());
+        }
+        walk_func_call(self, node);
+    }
+}
+
+fn functions(query: &str) -> Vec<String> {
+    let mut functions = Functions::default();
+    functions.visit_parse_result(&parse(query).unwrap().protobuf);
+    functions.0
+}
+
+#[test]
+fn it_visits_nested_expressions() {
+    assert_eq!(functions("SELECT lower(upper(name)) FROM users WHERE id IN (SELECT max(id) FROM accounts)"), ["lower", "upper", "max"]);
+}
+
+#[test]
+fn it_visits_every_node_type() {
+    assert_eq!(functions("SELECT * FROM JSON_TABLE(jsonb_build_object('a', 1), '$' COLUMNS (a int PATH '$.a'))"), ["jsonb_build_object"]);
+    assert_eq!(
+        functions("MERGE INTO t USING s ON t.id = s.id WHEN MATCHED AND s.updated_at > now() THEN DELETE WHEN NOT MATCHED THEN INSERT VALUES (s.id, random())"),
+        ["now", "random"]
+    );
+    assert_eq!(functions("CREATE TABLE t (id int DEFAULT nextval('s'), name text, CHECK (length(name) > 0))"), ["nextval", "length"]);
+    assert_eq!(functions("CREATE INDEX ON t (lower(name)) WHERE length(state) > 0"), ["lower", "length"]);
+}
+
+#[test]
+fn it_borrows_from_the_tree() {
+    #[derive(Default)]
+    struct Tables<'ast>(Vec<&'ast RangeVar>);
+
+    impl<'ast> Visitor<'ast> for Tables<'ast> {
+        fn visit_range_var(&mut self, node: &'ast RangeVar) {
+            self.0.push(node);
+        }
+    }
+
+    let result = parse("WITH recent AS (SELECT * FROM events) INSERT INTO archive SELECT * FROM recent JOIN users ON true").unwrap();
+    let mut tables = Tables::default();
+    tables.visit_parse_result(&result.protobuf);
+    assert_eq!(tables.0.iter().map(|table| table.relname.as_str()).collect::<Vec<_>>(), ["archive", "recent", "users", "events"]);
+}
+
+#[test]
+fn it_skips_children_that_are_not_walked() {
+    // Collects the tables of a statement, without descending into subqueries
+    #[derive(Default)]
+    struct OuterTables(Vec<String>);
+
+    impl<'ast> Visitor<'ast> for OuterTables {
+        fn visit_node(&mut self, node: &'ast Node) {
+            match &node.node {
+                Some(NodeEnum::SubLink(_)) => {}
+                Some(NodeEnum::RangeVar(table)) => self.0.push(table.relname.clone()),
+                _ => walk_node(self, node),
+            }
+        }
+    }
+
+    let mut tables = OuterTables::default();
+    tables.visit_parse_result(&parse("SELECT * FROM t JOIN u ON true WHERE EXISTS (SELECT 1 FROM v WHERE v.a = t.a)").unwrap().protobuf);
+    assert_eq!(tables.0, ["t", "u"]);
+}
+
+#[test]
+fn it_replaces_nodes() {
+    struct Schema;
+
+    impl VisitorMut for Schema {
+        fn visit_range_var_mut(&mut self, node: &mut RangeVar) {
+            node.schemaname = "app".to_string();
+        }
+    }
+
+    let mut result = parse("SELECT * FROM users JOIN orders ON orders.user_id = users.id").unwrap();
+    Schema.visit_parse_result_mut(&mut result.protobuf);
+    assert_eq!(result.deparse().unwrap(), "SELECT * FROM app.users JOIN app.orders ON orders.user_id = users.id");
+}
+
+/// Returns the name of a target that is a plain column.
+fn column_name(target: &Node) -> Option<&str> {
+    let Some(NodeEnum::ResTarget(target)) = &target.node else { return None };
+    let Some(NodeEnum::ColumnRef(column)) = target.val.as_ref()?.node.as_ref() else { return None };
+    match column.fields.last()?.node.as_ref()? {
+        NodeEnum::String(name) => Some(&name.sval),
+        _ => None,
+    }
+}
+
+#[test]
+fn it_removes_and_inserts_nodes() {
+    /// Hides the password column, and limits every query over a table to one tenant.
+    struct Restrict(Node);
+
+    impl VisitorMut for Restrict {
+        fn visit_select_stmt_mut(&mut self, node: &mut SelectStmt) {
+            walk_select_stmt_mut(self, node);
+            node.target_list.retain(|target| column_name(target) != Some("password"));
+            if !node.from_clause.is_empty() {
+                let condition = self.0.clone();
+                node.where_clause = Some(Box::new(match node.where_clause.take() {
+                    Some(clause) => Node {
+                        node: Some(NodeEnum::BoolExpr(Box::new(BoolExpr {
+                            boolop: BoolExprType::AndExpr.into(),
+                            args: vec![*clause, condition],
+                            ..Default::default()
+                        }))),
+                    },
+                    None => condition,
+                }));
+            }
+        }
+    }
+
+    let condition = parse("SELECT WHERE tenant_id = 1").unwrap();
+    let NodeEnum::SelectStmt(select) = condition.protobuf.stmts[0].stmt.as_ref().unwrap().node.as_ref().unwrap() else {
+        panic!("expected a SelectStmt")
+    };
+    let mut restrict = Restrict(*select.where_clause.clone().unwrap());
+
+    let mut result = parse("SELECT id, password, email FROM users WHERE id IN (SELECT user_id FROM orders)").unwrap();
+    restrict.visit_parse_result_mut(&mut result.protobuf);
+    assert_eq!(
+        result.deparse().unwrap(),
+        "SELECT id, email FROM users WHERE id IN (SELECT user_id FROM orders WHERE tenant_id = 1) AND tenant_id = 1"
+    );
+}